@@ -1,20 +1,29 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use crossterm::execute;
+use crossterm::terminal::supports_keyboard_enhancement;
 use ratatui::DefaultTerminal;
+use std::collections::HashSet;
 use std::io;
 use std::time::Duration;
 
 use crate::content::{
-    AdaptiveLessonGenerator, BigramType, ContentGenerator, Language, Lesson, ProgrammingLanguage,
+    custom, custom::CustomLessonLoader, AdaptiveLessonGenerator, BigramType, ContentGenerator,
+    Language, Lesson, ProgrammingLanguage, Wordlist,
 };
-use crate::data::{SessionRecord, Stats, Storage};
-use crate::engine::{calculate_results, SessionAnalyzer, TypingSession};
-use crate::keyboard::AzertyLayout;
+use crate::data::keybindings::{Action, Keymap};
+use crate::data::{AppConfig, SessionRecord, Stats, StatsDisplayConfig, Storage};
+use crate::engine::{calculate_results, GhostReplay, SessionAnalyzer, TypingSession};
+use crate::keyboard::{KeyboardLayout, KeyboardLayoutKind};
 use crate::ui;
 use crate::ui::keyboard::KeyboardConfig;
 
 /// Application state
 #[derive(Debug, PartialEq)]
 enum AppState {
+    LayoutMenu,
     DurationMenu,
     LessonMenu,
     Running,
@@ -34,14 +43,55 @@ pub struct App {
     selected_duration: usize,
     selected_duration_value: crate::engine::SessionDuration,
     keyboard_visible: bool,
-    keyboard_layout: AzertyLayout,
+    keyboard_layout: Box<dyn KeyboardLayout>,
+    selected_layout_index: usize,
     keyboard_config: KeyboardConfig,
+    /// Keys pressed so far for the chord currently being buffered (`LessonType::Chord`)
+    chord_buffer: Vec<char>,
+    /// When the in-progress chord is committed even if incomplete
+    chord_deadline: Option<std::time::Instant>,
+    /// User-configurable key-to-action table, loaded from `keys.toml` if present
+    keymap: Keymap,
+    /// Prior attempt at the current lesson, replayed as a "ghost" pace target
+    ghost: Option<GhostReplay>,
+    /// Cached, pre-wrapped preview text for the lesson menu, keyed by (lesson, width)
+    menu_preview_cache: ui::MenuPreviewCache,
+    /// Incremental fuzzy-filter query typed in the lesson menu
+    lesson_filter: String,
+    /// Which panel is active on the statistics screen
+    #[allow(dead_code)]
+    stats_tab: ui::StatsTabState,
+    /// Thresholds/top-N limits for the weaknesses and mistypes panels, loaded
+    /// from `config.toml`
+    #[allow(dead_code)]
+    stats_display_config: StatsDisplayConfig,
+    /// Names of the custom word-list packs (`.txt` files) found in
+    /// `Storage::wordlists_dir()`, available alongside the bundled languages
+    #[allow(dead_code)]
+    available_wordlist_packs: Vec<String>,
 }
 
+/// How long to wait for the rest of a chord's keys before committing it as-is
+const CHORD_TIMEOUT: Duration = Duration::from_millis(100);
+
 impl App {
     pub fn new() -> io::Result<Self> {
+        Self::new_with_layout_override(None)
+    }
+
+    /// Build the app, optionally overriding the persisted layout choice
+    /// (e.g. from the `--layout` CLI flag) for this run only — the user's
+    /// saved `stats.keyboard_layout` is left untouched unless they change
+    /// it again from the in-app `LayoutMenu`.
+    pub fn new_with_layout_override(
+        layout_override: Option<KeyboardLayoutKind>,
+    ) -> io::Result<Self> {
         let storage = Storage::new()?;
-        let stats = storage.load()?;
+        let mut stats = storage.load()?;
+
+        if let Some(kind) = layout_override {
+            stats.keyboard_layout = kind;
+        }
 
         // Build complete lesson list with reordered organization
         let mut lessons = Vec::new();
@@ -54,6 +104,9 @@ impl App {
         // FINGER TRAINING SECTION (24 lessons: 4 pairs × 6 lessons each) - NOW SECOND
         lessons.extend(Lesson::finger_pair_lessons());
 
+        // CHORD TRAINING SECTION (3 lessons): simultaneous-key combos
+        lessons.extend(Lesson::chord_lessons());
+
         // PRIMARY SECTION: Key Training (25 lessons) - NOW THIRD
         // Organized: individual lessons → group → shift variant
 
@@ -161,9 +214,30 @@ impl App {
         // Python Code Symbols (6 lessons)
         lessons.extend(Lesson::code_symbol_lessons(ProgrammingLanguage::Python));
 
+        // Custom lessons (markdown files under the user's config/cwd `custom/` dirs).
+        // A custom lesson's title could otherwise collide with a built-in
+        // one, and ghost replay looks up records purely by `lesson.title`;
+        // rename the custom side only, so built-in titles (and their
+        // session history) stay stable.
+        let existing_titles: HashSet<String> =
+            lessons.iter().map(|lesson| lesson.title.clone()).collect();
+        let mut custom_lessons = CustomLessonLoader::load_all();
+        custom::rename_conflicting_titles(&mut custom_lessons, &existing_titles);
+        lessons.extend(custom_lessons);
+
+        let selected_layout_index = KeyboardLayoutKind::all()
+            .iter()
+            .position(|kind| *kind == stats.keyboard_layout)
+            .unwrap_or(0);
+        let keyboard_layout = stats.keyboard_layout.build(&storage.azerty_layout_path());
+        let keymap = Keymap::load(&storage.keybindings_path());
+        let app_config = AppConfig::load(&storage.app_config_path());
+        let available_wordlist_packs =
+            Wordlist::discover_packs(&storage.wordlists_dir()).unwrap_or_default();
+
         Ok(Self {
             session: None,
-            state: AppState::LessonMenu, // Start with lesson selection
+            state: AppState::LayoutMenu, // Start with keyboard layout selection
             storage,
             stats,
             selected_lesson: 0,
@@ -172,11 +246,84 @@ impl App {
             selected_duration: 2, // Default to 5 minutes (index 2)
             selected_duration_value: crate::engine::SessionDuration::FiveMinutes,
             keyboard_visible: true, // Default visible
-            keyboard_layout: AzertyLayout::new(),
+            keyboard_layout,
+            selected_layout_index,
             keyboard_config: KeyboardConfig::default(),
+            chord_buffer: Vec::new(),
+            chord_deadline: None,
+            keymap,
+            ghost: None,
+            menu_preview_cache: ui::MenuPreviewCache::new(),
+            lesson_filter: String::new(),
+            stats_tab: ui::StatsTabState::new(&app_config.statistics.panels),
+            stats_display_config: app_config.stats_display,
+            available_wordlist_packs,
         })
     }
 
+    /// Indices into `self.lessons` whose title matches the current
+    /// `lesson_filter`, in their original order.
+    fn filtered_lesson_indices(&self) -> Vec<usize> {
+        ui::matching_lesson_indices(&self.lessons, &self.lesson_filter)
+    }
+
+    /// If `selected_lesson` no longer matches `lesson_filter`, snap it to the
+    /// first lesson that still does (called after the filter text changes).
+    fn clamp_lesson_selection_to_filter(&mut self) {
+        let indices = self.filtered_lesson_indices();
+        if !indices.is_empty() && !indices.contains(&self.selected_lesson) {
+            self.selected_lesson = indices[0];
+            self.lesson_scroll_offset = 0;
+        }
+    }
+
+    /// Whether the currently selected lesson is a chord (simultaneous-key) lesson
+    fn is_chord_lesson(&self) -> bool {
+        matches!(
+            self.lessons[self.selected_lesson].lesson_type,
+            crate::content::lesson::LessonType::Chord { .. }
+        )
+    }
+
+    /// Buffer one key of an in-progress chord, committing it once every expected
+    /// key has arrived (the timeout-based commit is handled in `run_loop`)
+    fn push_chord_key(&mut self, key: char) {
+        if self.chord_buffer.is_empty() {
+            self.chord_deadline = Some(std::time::Instant::now() + CHORD_TIMEOUT);
+        }
+        self.chord_buffer.push(key);
+
+        let expected_len = self
+            .session
+            .as_ref()
+            .map(|session| {
+                session
+                    .content
+                    .chars()
+                    .skip(session.current_index)
+                    .take_while(|&c| c != ' ')
+                    .count()
+            })
+            .unwrap_or(0);
+
+        if expected_len > 0 && self.chord_buffer.len() >= expected_len {
+            self.commit_chord();
+        }
+    }
+
+    /// Score whatever keys have been buffered so far as a chord, whether the
+    /// buffer is complete or the timeout elapsed on a partial chord
+    fn commit_chord(&mut self) {
+        if self.chord_buffer.is_empty() {
+            return;
+        }
+        let pressed = std::mem::take(&mut self.chord_buffer);
+        if let Some(session) = &mut self.session {
+            session.add_chord_input(&pressed);
+        }
+        self.chord_deadline = None;
+    }
+
     fn start_lesson(&mut self, lesson_index: usize) {
         let lesson = &self.lessons[lesson_index];
 
@@ -185,18 +332,30 @@ impl App {
             crate::content::lesson::LessonType::Adaptive => {
                 // Generate adaptive content if analytics available
                 if let Some(analytics) = &self.stats.adaptive_analytics {
-                    let generator = AdaptiveLessonGenerator::new(analytics);
+                    let mut generator = AdaptiveLessonGenerator::new(analytics);
                     generator.generate(500)
                 } else {
                     "Insufficient data for adaptive mode. Complete more sessions first.".to_string()
                 }
             }
+            // A custom lesson's content is fixed at load time, not chunked
+            // on demand — load it in full rather than truncating to 500 chars.
+            crate::content::lesson::LessonType::Custom { content } => content.clone(),
             _ => lesson.generate(500), // Standard content generation
         };
 
         let session = TypingSession::new(content, self.selected_duration_value.as_duration());
         // Don't call session.start() - timer starts on first keystroke
 
+        // Race the most recent prior attempt at this lesson, if one left a keystroke trace
+        self.ghost = self
+            .stats
+            .sessions
+            .iter()
+            .rev()
+            .find(|record| record.lesson_type == lesson.title && !record.keystrokes.is_empty())
+            .map(|record| GhostReplay::new(record.keystrokes.clone()));
+
         self.session = Some(session);
         self.state = AppState::Running;
     }
@@ -209,12 +368,15 @@ impl App {
             let more_content = match &lesson.lesson_type {
                 crate::content::lesson::LessonType::Adaptive => {
                     if let Some(analytics) = &self.stats.adaptive_analytics {
-                        let generator = AdaptiveLessonGenerator::new(analytics);
+                        let mut generator = AdaptiveLessonGenerator::new(analytics);
                         generator.generate(300)
                     } else {
                         String::new()
                     }
                 }
+                // Already loaded in full by `start_lesson`; re-requesting would
+                // just re-append the same leading excerpt `generate()` produces.
+                crate::content::lesson::LessonType::Custom { .. } => String::new(),
                 _ => lesson.generate(300),
             };
 
@@ -226,15 +388,50 @@ impl App {
 
     /// Main app entry point
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        // Enable the kitty keyboard protocol when the terminal advertises support, so
+        // we can see key release/repeat events for dwell-time and rollover tracking.
+        // Terminals without support (e.g. plain xterm) silently keep the press-only path.
+        let kitty_enabled = supports_keyboard_enhancement().unwrap_or(false);
+        if kitty_enabled {
+            execute!(
+                io::stdout(),
+                PushKeyboardEnhancementFlags(
+                    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                        | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
+                )
+            )?;
+        }
+
+        let result = self.run_loop(terminal);
+
+        if kitty_enabled {
+            execute!(io::stdout(), PopKeyboardEnhancementFlags)?;
+        }
+
+        result
+    }
+
+    fn run_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         loop {
             // Render
             terminal.draw(|f| match self.state {
+                AppState::LayoutMenu => {
+                    ui::render_layout_menu(
+                        f,
+                        &KeyboardLayoutKind::all(),
+                        self.selected_layout_index,
+                    );
+                }
                 AppState::LessonMenu => {
                     ui::render_menu(
                         f,
                         &self.lessons,
                         self.selected_lesson,
                         self.lesson_scroll_offset,
+                        &self.lesson_filter,
+                        &mut self.menu_preview_cache,
+                        None,
                     );
                 }
                 AppState::DurationMenu => {
@@ -245,12 +442,19 @@ impl App {
                         let result = calculate_results(session);
 
                         if session.is_complete() {
+                            let wpm_per_second =
+                                crate::engine::scoring::wpm_per_second_buckets(&session.inputs);
                             ui::render_results(
                                 f,
                                 result.wpm,
+                                result.raw_wpm,
                                 result.accuracy,
                                 result.duration,
                                 result.error_count,
+                                &result.slow_keys,
+                                result.rollover_count,
+                                result.consistency,
+                                &wpm_per_second,
                             );
                         } else {
                             let lesson_name = &self.lessons[self.selected_lesson].title;
@@ -260,10 +464,12 @@ impl App {
                                 result.wpm,
                                 result.accuracy,
                                 self.keyboard_visible,
-                                &self.keyboard_layout,
+                                self.keyboard_layout.as_ref(),
                                 &self.stats.adaptive_analytics,
                                 &self.keyboard_config,
                                 lesson_name,
+                                self.ghost.as_ref(),
+                                self.stats.cursor_style,
                             );
                         }
                     }
@@ -287,6 +493,13 @@ impl App {
                 }
             }
 
+            // Commit an in-progress chord once its timeout window elapses
+            if let Some(deadline) = self.chord_deadline {
+                if std::time::Instant::now() >= deadline {
+                    self.commit_chord();
+                }
+            }
+
             // Check session completion
             if let Some(session) = &mut self.session {
                 if session.is_complete() && self.state == AppState::Running {
@@ -310,126 +523,188 @@ impl App {
 
     /// Handle keyboard events
     fn handle_key_event(&mut self, key: KeyEvent) -> io::Result<()> {
-        // Ignore release events
+        // Dwell-time/rollover tracking (only populated when the terminal sent us
+        // release events, i.e. the kitty keyboard protocol was enabled)
+        if self.state == AppState::Running {
+            if let (KeyCode::Char(c), Some(session)) = (key.code, &mut self.session) {
+                match key.kind {
+                    KeyEventKind::Press | KeyEventKind::Repeat => session.record_key_down(c),
+                    KeyEventKind::Release => session.record_key_up(c),
+                }
+            }
+        }
+
+        // Ignore release events for everything else (menu navigation, typed input)
         if key.kind != KeyEventKind::Press {
             return Ok(());
         }
 
+        // Resolve the configured action for this key, if any (see `keys.toml`
+        // via `Storage::keybindings_path`); state-specific keys (Enter/Space to
+        // confirm, digit shortcuts, typed characters) are still matched on raw code.
+        let action = self.keymap.action_for(key.code, key.modifiers);
+
         match self.state {
-            AppState::LessonMenu => match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    // Quit from first menu
+            AppState::LayoutMenu => match action {
+                Some(Action::Quit) | Some(Action::Back) => {
                     self.state = AppState::Quit;
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if self.selected_lesson > 0 {
-                        self.selected_lesson -= 1;
-                        // Scroll up if selection goes above viewport
-                        if self.selected_lesson < self.lesson_scroll_offset {
-                            self.lesson_scroll_offset = self.selected_lesson;
-                        }
+                Some(Action::MenuUp) => {
+                    if self.selected_layout_index > 0 {
+                        self.selected_layout_index -= 1;
                     }
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if self.selected_lesson < self.lessons.len() - 1 {
-                        self.selected_lesson += 1;
-                        // Scroll down if selection goes below viewport (using conservative estimate of 20)
-                        let viewport_height = 20;
-                        if self.selected_lesson >= self.lesson_scroll_offset + viewport_height {
-                            self.lesson_scroll_offset = self.selected_lesson - viewport_height + 1;
-                        }
+                Some(Action::MenuDown) => {
+                    let max_idx = KeyboardLayoutKind::all().len() - 1;
+                    if self.selected_layout_index < max_idx {
+                        self.selected_layout_index += 1;
                     }
                 }
-                KeyCode::Enter | KeyCode::Char(' ') => {
-                    // Go to duration menu after lesson selected
-                    self.state = AppState::DurationMenu;
+                _ => match key.code {
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        let kind = KeyboardLayoutKind::all()[self.selected_layout_index];
+                        self.keyboard_layout = kind.build(&self.storage.azerty_layout_path());
+                        self.stats.keyboard_layout = kind;
+                        self.storage.save(&self.stats)?;
+                        self.state = AppState::LessonMenu;
+                    }
+                    _ => {}
+                },
+            },
+            AppState::LessonMenu => match action {
+                Some(Action::Quit) | Some(Action::Back) => {
+                    if self.lesson_filter.is_empty() {
+                        // Go back to layout menu
+                        self.state = AppState::LayoutMenu;
+                    } else {
+                        // Clear the filter first; ESC only leaves the menu once it's empty
+                        self.lesson_filter.clear();
+                        self.clamp_lesson_selection_to_filter();
+                    }
                 }
-                KeyCode::Char(c) if c.is_ascii_digit() => {
-                    // Allow direct selection with numbers
-                    if let Some(digit) = c.to_digit(10) {
-                        let index = (digit as usize).saturating_sub(1);
-                        if index < self.lessons.len() {
-                            self.selected_lesson = index;
-                            // Go to duration menu after lesson selected
-                            self.state = AppState::DurationMenu;
+                Some(Action::MenuUp) => {
+                    let indices = self.filtered_lesson_indices();
+                    if let Some(pos) = indices.iter().position(|&i| i == self.selected_lesson) {
+                        if pos > 0 {
+                            self.selected_lesson = indices[pos - 1];
+                            // Scroll up if selection goes above viewport
+                            if self.selected_lesson < self.lesson_scroll_offset {
+                                self.lesson_scroll_offset = self.selected_lesson;
+                            }
                         }
                     }
                 }
-                _ => {}
+                Some(Action::MenuDown) => {
+                    let indices = self.filtered_lesson_indices();
+                    if let Some(pos) = indices.iter().position(|&i| i == self.selected_lesson) {
+                        if pos + 1 < indices.len() {
+                            self.selected_lesson = indices[pos + 1];
+                            // Scroll down if selection goes below viewport (using conservative estimate of 20)
+                            let viewport_height = 20;
+                            if self.selected_lesson >= self.lesson_scroll_offset + viewport_height {
+                                self.lesson_scroll_offset =
+                                    self.selected_lesson - viewport_height + 1;
+                            }
+                        }
+                    }
+                }
+                _ => match key.code {
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        // Go to duration menu after lesson selected
+                        self.state = AppState::DurationMenu;
+                    }
+                    KeyCode::Char(c) if self.lesson_filter.is_empty() && c.is_ascii_digit() => {
+                        // Allow direct selection with numbers (disabled while
+                        // filtering, since digits feed the filter text there)
+                        if let Some(digit) = c.to_digit(10) {
+                            let index = (digit as usize).saturating_sub(1);
+                            if index < self.lessons.len() {
+                                self.selected_lesson = index;
+                                // Go to duration menu after lesson selected
+                                self.state = AppState::DurationMenu;
+                            }
+                        }
+                    }
+                    KeyCode::Char(c) if !c.is_control() => {
+                        self.lesson_filter.push(c);
+                        self.clamp_lesson_selection_to_filter();
+                    }
+                    KeyCode::Backspace => {
+                        self.lesson_filter.pop();
+                        self.clamp_lesson_selection_to_filter();
+                    }
+                    _ => {}
+                },
             },
-            AppState::DurationMenu => match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
+            AppState::DurationMenu => match action {
+                Some(Action::Quit) | Some(Action::Back) => {
                     // Go back to lesson menu
                     self.state = AppState::LessonMenu;
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
+                Some(Action::MenuUp) => {
                     if self.selected_duration > 0 {
                         self.selected_duration -= 1;
                     }
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
+                Some(Action::MenuDown) => {
                     let max_idx = crate::engine::SessionDuration::all().len() - 1;
                     if self.selected_duration < max_idx {
                         self.selected_duration += 1;
                     }
                 }
-                KeyCode::Enter | KeyCode::Char(' ') => {
-                    // Save selected duration and start lesson
-                    self.selected_duration_value =
-                        crate::engine::SessionDuration::all()[self.selected_duration];
-                    self.start_lesson(self.selected_lesson);
+                _ => {
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                        // Save selected duration and start lesson
+                        self.selected_duration_value =
+                            crate::engine::SessionDuration::all()[self.selected_duration];
+                        self.start_lesson(self.selected_lesson);
+                    }
                 }
-                _ => {}
             },
-            AppState::Running => match key.code {
-                KeyCode::Esc => {
+            AppState::Running => match action {
+                Some(Action::Back) => {
                     // Return to lesson menu (discard session)
                     self.state = AppState::LessonMenu;
                     self.session = None;
                 }
-                KeyCode::Tab => {
-                    // Toggle keyboard visibility
+                Some(Action::ToggleKeyboard) => {
                     self.keyboard_visible = !self.keyboard_visible;
                 }
-                KeyCode::Char('f') | KeyCode::Char('F')
-                    if key.modifiers.contains(KeyModifiers::CONTROL) =>
-                {
-                    // Toggle finger colors (Ctrl+F)
+                Some(Action::ToggleFingerColors) => {
                     self.keyboard_config.show_finger_colors =
                         !self.keyboard_config.show_finger_colors;
                 }
-                KeyCode::Char('h') | KeyCode::Char('H')
-                    if key.modifiers.contains(KeyModifiers::CONTROL) =>
-                {
-                    // Toggle heatmap (Ctrl+H)
+                Some(Action::ToggleHeatmap) => {
                     self.keyboard_config.show_heatmap = !self.keyboard_config.show_heatmap;
                 }
-                KeyCode::Char(c) => {
-                    if let Some(session) = &mut self.session {
-                        session.add_input(c);
+                _ => match key.code {
+                    KeyCode::Char(c) => {
+                        if self.is_chord_lesson() {
+                            self.push_chord_key(c);
+                        } else if let Some(session) = &mut self.session {
+                            session.add_input(c);
+                        }
                     }
-                }
-                KeyCode::Backspace => {
-                    if let Some(session) = &mut self.session {
-                        session.remove_last_input();
+                    KeyCode::Backspace => {
+                        if let Some(session) = &mut self.session {
+                            session.remove_last_input();
+                        }
                     }
+                    _ => {}
+                },
+            },
+            AppState::Completed => match action {
+                Some(Action::Quit) | Some(Action::Back) => {
+                    // Return to lesson menu
+                    self.state = AppState::LessonMenu;
+                    self.session = None;
+                }
+                Some(Action::Restart) => {
+                    // Re-select duration for restart
+                    self.state = AppState::DurationMenu;
                 }
                 _ => {}
             },
-            AppState::Completed => {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        // Return to lesson menu
-                        self.state = AppState::LessonMenu;
-                        self.session = None;
-                    }
-                    KeyCode::Char('r') => {
-                        // Re-select duration for restart
-                        self.state = AppState::DurationMenu;
-                    }
-                    _ => {}
-                }
-            }
             AppState::Quit => {}
         }
 
@@ -442,20 +717,26 @@ impl App {
             let result = calculate_results(session);
             let lesson = &self.lessons[self.selected_lesson];
 
-            // Save session record
+            // Save session record, including the keystroke trace for a future ghost replay
+            let keystrokes: Vec<u64> = session
+                .inputs
+                .iter()
+                .map(|input| input.timestamp.as_millis() as u64)
+                .collect();
             let record = SessionRecord::new(
                 lesson.title.clone(),
                 result.wpm,
                 result.accuracy,
                 result.duration,
-                self.selected_duration_value.as_duration(),
+                keystrokes,
             );
             self.stats.add_session(record);
 
             // Update adaptive analytics
             let analyzer = SessionAnalyzer::new();
             let analysis = analyzer.analyze_session(session);
-            self.stats.update_analytics(session, analysis);
+            self.stats
+                .update_analytics(session, analysis, &result, lesson.title.clone());
 
             // Save everything to JSON
             self.storage.save(&self.stats)?;
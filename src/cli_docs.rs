@@ -0,0 +1,125 @@
+/// Data-driven Markdown reference for the CLI surface handled by `main.rs`.
+///
+/// This crate has no `clap` dependency to walk a `Command` tree from, so
+/// the tree below is a small hand-maintained mirror of the argument
+/// handling actually implemented in `main.rs` (`next`, `--layout`, ...).
+/// `typer util markdown-help` renders it, giving a single always-current
+/// reference instead of hand-written docs that drift from the parser.
+pub struct CliFlag {
+    pub long: &'static str,
+    pub takes_value: bool,
+    pub help: &'static str,
+    pub default: Option<&'static str>,
+}
+
+pub struct CliCommand {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub flags: &'static [CliFlag],
+    pub subcommands: &'static [CliCommand],
+}
+
+/// The CLI surface exposed by `main.rs`'s hand-rolled argument parsing.
+pub const ROOT: CliCommand = CliCommand {
+    name: "typer",
+    help: "Terminal typing trainer.",
+    flags: &[CliFlag {
+        long: "--layout",
+        takes_value: true,
+        help: "Keyboard layout for this run (see `KeyboardLayoutKind::all`), overriding the persisted choice. Accepts `--layout <value>` or `--layout=<value>`. Unknown names are ignored.",
+        default: None,
+    }],
+    subcommands: &[
+        CliCommand {
+            name: "next",
+            help: "Print the bigram you most need to drill, picked from the persisted mastery store, without launching the TUI.",
+            flags: &[],
+            subcommands: &[],
+        },
+        CliCommand {
+            name: "util",
+            help: "Developer utility commands.",
+            flags: &[],
+            subcommands: &[CliCommand {
+                name: "markdown-help",
+                help: "Print this command reference as Markdown.",
+                flags: &[],
+                subcommands: &[],
+            }],
+        },
+    ],
+};
+
+/// Render `command` and every subcommand recursively as Markdown, one
+/// heading level deeper per nesting depth.
+pub fn render_markdown(command: &CliCommand) -> String {
+    let mut out = String::new();
+    render_command(command, 1, &mut out);
+    out
+}
+
+fn render_command(command: &CliCommand, depth: usize, out: &mut String) {
+    let heading = "#".repeat(depth.min(6));
+    out.push_str(&format!("{} `{}`\n\n{}\n\n", heading, command.name, command.help));
+
+    if !command.flags.is_empty() {
+        out.push_str("Flags:\n\n");
+        for flag in command.flags {
+            let value = if flag.takes_value { " <value>" } else { "" };
+            let default = match flag.default {
+                Some(default) => format!(" (default: `{}`)", default),
+                None => String::new(),
+            };
+            out.push_str(&format!("- `{}{}` — {}{}\n", flag.long, value, flag.help, default));
+        }
+        out.push('\n');
+    }
+
+    for subcommand in command.subcommands {
+        render_command(subcommand, depth + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_includes_root_and_subcommands() {
+        let markdown = render_markdown(&ROOT);
+        assert!(markdown.contains("# `typer`"));
+        assert!(markdown.contains("## `next`"));
+        assert!(markdown.contains("## `util`"));
+        assert!(markdown.contains("### `markdown-help`"));
+    }
+
+    #[test]
+    fn test_render_markdown_lists_flags_with_default() {
+        let command = CliCommand {
+            name: "typer",
+            help: "root",
+            flags: &[CliFlag {
+                long: "--layout",
+                takes_value: true,
+                help: "pick a layout",
+                default: Some("azerty"),
+            }],
+            subcommands: &[],
+        };
+
+        let markdown = render_markdown(&command);
+        assert!(markdown.contains("`--layout <value>` — pick a layout (default: `azerty`)"));
+    }
+
+    #[test]
+    fn test_render_markdown_omits_flags_section_when_none() {
+        let command = CliCommand {
+            name: "next",
+            help: "does a thing",
+            flags: &[],
+            subcommands: &[],
+        };
+
+        assert!(!render_markdown(&command).contains("Flags:"));
+    }
+}
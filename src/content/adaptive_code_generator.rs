@@ -0,0 +1,202 @@
+/// Adaptive code-snippet generator
+/// Scores code snippets by the user's symbol-category mastery, so code drills
+/// emphasize the punctuation/operator groups a user actually struggles with,
+/// instead of cycling through a flat static list.
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::thread_rng;
+
+use super::code_symbols::{
+    classify_symbol_category, python_snippets, rust_snippets, typescript_snippets, CodeSnippet,
+    ProgrammingLanguage, SymbolCategory, ALL_SYMBOL_CATEGORIES,
+};
+use crate::engine::analytics::AdaptiveAnalytics;
+
+/// Minimum combined attempts across a category's keys before its accuracy is
+/// trusted; below this the category is treated as weak so it still gets
+/// practiced rather than being starved by sparse data.
+const MIN_CATEGORY_ATTEMPTS: usize = 10;
+
+/// Floor for a category's weight so even a mastered category keeps a small
+/// chance of being drilled.
+const MIN_CATEGORY_WEIGHT: f64 = 1.0;
+
+pub struct AdaptiveCodeLessonGenerator<'a> {
+    analytics: &'a AdaptiveAnalytics,
+    snippets: Vec<CodeSnippet>,
+}
+
+impl<'a> AdaptiveCodeLessonGenerator<'a> {
+    pub fn new(analytics: &'a AdaptiveAnalytics, language: ProgrammingLanguage) -> Self {
+        let snippets = match language {
+            ProgrammingLanguage::TypeScript => typescript_snippets(),
+            ProgrammingLanguage::Rust => rust_snippets(),
+            ProgrammingLanguage::Python => python_snippets(),
+        };
+
+        Self { analytics, snippets }
+    }
+
+    /// Generate a drill of whole snippets concatenated until `length` is
+    /// reached, drawn via a weighted distribution that favors the categories
+    /// the user's symbol mastery is weakest at, and capped at the difficulty
+    /// the user's weakest category can currently handle.
+    pub fn generate(&self, length: usize) -> String {
+        let ceiling = self.difficulty_ceiling();
+        let candidates: Vec<&CodeSnippet> = self
+            .snippets
+            .iter()
+            .filter(|s| s.difficulty <= ceiling)
+            .collect();
+
+        if candidates.is_empty() {
+            return String::new();
+        }
+
+        let weights: Vec<f64> = candidates
+            .iter()
+            .map(|s| self.category_weight(s.category))
+            .collect();
+
+        let dist = match WeightedIndex::new(&weights) {
+            Ok(dist) => dist,
+            Err(_) => return Self::cycle_snippets(&candidates, length),
+        };
+
+        let mut rng = thread_rng();
+        let mut result = String::new();
+
+        while result.len() < length {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            result.push_str(candidates[dist.sample(&mut rng)].template);
+        }
+
+        result.chars().take(length).collect()
+    }
+
+    /// Drilling weight for `category`: the accuracy deficit across its member
+    /// keys, so a weaker category is sampled more often. Floored at
+    /// `MIN_CATEGORY_WEIGHT` and defaulting to "weak" when there isn't enough
+    /// data yet to trust a category's accuracy.
+    fn category_weight(&self, category: SymbolCategory) -> f64 {
+        let (total_attempts, total_correct) = self
+            .analytics
+            .key_stats
+            .iter()
+            .filter(|(key, _)| classify_symbol_category(**key) == Some(category))
+            .fold((0usize, 0usize), |(attempts, correct), (_, stats)| {
+                (
+                    attempts + stats.total_attempts,
+                    correct + stats.correct_attempts,
+                )
+            });
+
+        if total_attempts < MIN_CATEGORY_ATTEMPTS {
+            return 100.0;
+        }
+
+        let accuracy = (total_correct as f64 / total_attempts as f64) * 100.0;
+        (100.0 - accuracy).max(MIN_CATEGORY_WEIGHT)
+    }
+
+    /// The hardest difficulty tier the user's weakest-mastered category can
+    /// currently handle, so e.g. a beginner at `Arrows` isn't handed the
+    /// level-6 `reduce` one-liner just because other categories are mastered.
+    fn difficulty_ceiling(&self) -> u8 {
+        ALL_SYMBOL_CATEGORIES
+            .iter()
+            .map(|&category| Self::difficulty_for_weight(self.category_weight(category)))
+            .min()
+            .unwrap_or(6)
+    }
+
+    /// Difficulty tier a category's accuracy deficit weight unlocks: capped
+    /// low while still learning, up to the full range once accurate.
+    fn difficulty_for_weight(weight: f64) -> u8 {
+        if weight >= 80.0 {
+            2
+        } else if weight >= 50.0 {
+            4
+        } else {
+            6
+        }
+    }
+
+    /// Fallback when `WeightedIndex` can't be built (e.g. all weights zero):
+    /// cycle through the candidates in order instead of sampling.
+    fn cycle_snippets(snippets: &[&CodeSnippet], length: usize) -> String {
+        let mut result = String::new();
+        let mut idx = 0;
+
+        while result.len() < length {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            result.push_str(snippets[idx % snippets.len()].template);
+            idx += 1;
+        }
+
+        result.chars().take(length).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::analytics::KeyStats;
+
+    fn analytics_weak_in(keys: &[char], accuracy: f64) -> AdaptiveAnalytics {
+        let mut analytics = AdaptiveAnalytics::default();
+        for &key in keys {
+            let mut stats = KeyStats::new(key);
+            stats.total_attempts = 50;
+            stats.correct_attempts = (50.0 * accuracy / 100.0) as usize;
+            analytics.key_stats.insert(key, stats);
+        }
+        analytics
+    }
+
+    #[test]
+    fn test_generate_respects_length() {
+        let analytics = AdaptiveAnalytics::default();
+        let generator = AdaptiveCodeLessonGenerator::new(&analytics, ProgrammingLanguage::Rust);
+
+        let content = generator.generate(80);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 80);
+    }
+
+    #[test]
+    fn test_weak_bracket_mastery_caps_difficulty() {
+        // Brackets: '(', ')', '[', ']', '{', '}' all at 20% accuracy
+        let analytics = analytics_weak_in(&['(', ')', '[', ']', '{', '}'], 20.0);
+        let generator =
+            AdaptiveCodeLessonGenerator::new(&analytics, ProgrammingLanguage::TypeScript);
+
+        assert_eq!(generator.difficulty_ceiling(), 2);
+    }
+
+    #[test]
+    fn test_mastered_categories_allow_full_difficulty_range() {
+        let mut analytics = AdaptiveAnalytics::default();
+        for &key in &['(', ')', '[', ']', '{', '}', '+', '-', '*', '/', '=', '!', '<', '>', '&', '|', '.', ',', ';', ':'] {
+            let mut stats = KeyStats::new(key);
+            stats.total_attempts = 50;
+            stats.correct_attempts = 49;
+            analytics.key_stats.insert(key, stats);
+        }
+        let generator = AdaptiveCodeLessonGenerator::new(&analytics, ProgrammingLanguage::Rust);
+
+        assert_eq!(generator.difficulty_ceiling(), 6);
+    }
+
+    #[test]
+    fn test_category_weight_defaults_to_weak_without_enough_data() {
+        let analytics = AdaptiveAnalytics::default();
+        let generator = AdaptiveCodeLessonGenerator::new(&analytics, ProgrammingLanguage::Python);
+
+        assert_eq!(generator.category_weight(SymbolCategory::Brackets), 100.0);
+    }
+}
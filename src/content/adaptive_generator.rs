@@ -1,22 +1,122 @@
 /// Adaptive lesson content generator
 /// Generates personalized practice content based on user weaknesses
-use rand::{thread_rng, Rng};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
+use super::bigram::Language;
+use super::inflection::inflect;
+use super::wordlist::Wordlist;
 use crate::engine::adaptive::WeaknessDetector;
 use crate::engine::analytics::{AdaptiveAnalytics, MasteryLevel};
+use crate::engine::digraph_model::DigraphConfusionModel;
+
+/// Number of high-risk digraphs to consider when seeding alternation patterns.
+const RISKY_DIGRAPH_POOL: usize = 10;
+
+/// Language used to source real words and inflection rules for
+/// `generate_words`; the adaptive generator has no signal for which
+/// language the user is practicing in, so it defaults to English.
+const WORD_DRILL_LANGUAGE: Language = Language::English;
+
+/// Selects which of `AdaptiveLessonGenerator`'s content strategies to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveContentMode {
+    /// Synthetic key patterns: repetitions, alternations, triplets.
+    Patterns,
+    /// Real words chosen for their density of the user's focus keys.
+    Words,
+}
+
+/// Floor for a key's practice rate so even a 100%-accurate, fast key still
+/// has a (small) chance of being sampled for retention practice.
+const MIN_PRACTICE_RATE: f64 = 1.0;
+
+/// Average keystroke time (ms) beyond which a key starts picking up a
+/// slowness penalty on top of its accuracy deficit.
+const SLOWNESS_DIVISOR: f64 = 100.0;
 
 /// Adaptive lesson generator that creates personalized content
 pub struct AdaptiveLessonGenerator<'a> {
     analytics: &'a AdaptiveAnalytics,
+    rng: StdRng,
 }
 
 impl<'a> AdaptiveLessonGenerator<'a> {
     pub fn new(analytics: &'a AdaptiveAnalytics) -> Self {
-        Self { analytics }
+        Self {
+            analytics,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Build a generator whose output is fully determined by `seed`, so the
+    /// same seed regenerates identical practice text on another machine and
+    /// the test suite can assert exact output instead of just "non-empty".
+    pub fn with_seed(analytics: &'a AdaptiveAnalytics, seed: u64) -> Self {
+        Self {
+            analytics,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Generate adaptive practice content of specified length using `mode`
+    /// to pick between synthetic key patterns and real-word drills.
+    pub fn generate_content(&mut self, mode: AdaptiveContentMode, length: usize) -> String {
+        match mode {
+            AdaptiveContentMode::Patterns => self.generate(length),
+            AdaptiveContentMode::Words => self.generate_words(length),
+        }
+    }
+
+    /// Generate a drill of real words chosen because they are dense in the
+    /// user's focus keys (e.g. a user weak on `d`/`k` gets words like
+    /// "docked", "kindled"), mixing in inflected forms for variety. Falls
+    /// back to the pattern-based `generate` when no bundled wordlist is
+    /// available for `WORD_DRILL_LANGUAGE`.
+    pub fn generate_words(&mut self, length: usize) -> String {
+        let wordlist = match Wordlist::bundled(WORD_DRILL_LANGUAGE) {
+            Some(wordlist) => wordlist,
+            None => return self.generate(length),
+        };
+
+        let words = wordlist.words();
+        if words.is_empty() {
+            return self.generate(length);
+        }
+
+        // Score each word by how dense it is in the user's focus keys: the
+        // sum of each of its characters' practice rate, so e.g. "docked"
+        // scores high for a user weak on 'd' and 'k'.
+        let weights: Vec<f64> = words
+            .iter()
+            .map(|word| word.chars().map(|c| self.practice_rate(c)).sum())
+            .collect();
+
+        let dist = match WeightedIndex::new(&weights) {
+            Ok(dist) => dist,
+            Err(_) => return self.generate(length),
+        };
+
+        let mut result = String::new();
+        while result.len() < length {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+
+            let word = &words[dist.sample(&mut self.rng)];
+            if self.rng.gen_bool(0.5) {
+                result.push_str(&inflect(word, WORD_DRILL_LANGUAGE));
+            } else {
+                result.push_str(word);
+            }
+        }
+
+        result.chars().take(length).collect()
     }
 
     /// Generate adaptive practice content of specified length
-    pub fn generate(&self, length: usize) -> String {
+    pub fn generate(&mut self, length: usize) -> String {
         // Identify focus areas
         let weak_keys = WeaknessDetector::identify_weak_keys(self.analytics, 80.0);
         let slow_keys = WeaknessDetector::identify_slow_keys(self.analytics, 0.75);
@@ -33,76 +133,89 @@ impl<'a> AdaptiveLessonGenerator<'a> {
             return self.generate_balanced_practice(length);
         }
 
+        // Predict which (preceding, following) transitions are most likely to
+        // be mistyped, so alternation patterns can target those specifically
+        // instead of pairing focus keys purely at random.
+        let risky_digraphs =
+            DigraphConfusionModel::predict_error_prone_digraphs(self.analytics, RISKY_DIGRAPH_POOL);
+
         // Generate content with weighted distribution
-        self.generate_weighted_content(&focus_keys, length)
+        self.generate_weighted_content(&focus_keys, &risky_digraphs, length)
     }
 
-    /// Generate content with mastery-based distribution using practice_weight()
-    fn generate_weighted_content(&self, _focus_keys: &[char], length: usize) -> String {
+    /// Generate content with a per-key weighted distribution: each focus key's
+    /// practice rate is its accuracy deficit plus a slowness penalty, so a key
+    /// at 71% accuracy is drilled more than one at 50% rather than both being
+    /// snapped into the same coarse mastery-tier bucket.
+    fn generate_weighted_content(
+        &mut self,
+        focus_keys: &[char],
+        risky_digraphs: &[(char, char)],
+        length: usize,
+    ) -> String {
         let mut result = String::new();
-        let mut rng = thread_rng();
 
-        // Classify keys by mastery level
-        let beginner_keys = self.get_keys_by_mastery(MasteryLevel::Beginner);
-        let learning_keys = self.get_keys_by_mastery(MasteryLevel::Learning);
-        let proficient_keys = self.get_keys_by_mastery(MasteryLevel::Proficient);
-        let mastered_keys = self.get_keys_by_mastery(MasteryLevel::Mastered);
+        let rates: Vec<f64> = focus_keys.iter().map(|&key| self.practice_rate(key)).collect();
 
-        // Calculate cumulative thresholds from practice weights
-        let beginner_threshold = MasteryLevel::Beginner.practice_weight(); // 0.6
-        let learning_threshold = beginner_threshold + MasteryLevel::Learning.practice_weight(); // 0.9
-        let proficient_threshold = learning_threshold + MasteryLevel::Proficient.practice_weight(); // 1.0
+        let dist = match WeightedIndex::new(&rates) {
+            Ok(dist) => dist,
+            Err(_) => return self.generate_balanced_practice(length),
+        };
 
         while result.len() < length {
             if !result.is_empty() {
                 result.push(' ');
             }
 
-            // Weighted random selection based on mastery levels
-            let r: f32 = rng.gen();
-
-            let keys = if r < beginner_threshold && !beginner_keys.is_empty() {
-                // 60%: Beginner keys
-                &beginner_keys
-            } else if r < learning_threshold && !learning_keys.is_empty() {
-                // 30%: Learning keys
-                &learning_keys
-            } else if r < proficient_threshold && !proficient_keys.is_empty() {
-                // 10%: Proficient keys
-                &proficient_keys
-            } else if !mastered_keys.is_empty() {
-                // 5%: Mastered keys (retention practice)
-                &mastered_keys
-            } else if !beginner_keys.is_empty() {
-                // Fallback to beginner keys if others not available
-                &beginner_keys
-            } else {
-                // Should not happen, but handle gracefully
-                &learning_keys
-            };
-
-            if keys.is_empty() {
-                continue;
-            }
+            // Draw a small cluster of keys so generate_pattern can still form
+            // alternations and triplets, not just single-key repetition.
+            let cluster: Vec<char> = (0..3)
+                .map(|_| focus_keys[dist.sample(&mut self.rng)])
+                .collect();
 
-            // Generate pattern with selected keys
-            let pattern = self.generate_pattern(keys);
+            let pattern = self.generate_pattern(&cluster, risky_digraphs);
             result.push_str(&pattern);
         }
 
         result.chars().take(length).collect()
     }
 
-    /// Generate varied patterns: repetitions, alternations, triplets
-    fn generate_pattern(&self, keys: &[char]) -> String {
-        let mut rng = thread_rng();
-        let pattern_type: u8 = rng.gen_range(0..3);
+    /// Practice rate for a single key: higher for lower accuracy and slower
+    /// average keystroke time, floored at `MIN_PRACTICE_RATE` so even a
+    /// mastered key keeps a small chance of appearing.
+    fn practice_rate(&self, key: char) -> f64 {
+        let stats = match self.analytics.key_stats.get(&key) {
+            Some(stats) => stats,
+            None => return MIN_PRACTICE_RATE,
+        };
+
+        let accuracy_deficit = 100.0 - stats.accuracy();
+        let slowness_penalty = stats.average_time_ms() / SLOWNESS_DIVISOR;
+
+        (accuracy_deficit + slowness_penalty).max(MIN_PRACTICE_RATE)
+    }
+
+    /// Generate varied patterns: repetitions, alternations, triplets.
+    /// When one of `risky_digraphs` can be formed from `keys`, the alternation
+    /// branch is biased toward drilling that specific high-risk transition
+    /// instead of pairing keys purely at random.
+    fn generate_pattern(&mut self, keys: &[char], risky_digraphs: &[(char, char)]) -> String {
+        if let Some(&(a, b)) = risky_digraphs
+            .iter()
+            .find(|(a, b)| keys.contains(a) && keys.contains(b))
+        {
+            if self.rng.gen_bool(0.6) {
+                return format!("{}{} {}{}", a, b, a, b);
+            }
+        }
+
+        let pattern_type: u8 = self.rng.gen_range(0..3);
 
         match pattern_type {
             0 => {
                 // Repetition: "ff" or "ff ff"
-                let key = keys[rng.gen_range(0..keys.len())];
-                if rng.gen_bool(0.5) {
+                let key = keys[self.rng.gen_range(0..keys.len())];
+                if self.rng.gen_bool(0.5) {
                     format!("{}{}", key, key)
                 } else {
                     format!("{}{} {}{}", key, key, key, key)
@@ -111,8 +224,8 @@ impl<'a> AdaptiveLessonGenerator<'a> {
             1 => {
                 // Alternation: "fj fj"
                 if keys.len() >= 2 {
-                    let k1 = keys[rng.gen_range(0..keys.len())];
-                    let k2 = keys[rng.gen_range(0..keys.len())];
+                    let k1 = keys[self.rng.gen_range(0..keys.len())];
+                    let k2 = keys[self.rng.gen_range(0..keys.len())];
                     format!("{}{} {}{}", k1, k2, k1, k2)
                 } else {
                     let key = keys[0];
@@ -122,13 +235,13 @@ impl<'a> AdaptiveLessonGenerator<'a> {
             _ => {
                 // Triplet or sequence: "fjd"
                 if keys.len() >= 3 {
-                    let k1 = keys[rng.gen_range(0..keys.len())];
-                    let k2 = keys[rng.gen_range(0..keys.len())];
-                    let k3 = keys[rng.gen_range(0..keys.len())];
+                    let k1 = keys[self.rng.gen_range(0..keys.len())];
+                    let k2 = keys[self.rng.gen_range(0..keys.len())];
+                    let k3 = keys[self.rng.gen_range(0..keys.len())];
                     format!("{}{}{}", k1, k2, k3)
                 } else if keys.len() >= 2 {
-                    let k1 = keys[rng.gen_range(0..keys.len())];
-                    let k2 = keys[rng.gen_range(0..keys.len())];
+                    let k1 = keys[self.rng.gen_range(0..keys.len())];
+                    let k2 = keys[self.rng.gen_range(0..keys.len())];
                     format!("{}{}", k1, k2)
                 } else {
                     let key = keys[0];
@@ -193,10 +306,28 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_content() {
+    fn test_practice_rate_reflects_accuracy_deficit() {
+        let analytics = create_test_analytics();
+        let generator = AdaptiveLessonGenerator::new(&analytics);
+
+        // 'd' (70% accuracy) has a larger deficit than 's' (84%) or 'f' (96%)
+        assert!(generator.practice_rate('d') > generator.practice_rate('s'));
+        assert!(generator.practice_rate('s') > generator.practice_rate('f'));
+    }
+
+    #[test]
+    fn test_practice_rate_floors_at_minimum_for_untracked_key() {
         let analytics = create_test_analytics();
         let generator = AdaptiveLessonGenerator::new(&analytics);
 
+        assert_eq!(generator.practice_rate('z'), MIN_PRACTICE_RATE);
+    }
+
+    #[test]
+    fn test_generate_content() {
+        let analytics = create_test_analytics();
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
+
         let content = generator.generate(100);
 
         assert!(!content.is_empty());
@@ -206,7 +337,7 @@ mod tests {
     #[test]
     fn test_generate_contains_weak_keys() {
         let analytics = create_test_analytics();
-        let generator = AdaptiveLessonGenerator::new(&analytics);
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
 
         let content = generator.generate(200);
 
@@ -217,7 +348,7 @@ mod tests {
     #[test]
     fn test_fallback_balanced_practice() {
         let analytics = AdaptiveAnalytics::default();
-        let generator = AdaptiveLessonGenerator::new(&analytics);
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
 
         let content = generator.generate(50);
 
@@ -266,10 +397,30 @@ mod tests {
         assert!(!mastered.contains(&'s'));
     }
 
+    #[test]
+    fn test_generate_still_works_with_risky_digraphs_present() {
+        use crate::engine::analytics::BigramStats;
+
+        let mut analytics = create_test_analytics();
+        // 'd' following 's' is frequently mistyped; seed enough samples for
+        // the digraph model to trust the per-key breakdown and feed it into
+        // generate_pattern's alternation branch.
+        let mut sd_stats = BigramStats::new("sd".to_string());
+        sd_stats.total_attempts = 20;
+        sd_stats.correct_attempts = 4;
+        analytics.bigram_stats.insert("sd".to_string(), sd_stats);
+
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
+        let content = generator.generate(300);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 300);
+    }
+
     #[test]
     fn test_multiple_generations_vary() {
         let analytics = create_test_analytics();
-        let generator = AdaptiveLessonGenerator::new(&analytics);
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
 
         let content1 = generator.generate(80);
         let content2 = generator.generate(80);
@@ -280,4 +431,59 @@ mod tests {
         assert!(!content1.is_empty());
         assert!(!content2.is_empty());
     }
+
+    #[test]
+    fn test_with_seed_is_deterministic() {
+        let analytics = create_test_analytics();
+
+        let mut generator_a = AdaptiveLessonGenerator::with_seed(&analytics, 42);
+        let mut generator_b = AdaptiveLessonGenerator::with_seed(&analytics, 42);
+
+        assert_eq!(generator_a.generate(200), generator_b.generate(200));
+    }
+
+    #[test]
+    fn test_with_seed_differs_across_seeds() {
+        let analytics = create_test_analytics();
+
+        let mut generator_a = AdaptiveLessonGenerator::with_seed(&analytics, 1);
+        let mut generator_b = AdaptiveLessonGenerator::with_seed(&analytics, 2);
+
+        assert_ne!(generator_a.generate(200), generator_b.generate(200));
+    }
+
+    #[test]
+    fn test_generate_words_respects_length() {
+        let analytics = create_test_analytics();
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
+
+        // Without the `wordlist-en` feature enabled, this falls back to
+        // pattern-based generation; either way the output stays well-formed.
+        let content = generator.generate_words(100);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 100);
+    }
+
+    #[test]
+    fn test_generate_content_selector_dispatches_to_words() {
+        let analytics = create_test_analytics();
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
+
+        let content = generator.generate_content(AdaptiveContentMode::Words, 80);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 80);
+    }
+
+    #[test]
+    fn test_generate_content_selector_dispatches_to_patterns() {
+        let analytics = create_test_analytics();
+        let mut generator = AdaptiveLessonGenerator::new(&analytics);
+
+        let content = generator.generate_content(AdaptiveContentMode::Patterns, 80);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 80);
+    }
 }
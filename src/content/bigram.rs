@@ -1,5 +1,7 @@
 /// Bigram training support for typing practice
 /// Bigrams are common two-letter combinations that improve typing fluency
+use std::collections::HashMap;
+
 /// Language for natural bigrams
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Language {
@@ -7,20 +9,40 @@ pub enum Language {
     English,
 }
 
+impl Language {
+    /// Detect the most likely language of `sample` by comparing its trigram
+    /// frequencies against each language's trigram model, so the tool can
+    /// auto-configure when a user pastes text they want to practice on
+    pub fn detect(sample: &str) -> Language {
+        super::trigram::detect_language(sample)
+    }
+}
+
 /// Type of bigram practice
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum BigramType {
-    Natural, // Language bigrams (qu, th, er)
-    Code,    // Programming symbols (-> :: =>)
+    Natural,             // Language bigrams (qu, th, er)
+    Code,                // Programming symbols (-> :: =>)
+    Custom(Vec<Bigram>), // User-corpus-derived bigrams, see `BigramType::from_corpus`
+}
+
+impl BigramType {
+    /// A `Custom` bigram type whose table is derived from the user's own
+    /// `text` (see `bigrams_from_corpus`), so drills can target material
+    /// like a codebase's identifiers or a specialty vocabulary instead of
+    /// the crate's hardcoded French/English tables.
+    pub fn from_corpus(text: &str, top_n: usize) -> Self {
+        BigramType::Custom(bigrams_from_corpus(text, top_n))
+    }
 }
 
 /// A single bigram with frequency and example words
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bigram {
     pub pattern: String,
-    /// Phase 3: Natural language frequency weighting for future spaced repetition algorithms
-    #[allow(dead_code)]
-    pub frequency: f32, // 0.0 to 1.0, higher = more common (for future use)
+    /// 0.0 to 1.0, higher = more common. Seeds a bigram's initial review
+    /// priority in `bigram_scheduler::BigramScheduler`.
+    pub frequency: f32,
     pub examples: Vec<String>,
 }
 
@@ -865,6 +887,68 @@ pub fn english_bigrams() -> Vec<Bigram> {
     ]
 }
 
+/// Derive a frequency-ordered `Vec<Bigram>` from arbitrary `text` (a
+/// user-supplied book, code dump, etc.) instead of a hand-curated table.
+/// Lowercases the corpus and splits it into words on non-letter boundaries
+/// (accented characters count as letters and stay inside words), slides a
+/// 2-char window over each word to count every bigram, and keeps a small
+/// set of the shortest distinct words containing each bigram (capped at 10)
+/// as `examples`. Keeps the top `top_n` bigrams by raw count, then rescales
+/// their counts linearly into the existing 0.70-1.00 `frequency` range (max
+/// count -> 1.00, min selected count -> 0.70) so the result drops straight
+/// into the same frequency-ordered pipeline as `french_bigrams`/`english_bigrams`.
+pub fn bigrams_from_corpus(text: &str, top_n: usize) -> Vec<Bigram> {
+    const MAX_EXAMPLES: usize = 10;
+
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    let mut examples: HashMap<String, Vec<String>> = HashMap::new();
+
+    let lowercased = text.to_lowercase();
+    for word in lowercased.split(|c: char| !c.is_alphabetic()) {
+        if word.len() < 2 {
+            continue;
+        }
+
+        let chars: Vec<char> = word.chars().collect();
+        for window in chars.windows(2) {
+            let pattern: String = window.iter().collect();
+            *counts.entry(pattern.clone()).or_insert(0) += 1;
+
+            let word_examples = examples.entry(pattern).or_default();
+            if !word_examples.iter().any(|w| w == word) {
+                word_examples.push(word.to_string());
+                word_examples.sort_by_key(|w| w.len());
+                word_examples.truncate(MAX_EXAMPLES);
+            }
+        }
+    }
+
+    let mut ranked: Vec<(String, u64)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(top_n);
+
+    let max_count = ranked.first().map(|(_, count)| *count).unwrap_or(0);
+    let min_count = ranked.last().map(|(_, count)| *count).unwrap_or(0);
+    let range = (max_count - min_count) as f32;
+
+    ranked
+        .into_iter()
+        .map(|(pattern, count)| {
+            let frequency = if range == 0.0 {
+                1.00
+            } else {
+                0.70 + (count - min_count) as f32 / range * 0.30
+            };
+            let words = examples.remove(&pattern).unwrap_or_default();
+            Bigram {
+                pattern,
+                frequency,
+                examples: words,
+            }
+        })
+        .collect()
+}
+
 /// Code/programming bigrams (frequency-ordered)
 pub fn code_bigrams() -> Vec<Bigram> {
     vec![
@@ -945,14 +1029,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bigrams_from_corpus_orders_by_frequency() {
+        let corpus = "the quick brown fox the lazy dog the fox runs";
+        let bigrams = bigrams_from_corpus(corpus, 5);
+
+        assert_eq!(bigrams.len(), 5);
+        for i in 0..bigrams.len() - 1 {
+            assert!(bigrams[i].frequency >= bigrams[i + 1].frequency);
+        }
+        // "th"/"he" each appear 3 times (the, the, the), the most of any bigram
+        assert_eq!(bigrams[0].frequency, 1.00);
+        assert!(bigrams.iter().any(|b| b.pattern == "th"));
+    }
+
+    #[test]
+    fn test_bigrams_from_corpus_rescales_into_expected_range() {
+        let bigrams = bigrams_from_corpus("the quick brown fox the lazy dog the fox runs", 5);
+        for bigram in &bigrams {
+            assert!(bigram.frequency >= 0.70 && bigram.frequency <= 1.00);
+        }
+    }
+
+    #[test]
+    fn test_bigrams_from_corpus_examples_contain_pattern() {
+        let bigrams = bigrams_from_corpus("the quick brown fox the lazy dog the fox runs", 5);
+        for bigram in &bigrams {
+            assert!(!bigram.examples.is_empty());
+            for example in &bigram.examples {
+                assert!(example.contains(&bigram.pattern));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bigrams_from_corpus_keeps_accented_words_intact() {
+        let bigrams = bigrams_from_corpus("été étude étude été", 3);
+        assert!(bigrams.iter().any(|b| b.pattern == "ét"));
+        let et_bigram = bigrams.iter().find(|b| b.pattern == "ét").unwrap();
+        assert!(et_bigram.examples.iter().any(|w| w == "été"));
+    }
+
+    #[test]
+    fn test_bigrams_from_corpus_empty_text_is_empty() {
+        assert!(bigrams_from_corpus("", 10).is_empty());
+    }
+
     #[test]
     fn test_examples_contain_bigrams() {
+        use crate::content::bigram_match::{bigram_matches, MatchingPolicy};
+
         let all = vec![french_bigrams(), english_bigrams()];
         for set in all {
             for bigram in set {
                 for example in &bigram.examples {
+                    // NFC-normalized comparison rather than raw
+                    // `to_lowercase().contains`, so a composed ("é") vs.
+                    // decomposed ("e" + combining acute) accent in either
+                    // side can't make a genuinely-matching example fail
                     assert!(
-                        example.to_lowercase().contains(&bigram.pattern),
+                        bigram_matches(&bigram.pattern, example, MatchingPolicy::Strict),
                         "Example '{}' should contain bigram '{}'",
                         example,
                         bigram.pattern
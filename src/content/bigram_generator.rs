@@ -1,5 +1,24 @@
 /// Content generator for bigram training lessons
+use std::collections::{HashMap, HashSet};
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
 use super::bigram::{code_bigrams, english_bigrams, french_bigrams, Bigram, BigramType, Language};
+use super::language_detector;
+use crate::engine::analytics::{AdaptiveAnalytics, SessionAnalysis};
+
+/// How many distinct weak bigrams a retry drill targets at most
+const MAX_RETRY_BIGRAMS: usize = 5;
+/// Consecutive times a weak bigram's example word repeats before moving on
+const RETRY_EXAMPLE_REPEATS: usize = 3;
+/// Insert one mastered word after every this-many weak words, for rhythm
+const RETRY_MASTERED_INTERVAL: usize = 3;
+/// Accuracy a bigram needs in the accumulated analytics to count as "mastered"
+const RETRY_MASTERED_ACCURACY: f64 = 90.0;
+/// Minimum attempts a bigram needs before its accuracy is trusted as "mastered"
+const RETRY_MASTERED_MIN_ATTEMPTS: usize = 5;
 
 pub struct BigramGenerator {
     bigrams: Vec<Bigram>,
@@ -14,17 +33,30 @@ impl BigramGenerator {
                 None => french_bigrams(), // Default to French
             },
             BigramType::Code => code_bigrams(),
+            BigramType::Custom(bigrams) => bigrams,
         };
 
         Self { bigrams }
     }
 
+    /// Build a `Natural` bigram generator for `text`'s auto-detected language
+    /// (see `language_detector::detect_language`, the same detector
+    /// `CommonWordGenerator::for_text` uses), instead of forcing the caller
+    /// to pick French or English up front.
+    pub fn for_text(text: &str) -> Self {
+        Self::new(BigramType::Natural, Some(language_detector::detect_language(text)))
+    }
+
     /// Generate content for a given level
     /// Level 1: Drill mode (pure repetition)
     /// Level 2: Word mode (contextual words)
     /// Level 3: Mixed mode (realistic sentences)
     /// Level 4: Mixed mode with all bigrams
     pub fn generate(&self, level: usize, length: usize) -> String {
+        if self.bigrams.is_empty() {
+            return String::new();
+        }
+
         let selected_bigrams = self.select_bigrams_for_level(level);
 
         match level {
@@ -120,6 +152,251 @@ impl BigramGenerator {
 
         result.chars().take(length).collect()
     }
+
+    /// Markov mode: natural-feeling prose instead of `generate_mixed_mode`'s
+    /// fixed cycling. At each step, the next bigram is chosen with
+    /// probability proportional to how often it follows the previous one in
+    /// the crate's own example-word lists (see `bigram_transition_model`),
+    /// falling back to the candidate's own `frequency` when no such
+    /// transition is recorded; one of its example words is then appended.
+    /// `seed` makes a run reproducible for tests while still varying freely
+    /// across real sessions when callers pass a fresh seed.
+    pub fn generate_markov_mode(&self, level: usize, length: usize, seed: u64) -> String {
+        let selected_bigrams = self.select_bigrams_for_level(level);
+        if selected_bigrams.is_empty() {
+            return String::new();
+        }
+
+        let transitions = bigram_transition_model(&selected_bigrams);
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut result = String::new();
+        let mut prev: Option<&str> = None;
+
+        while result.chars().count() < length {
+            let bigram = choose_next_bigram(&selected_bigrams, prev, &transitions, &mut rng);
+            let example_idx = rng.gen_range(0..bigram.examples.len());
+
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(&bigram.examples[example_idx]);
+            prev = Some(bigram.pattern.as_str());
+        }
+
+        result.chars().take(length).collect()
+    }
+
+    /// Post-session retry drill: takes this session's per-key/per-bigram
+    /// performance plus the accumulated `AdaptiveAnalytics`, picks the keys
+    /// with the worst accuracy and the bigrams with the slowest average
+    /// timing, and emits a practice string that over-represents that weak
+    /// material. Each targeted bigram's example word repeats
+    /// `RETRY_EXAMPLE_REPEATS` times before moving to the next one, with a
+    /// word from a bigram the learner has already mastered interleaved every
+    /// `RETRY_MASTERED_INTERVAL` words for rhythm. Returns an empty string if
+    /// the session shows no weak bigrams to retry.
+    pub fn generate_retry_drill(
+        &self,
+        analysis: &SessionAnalysis,
+        analytics: &AdaptiveAnalytics,
+        length: usize,
+    ) -> String {
+        let weak_bigrams = self.weak_bigrams_for_retry(analysis);
+        if weak_bigrams.is_empty() {
+            return String::new();
+        }
+
+        let mastered_words = self.mastered_words_for_rhythm(analytics);
+
+        let mut result = String::new();
+        let mut word_count = 0;
+
+        while result.len() < length {
+            if word_count > 0 {
+                result.push(' ');
+            }
+
+            let block_idx = word_count / RETRY_EXAMPLE_REPEATS;
+            let bigram = weak_bigrams[block_idx % weak_bigrams.len()];
+            let example_idx = (block_idx / weak_bigrams.len()) % bigram.examples.len();
+            result.push_str(&bigram.examples[example_idx]);
+            word_count += 1;
+
+            if !mastered_words.is_empty()
+                && word_count % RETRY_MASTERED_INTERVAL == 0
+                && result.len() < length
+            {
+                result.push(' ');
+                let mastered_idx = (word_count / RETRY_MASTERED_INTERVAL - 1) % mastered_words.len();
+                result.push_str(mastered_words[mastered_idx]);
+            }
+        }
+
+        result.chars().take(length).collect()
+    }
+
+    /// The bigrams to target in a retry drill: this session's slowest
+    /// bigrams by average timing, topped up with a bigram containing each of
+    /// this session's worst-accuracy keys (if this generator's table has
+    /// one), up to `MAX_RETRY_BIGRAMS` total. Only bigrams present in this
+    /// generator's own table are returned, so a retry drill never practices
+    /// material outside the active language/bigram type.
+    fn weak_bigrams_for_retry(&self, analysis: &SessionAnalysis) -> Vec<&Bigram> {
+        let mut slow_patterns: Vec<(&String, f64)> = analysis
+            .bigram_performance
+            .iter()
+            .filter(|(_, perf)| !perf.timings.is_empty())
+            .map(|(pattern, perf)| {
+                let total_ms: f64 = perf.timings.iter().map(|d| d.as_secs_f64() * 1000.0).sum();
+                (pattern, total_ms / perf.timings.len() as f64)
+            })
+            .collect();
+        slow_patterns.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(b.0))
+        });
+
+        let mut weak_keys: Vec<(&char, f64)> = analysis
+            .key_performance
+            .iter()
+            .filter(|(_, perf)| !perf.errors.is_empty())
+            .map(|(key, perf)| (key, perf.errors.len() as f64 / perf.total_attempts as f64))
+            .collect();
+        weak_keys.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(b.0))
+        });
+
+        let mut targets: Vec<&Bigram> = Vec::new();
+        for (pattern, _) in slow_patterns {
+            if targets.len() >= MAX_RETRY_BIGRAMS {
+                break;
+            }
+            if let Some(bigram) = self.bigrams.iter().find(|b| &b.pattern == pattern) {
+                targets.push(bigram);
+            }
+        }
+        for (key, _) in weak_keys {
+            if targets.len() >= MAX_RETRY_BIGRAMS {
+                break;
+            }
+            if let Some(bigram) = self.bigrams.iter().find(|b| b.pattern.contains(*key)) {
+                if !targets.iter().any(|t| t.pattern == bigram.pattern) {
+                    targets.push(bigram);
+                }
+            }
+        }
+
+        targets
+    }
+
+    /// One example word per bigram the learner has already mastered
+    /// (`RETRY_MASTERED_ACCURACY`+ accuracy over at least
+    /// `RETRY_MASTERED_MIN_ATTEMPTS` attempts), for interleaving into a
+    /// retry drill "for rhythm".
+    fn mastered_words_for_rhythm(&self, analytics: &AdaptiveAnalytics) -> Vec<&str> {
+        let mut mastered: Vec<&str> = analytics
+            .bigram_stats
+            .values()
+            .filter(|stats| {
+                stats.total_attempts >= RETRY_MASTERED_MIN_ATTEMPTS
+                    && stats.accuracy() >= RETRY_MASTERED_ACCURACY
+            })
+            .filter_map(|stats| self.bigrams.iter().find(|b| b.pattern == stats.bigram))
+            .filter_map(|bigram| bigram.examples.first())
+            .map(String::as_str)
+            .collect();
+        mastered.sort_unstable();
+        mastered.dedup();
+        mastered
+    }
+}
+
+/// Count how often one bigram's pattern immediately follows another's inside
+/// any example word in `bigrams` (overlapping 2-char windows), then
+/// normalize each "from" bigram's outgoing counts into relative
+/// frequencies. Derived straight from the crate's own example-word lists
+/// rather than a separate hardcoded transition table, the same idea
+/// `trigram::letter_language_model` uses for its per-letter frequencies.
+fn bigram_transition_model<'a>(bigrams: &[&'a Bigram]) -> HashMap<&'a str, HashMap<&'a str, f32>> {
+    let known: HashSet<&'a str> = bigrams.iter().map(|b| b.pattern.as_str()).collect();
+
+    // Dedup example words before counting: several bigrams can list the same
+    // word (e.g. "maison" under both "ma" and "on"), and counting it once per
+    // listing would over-weight whichever bigrams happen to be cross-referenced
+    // rather than reflecting how often the transition actually occurs.
+    let examples: HashSet<&str> = bigrams
+        .iter()
+        .flat_map(|bigram| bigram.examples.iter().map(String::as_str))
+        .collect();
+
+    let mut counts: HashMap<&'a str, HashMap<&'a str, u32>> = HashMap::new();
+
+    for example in examples {
+        let chars: Vec<char> = example.chars().collect();
+        if chars.len() < 3 {
+            continue;
+        }
+
+        for window in chars.windows(3) {
+            let from: String = window[0..2].iter().collect();
+            let to: String = window[1..3].iter().collect();
+
+            if let (Some(&from_pattern), Some(&to_pattern)) =
+                (known.get(from.as_str()), known.get(to.as_str()))
+            {
+                *counts
+                    .entry(from_pattern)
+                    .or_default()
+                    .entry(to_pattern)
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(from, to_counts)| {
+            let total: u32 = to_counts.values().sum();
+            let freqs = to_counts
+                .into_iter()
+                .map(|(to, count)| (to, count as f32 / total as f32))
+                .collect();
+            (from, freqs)
+        })
+        .collect()
+}
+
+/// Weighted-pick the next bigram to emit: if `prev` has recorded
+/// transitions, each candidate's weight is its transition frequency from
+/// `prev` when known, falling back to the candidate's own unigram
+/// `frequency` otherwise; with no `prev` (the first word) every candidate
+/// uses its unigram `frequency` directly.
+fn choose_next_bigram<'a>(
+    bigrams: &[&'a Bigram],
+    prev: Option<&str>,
+    transitions: &HashMap<&str, HashMap<&str, f32>>,
+    rng: &mut StdRng,
+) -> &'a Bigram {
+    let next_freqs = prev.and_then(|p| transitions.get(p));
+
+    let weights: Vec<f32> = bigrams
+        .iter()
+        .map(|bigram| {
+            next_freqs
+                .and_then(|freqs| freqs.get(bigram.pattern.as_str()))
+                .copied()
+                .unwrap_or(bigram.frequency)
+        })
+        .collect();
+
+    match WeightedIndex::new(&weights) {
+        Ok(dist) => bigrams[dist.sample(rng)],
+        Err(_) => bigrams[0],
+    }
 }
 
 #[cfg(test)]
@@ -184,6 +461,226 @@ mod tests {
         assert!(content.contains("->") || content.contains("::") || content.contains("=>"));
     }
 
+    #[test]
+    fn test_for_text_detects_french() {
+        let text = "le renard brun rapide saute par dessus le chien paresseux sans rien penser";
+        let gen = BigramGenerator::for_text(text);
+        let content = gen.generate(1, 30);
+
+        assert!(content.contains("es es es") || content.contains("le le le"));
+    }
+
+    #[test]
+    fn test_for_text_detects_english() {
+        let text = "the quick brown fox jumps over the lazy dog while thinking about nothing else";
+        let gen = BigramGenerator::for_text(text);
+        let content = gen.generate(1, 30);
+
+        assert!(content.contains("th th th") || content.contains("he he he"));
+    }
+
+    #[test]
+    fn test_for_text_falls_back_to_heuristic_for_short_sample() {
+        // Too short for n-gram scoring; language_detector's diacritic
+        // heuristic should still catch the French accent.
+        let gen = BigramGenerator::for_text("café");
+        let content = gen.generate(1, 30);
+
+        assert!(content.contains("es es es") || content.contains("le le le"));
+    }
+
+    #[test]
+    fn test_custom_bigrams_from_corpus() {
+        let bigram_type = BigramType::from_corpus("the quick brown fox the lazy dog the fox", 3);
+        let gen = BigramGenerator::new(bigram_type, None);
+        let content = gen.generate(1, 30);
+
+        assert!(!content.is_empty());
+    }
+
+    #[test]
+    fn test_custom_bigrams_from_empty_corpus_yields_empty_string() {
+        let bigram_type = BigramType::from_corpus("", 3);
+        let gen = BigramGenerator::new(bigram_type, None);
+
+        assert_eq!(gen.generate(1, 30), String::new());
+        assert_eq!(gen.generate(2, 30), String::new());
+        assert_eq!(gen.generate(3, 30), String::new());
+    }
+
+    #[test]
+    fn test_retry_drill_empty_session_yields_empty_string() {
+        use crate::engine::analytics::SessionAnalysis;
+        use std::collections::HashMap;
+
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+        let analysis = SessionAnalysis {
+            key_performance: HashMap::new(),
+            bigram_performance: HashMap::new(),
+            rollover_count: 0,
+        };
+        let analytics = AdaptiveAnalytics::default();
+
+        assert_eq!(gen.generate_retry_drill(&analysis, &analytics, 50), "");
+    }
+
+    #[test]
+    fn test_retry_drill_targets_slowest_bigram() {
+        use crate::engine::analytics::{KeyPerformance, SessionAnalysis};
+        use std::collections::HashMap;
+        use std::time::Duration;
+
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+
+        let mut bigram_performance = HashMap::new();
+        bigram_performance.insert(
+            "es".to_string(),
+            KeyPerformance {
+                total_attempts: 4,
+                correct_attempts: 4,
+                timings: vec![Duration::from_millis(900)],
+                ..Default::default()
+            },
+        );
+        bigram_performance.insert(
+            "le".to_string(),
+            KeyPerformance {
+                total_attempts: 4,
+                correct_attempts: 4,
+                timings: vec![Duration::from_millis(100)],
+                ..Default::default()
+            },
+        );
+
+        let analysis = SessionAnalysis {
+            key_performance: HashMap::new(),
+            bigram_performance,
+            rollover_count: 0,
+        };
+        let analytics = AdaptiveAnalytics::default();
+
+        let content = gen.generate_retry_drill(&analysis, &analytics, 60);
+
+        // "es" was far slower on average, so its examples should dominate
+        assert!(content.contains("les") || content.contains("des") || content.contains("test"));
+    }
+
+    #[test]
+    fn test_retry_drill_interleaves_mastered_word() {
+        use crate::engine::analytics::{BigramStats, KeyPerformance, SessionAnalysis};
+        use std::collections::HashMap;
+        use std::time::Duration;
+
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+
+        let mut bigram_performance = HashMap::new();
+        bigram_performance.insert(
+            "qu".to_string(),
+            KeyPerformance {
+                total_attempts: 4,
+                correct_attempts: 2,
+                timings: vec![Duration::from_millis(900)],
+                ..Default::default()
+            },
+        );
+
+        let analysis = SessionAnalysis {
+            key_performance: HashMap::new(),
+            bigram_performance,
+            rollover_count: 0,
+        };
+
+        let mut mastered = BigramStats::new("de".to_string());
+        mastered.total_attempts = 30;
+        mastered.correct_attempts = 29;
+
+        let mut analytics = AdaptiveAnalytics::default();
+        analytics.bigram_stats.insert("de".to_string(), mastered);
+
+        let content = gen.generate_retry_drill(&analysis, &analytics, 60);
+
+        assert!(content.split_whitespace().any(|word| word == "de"));
+    }
+
+    #[test]
+    fn test_markov_mode_respects_length() {
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+        let content = gen.generate_markov_mode(3, 60, 42);
+
+        assert!(!content.is_empty());
+        assert!(content.chars().count() <= 60);
+    }
+
+    #[test]
+    fn test_markov_mode_is_deterministic_for_a_given_seed() {
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+
+        let first = gen.generate_markov_mode(3, 80, 7);
+        let second = gen.generate_markov_mode(3, 80, 7);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_markov_mode_varies_with_seed() {
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+
+        let a = gen.generate_markov_mode(4, 120, 1);
+        let b = gen.generate_markov_mode(4, 120, 2);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_markov_mode_only_uses_words_from_selected_bigrams() {
+        let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
+        let content = gen.generate_markov_mode(1, 80, 3);
+
+        let allowed_words: std::collections::HashSet<&str> = gen
+            .select_bigrams_for_level(1)
+            .iter()
+            .flat_map(|b| b.examples.iter().map(String::as_str))
+            .collect();
+
+        for word in content.split_whitespace() {
+            assert!(allowed_words.contains(word), "unexpected word {word}");
+        }
+    }
+
+    #[test]
+    fn test_bigram_transition_model_counts_adjacent_patterns_from_examples() {
+        let bigrams = vec![
+            Bigram::new("le", 1.0, &["lettre"]),
+            Bigram::new("et", 0.9, &["lettre"]),
+            Bigram::new("tt", 0.8, &["lettre"]),
+        ];
+        let refs: Vec<&Bigram> = bigrams.iter().collect();
+
+        let model = bigram_transition_model(&refs);
+
+        assert_eq!(model.get("le").and_then(|m| m.get("et")), Some(&1.0));
+    }
+
+    #[test]
+    fn test_bigram_transition_model_counts_shared_example_word_once() {
+        // "lettre" is listed under both "le" and "tt"; it must still count as
+        // a single occurrence of "le" -> "et", not once per listing bigram.
+        let bigrams = vec![
+            Bigram::new("le", 1.0, &["lettre"]),
+            Bigram::new("et", 0.9, &["lettre", "fete"]),
+            Bigram::new("tt", 0.8, &["lettre"]),
+        ];
+        let refs: Vec<&Bigram> = bigrams.iter().collect();
+
+        let model = bigram_transition_model(&refs);
+
+        let total_from_le: u32 = 1; // exactly one distinct occurrence counted
+        assert_eq!(
+            model.get("le").and_then(|m| m.get("et")).copied(),
+            Some(total_from_le as f32)
+        );
+    }
+
     #[test]
     fn test_level_progression() {
         let gen = BigramGenerator::new(BigramType::Natural, Some(Language::French));
@@ -0,0 +1,250 @@
+/// Persistent per-bigram mastery tracking: attempts, error count, and a
+/// rolling mean inter-keystroke latency, keyed by bigram `pattern` and kept
+/// across sessions (see `crate::data::storage::Storage::bigram_mastery_path`).
+/// Backs the `next` command's "give me the drill I most need" selector.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::bigram::Bigram;
+
+/// Weight of error rate in the weakness score
+const ERROR_WEIGHT: f64 = 0.6;
+/// Weight of normalized latency in the weakness score
+const SPEED_WEIGHT: f64 = 0.4;
+
+/// Attempts, errors, and rolling mean latency recorded for one bigram pattern
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct BigramMasteryEntry {
+    pub attempts: u32,
+    pub errors: u32,
+    pub mean_latency_ms: f64,
+}
+
+impl BigramMasteryEntry {
+    fn record(&mut self, correct: bool, latency_ms: f64) {
+        self.attempts += 1;
+        if !correct {
+            self.errors += 1;
+        }
+        self.mean_latency_ms += (latency_ms - self.mean_latency_ms) / self.attempts as f64;
+    }
+
+    fn error_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.attempts as f64
+        }
+    }
+}
+
+/// Persistent store of `BigramMasteryEntry`s, keyed by bigram pattern
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BigramMasteryStore {
+    entries: HashMap<String, BigramMasteryEntry>,
+}
+
+impl BigramMasteryStore {
+    /// Record one rep of `pattern`: whether it was typed correctly and the
+    /// inter-keystroke latency (in milliseconds) it took.
+    pub fn record_attempt(&mut self, pattern: &str, correct: bool, latency_ms: f64) {
+        self.entries
+            .entry(pattern.to_string())
+            .or_default()
+            .record(correct, latency_ms);
+    }
+
+    /// The entry recorded for `pattern`, if any reps have been logged
+    pub fn entry(&self, pattern: &str) -> Option<&BigramMasteryEntry> {
+        self.entries.get(pattern)
+    }
+
+    /// Mean of every tracked bigram's mean latency, the denominator
+    /// `weakness_score`'s `normalized_latency` term divides by. `0.0` (and
+    /// therefore a `0.0` normalized latency for everything) until at least
+    /// one attempt has been recorded anywhere.
+    fn overall_mean_latency(&self) -> f64 {
+        let tracked: Vec<f64> = self
+            .entries
+            .values()
+            .filter(|entry| entry.attempts > 0)
+            .map(|entry| entry.mean_latency_ms)
+            .collect();
+
+        if tracked.is_empty() {
+            0.0
+        } else {
+            tracked.iter().sum::<f64>() / tracked.len() as f64
+        }
+    }
+
+    /// `error_rate * ERROR_WEIGHT + normalized_latency * SPEED_WEIGHT`, where
+    /// `normalized_latency` is the bigram's own mean latency divided by
+    /// `overall_mean`. A bigram with no recorded attempts scores `0.0`, the
+    /// same as a perfectly mastered one; `next_weakest` only surfaces it
+    /// once it's actually been missed or typed slowly relative to the rest.
+    fn weakness_score(&self, pattern: &str, overall_mean: f64) -> f64 {
+        let Some(entry) = self.entries.get(pattern) else {
+            return 0.0;
+        };
+
+        let normalized_latency = if overall_mean > 0.0 {
+            entry.mean_latency_ms / overall_mean
+        } else {
+            0.0
+        };
+
+        entry.error_rate() * ERROR_WEIGHT + normalized_latency * SPEED_WEIGHT
+    }
+
+    /// The highest-scoring bigram in `candidates` that has at least one
+    /// example, the drill `typer next` builds a lesson from. `None` if
+    /// `candidates` has nothing with examples to offer.
+    pub fn next_weakest<'a>(&self, candidates: &'a [Bigram]) -> Option<&'a Bigram> {
+        let overall_mean = self.overall_mean_latency();
+
+        candidates
+            .iter()
+            .filter(|bigram| !bigram.examples.is_empty())
+            .max_by(|a, b| {
+                self.weakness_score(&a.pattern, overall_mean)
+                    .partial_cmp(&self.weakness_score(&b.pattern, overall_mean))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize bigram mastery store: {}", e),
+            )
+        })?;
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_attempt_tracks_attempts_and_errors() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", true, 100.0);
+        store.record_attempt("th", false, 200.0);
+
+        let entry = store.entry("th").unwrap();
+        assert_eq!(entry.attempts, 2);
+        assert_eq!(entry.errors, 1);
+    }
+
+    #[test]
+    fn test_record_attempt_tracks_rolling_mean_latency() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", true, 100.0);
+        store.record_attempt("th", true, 200.0);
+
+        assert_eq!(store.entry("th").unwrap().mean_latency_ms, 150.0);
+    }
+
+    #[test]
+    fn test_next_weakest_prefers_higher_error_rate() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", true, 100.0);
+        store.record_attempt("he", false, 100.0);
+
+        let candidates = vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("he", 0.90, &["her"]),
+        ];
+
+        assert_eq!(store.next_weakest(&candidates).unwrap().pattern, "he");
+    }
+
+    #[test]
+    fn test_next_weakest_prefers_slower_latency_when_errors_tied() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", true, 100.0);
+        store.record_attempt("he", true, 500.0);
+
+        let candidates = vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("he", 0.90, &["her"]),
+        ];
+
+        assert_eq!(store.next_weakest(&candidates).unwrap().pattern, "he");
+    }
+
+    #[test]
+    fn test_next_weakest_skips_bigrams_without_examples() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", false, 100.0);
+        store.record_attempt("he", true, 100.0);
+
+        let candidates = vec![
+            Bigram::new("th", 1.00, &[]),
+            Bigram::new("he", 0.90, &["her"]),
+        ];
+
+        assert_eq!(store.next_weakest(&candidates).unwrap().pattern, "he");
+    }
+
+    #[test]
+    fn test_next_weakest_none_when_no_candidates_have_examples() {
+        let store = BigramMasteryStore::default();
+        let candidates = vec![Bigram::new("th", 1.00, &[])];
+
+        assert!(store.next_weakest(&candidates).is_none());
+    }
+
+    #[test]
+    fn test_untracked_bigram_scores_zero_weakness() {
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", false, 100.0);
+
+        let candidates = vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("zq", 0.90, &["zqzq"]),
+        ];
+
+        // "zq" has never been attempted, so it scores 0.0 weakness and loses
+        // to "th", which has a recorded error.
+        assert_eq!(store.next_weakest(&candidates).unwrap().pattern, "th");
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("bigram_mastery.json");
+
+        let mut store = BigramMasteryStore::default();
+        store.record_attempt("th", false, 150.0);
+        store.save(&path).unwrap();
+
+        let loaded = BigramMasteryStore::load(&path);
+        assert_eq!(loaded.entry("th").unwrap().attempts, 1);
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_default() {
+        let store = BigramMasteryStore::load(Path::new("/no/such/file.json"));
+        assert!(store.entry("th").is_none());
+    }
+}
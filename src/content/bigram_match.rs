@@ -0,0 +1,289 @@
+/// Unicode-normalization-aware comparison for bigram patterns against
+/// example/corpus/typed text. Plain `str::contains` on raw text silently
+/// breaks for accented French once composed ("é", one code point) and
+/// decomposed ("e" + U+0301 combining acute accent) forms mix, which
+/// happens routinely once text comes from an external corpus or a user's
+/// own keystrokes rather than this crate's own hand-typed literals.
+///
+/// Both sides of a bigram comparison should go through `normalize` (or the
+/// `bigram_matches` convenience) under the same `MatchingPolicy` before
+/// being compared, so "é" and "e"+combining-acute are always treated as the
+/// same character, and — for beginners who haven't learned French accent
+/// keys yet — `MatchingPolicy::AccentFolding` additionally treats "é"/"è"/"ê"
+/// as a bare "e".
+use unicode_normalization::UnicodeNormalization;
+
+/// How strictly two bigram-related strings must match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchingPolicy {
+    /// NFC-normalize and lowercase before comparing, so composed and
+    /// decomposed forms of the same accented character are equal, but
+    /// distinct accented letters (or an accented letter vs. its bare form)
+    /// are not.
+    Strict,
+    /// `Strict`, plus folds accented Latin vowels/consonants (à/â/ä -> a,
+    /// é/è/ê/ë -> e, î/ï -> i, ô/ö -> o, ù/û/ü -> u, ç -> c, ÿ -> y) down to
+    /// their bare letter, so a pattern like "e" matches "é" too.
+    AccentFolding,
+}
+
+/// Normalize `text` under `policy`: NFC-normalize, lowercase, and (under
+/// `AccentFolding`) fold accented letters to their bare form.
+pub fn normalize(text: &str, policy: MatchingPolicy) -> String {
+    let nfc: String = text.nfc().collect();
+    let lowered = nfc.to_lowercase();
+
+    match policy {
+        MatchingPolicy::Strict => lowered,
+        MatchingPolicy::AccentFolding => lowered.chars().map(fold_accent).collect(),
+    }
+}
+
+/// Whether `candidate` contains `pattern`, comparing both under the same
+/// `MatchingPolicy` rather than assuming byte-identical lowercase forms.
+pub fn bigram_matches(pattern: &str, candidate: &str, policy: MatchingPolicy) -> bool {
+    normalize(candidate, policy).contains(&normalize(pattern, policy))
+}
+
+/// A small, user-editable table of input substitutions, for accents a
+/// layout or dead-key sequence can't produce cleanly (e.g. "ca" standing in
+/// for "ça" on a layout with no easy cedilla). Layered on top of
+/// `normalize`'s automatic NFC/accent-folding, which only folds single
+/// characters and can't express a multi-character stand-in like this.
+///
+/// Rules match blunt substrings, not whole words, by design (matching the
+/// "ad-hoc equivalence map" this is meant to be) — a rule like "ca" -> "ça"
+/// will also rewrite an unrelated correct "ca" inside a longer word. Keep
+/// rules specific to real accent gaps rather than short common substrings
+/// to avoid that.
+#[derive(Debug, Clone, Default)]
+pub struct SubstitutionTable {
+    /// `(typed, canonical)` pairs, longest `typed` first so e.g. a two-char
+    /// rule isn't shadowed by a one-char rule applied first.
+    rules: Vec<(String, String)>,
+}
+
+impl SubstitutionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register that typing `typed` should be accepted in place of
+    /// `canonical`. `typed` should be lowercase, since `apply` is always
+    /// called on already-lowercased text (see
+    /// `bigram_matches_with_substitutions`). A blank `typed` string is
+    /// ignored (it would match everywhere without consuming any input,
+    /// looping `apply` forever).
+    pub fn add_rule(&mut self, typed: &str, canonical: &str) {
+        if typed.is_empty() {
+            return;
+        }
+
+        self.rules.push((typed.to_string(), canonical.to_string()));
+        self.rules.sort_by_key(|(typed, _)| std::cmp::Reverse(typed.chars().count()));
+    }
+
+    /// Apply every registered rule to `text` in a single left-to-right pass
+    /// (longest `typed` pattern wins at each position), so a rule's
+    /// `canonical` output is never itself re-matched by a later rule the
+    /// way chaining `String::replace` calls one after another would.
+    pub fn apply(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let rules: Vec<(Vec<char>, &str)> = self
+            .rules
+            .iter()
+            .map(|(typed, canonical)| (typed.chars().collect(), canonical.as_str()))
+            .collect();
+
+        let mut result = String::with_capacity(text.len());
+        let mut i = 0;
+
+        'outer: while i < chars.len() {
+            for (typed_chars, canonical) in &rules {
+                let end = i + typed_chars.len();
+                if end <= chars.len() && chars[i..end] == typed_chars[..] {
+                    result.push_str(canonical);
+                    i = end;
+                    continue 'outer;
+                }
+            }
+
+            result.push(chars[i]);
+            i += 1;
+        }
+
+        result
+    }
+
+    /// A small starter table of common French accent/dead-key workarounds,
+    /// for layouts or dead-key sequences that can't produce them directly.
+    pub fn french_defaults() -> Self {
+        let mut table = Self::new();
+        table.add_rule("a`", "à");
+        table.add_rule("e'", "é");
+        table.add_rule("e`", "è");
+        table.add_rule("e^", "ê");
+        table.add_rule("o^", "ô");
+        table
+    }
+}
+
+/// Whether `typed` matches `pattern` under `policy`, after first running
+/// `typed` through `substitutions` so ad-hoc equivalences (not just
+/// automatic NFC/accent-folding) register as correct.
+pub fn bigram_matches_with_substitutions(
+    pattern: &str,
+    typed: &str,
+    policy: MatchingPolicy,
+    substitutions: &SubstitutionTable,
+) -> bool {
+    // Lowercase before substituting: rules are written lowercase (see
+    // `french_defaults`), and `bigram_matches` only lowercases *after* this
+    // point, so an uppercase/mixed-case keystroke would otherwise miss every rule.
+    bigram_matches(pattern, &substitutions.apply(&typed.to_lowercase()), policy)
+}
+
+/// Fold one accented Latin letter down to its bare form; anything else
+/// passes through unchanged.
+fn fold_accent(c: char) -> char {
+    match c {
+        'à' | 'â' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'î' | 'ï' => 'i',
+        'ô' | 'ö' => 'o',
+        'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        'ÿ' => 'y',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "é" as a single composed code point (NFC form)
+    const E_ACUTE_COMPOSED: &str = "\u{00e9}";
+    /// "e" followed by a combining acute accent (NFD form)
+    const E_ACUTE_DECOMPOSED: &str = "e\u{0301}";
+
+    #[test]
+    fn test_strict_normalizes_composed_and_decomposed_the_same() {
+        assert_eq!(
+            normalize(E_ACUTE_COMPOSED, MatchingPolicy::Strict),
+            normalize(E_ACUTE_DECOMPOSED, MatchingPolicy::Strict)
+        );
+    }
+
+    #[test]
+    fn test_strict_does_not_fold_accents() {
+        assert_ne!(
+            normalize(E_ACUTE_COMPOSED, MatchingPolicy::Strict),
+            normalize("e", MatchingPolicy::Strict)
+        );
+    }
+
+    #[test]
+    fn test_accent_folding_treats_e_acute_as_bare_e() {
+        assert_eq!(
+            normalize(E_ACUTE_COMPOSED, MatchingPolicy::AccentFolding),
+            normalize("e", MatchingPolicy::AccentFolding)
+        );
+    }
+
+    #[test]
+    fn test_bigram_matches_finds_decomposed_pattern_in_composed_candidate() {
+        let word = format!("caf{}", E_ACUTE_COMPOSED);
+        assert!(bigram_matches(
+            &format!("f{}", E_ACUTE_DECOMPOSED),
+            &word,
+            MatchingPolicy::Strict
+        ));
+    }
+
+    #[test]
+    fn test_bigram_matches_strict_rejects_bare_e_against_e_acute_word() {
+        let word = format!("caf{}", E_ACUTE_COMPOSED);
+        assert!(!bigram_matches("fe", &word, MatchingPolicy::Strict));
+    }
+
+    #[test]
+    fn test_bigram_matches_accent_folding_accepts_bare_e_against_e_acute_word() {
+        let word = format!("caf{}", E_ACUTE_COMPOSED);
+        assert!(bigram_matches("fe", &word, MatchingPolicy::AccentFolding));
+    }
+
+    #[test]
+    fn test_bigram_matches_is_case_insensitive() {
+        assert!(bigram_matches("TH", "The", MatchingPolicy::Strict));
+    }
+
+    #[test]
+    fn test_substitution_table_applies_registered_rule() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("ca", "ça");
+
+        assert_eq!(table.apply("ca va"), "ça va");
+    }
+
+    #[test]
+    fn test_substitution_table_prefers_longer_rules_first() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("a", "à"); // registered first, shorter
+        table.add_rule("ca", "ça"); // should still win over "a" within "ca"
+
+        assert_eq!(table.apply("ca"), "ça");
+    }
+
+    #[test]
+    fn test_bigram_matches_with_substitutions_accepts_typed_fallback() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("ca", "ça");
+
+        assert!(bigram_matches_with_substitutions("ça", "ca", MatchingPolicy::Strict, &table));
+    }
+
+    #[test]
+    fn test_bigram_matches_with_substitutions_is_case_insensitive() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("ca", "ça");
+
+        assert!(bigram_matches_with_substitutions("ça", "CA", MatchingPolicy::Strict, &table));
+    }
+
+    #[test]
+    fn test_bigram_matches_with_substitutions_rejects_unmapped_typed_text() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("ca", "ça");
+
+        assert!(!bigram_matches_with_substitutions("ça", "za", MatchingPolicy::Strict, &table));
+    }
+
+    #[test]
+    fn test_substitution_table_ignores_blank_typed_rule() {
+        let mut table = SubstitutionTable::new();
+        table.add_rule("", "x");
+
+        assert_eq!(table.apply("hello"), "hello");
+    }
+
+    #[test]
+    fn test_french_defaults_covers_common_dead_key_workarounds() {
+        let table = SubstitutionTable::french_defaults();
+
+        assert_eq!(table.apply("a`"), "à");
+        assert_eq!(table.apply("e'"), "é");
+        assert_eq!(table.apply("e`"), "è");
+    }
+
+    #[test]
+    fn test_french_defaults_leaves_plain_ca_alone() {
+        // AZERTY has a direct key for "ç" (see `keyboard::AzertyLayout`), so
+        // there's no dead-key gap to work around here, unlike `a``/`e'`/etc.
+        // A blunt "ca" -> "ça" rule would instead mangle every legitimate
+        // "ca" inside a longer word (e.g. "carte", "canard").
+        let table = SubstitutionTable::french_defaults();
+
+        assert_eq!(table.apply("carte"), "carte");
+    }
+}
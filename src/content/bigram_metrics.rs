@@ -0,0 +1,297 @@
+/// Keystroke-level metrics for bigram practice drills, inspired by
+/// keystroke-logging research: pause events, erasures, and time-per-char,
+/// all attributed down to the specific `Bigram` pattern being typed so the
+/// post-session report can rank which transitions are genuinely slow even
+/// when the user never makes an outright error. `BigramLatencyStats`'s
+/// `mean_latency`/`error_rate` are the natural inputs to grading a
+/// `bigram_scheduler::BigramScheduler` rep, once a caller wires them together.
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::engine::scoring::calculate_wpm;
+
+/// Gap between keystrokes at or above this duration counts as a short pause
+const SHORT_PAUSE_THRESHOLD: Duration = Duration::from_millis(300);
+/// Gap between keystrokes at or above this duration counts as a long pause
+const LONG_PAUSE_THRESHOLD: Duration = Duration::from_millis(1000);
+
+/// Latency and error-rate summary for one bigram pattern across a drill
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigramLatencyStats {
+    pub pattern: String,
+    /// Inter-key interval between the pattern's two characters, averaged
+    /// across every time it was typed this drill
+    pub mean_latency: Duration,
+    pub reps: u32,
+    pub errors: u32,
+}
+
+impl BigramLatencyStats {
+    pub fn error_rate(&self) -> f64 {
+        if self.reps == 0 {
+            return 0.0;
+        }
+        self.errors as f64 / self.reps as f64
+    }
+}
+
+/// Post-session keystroke metrics report, built by `BigramMetricsRecorder::report`
+#[derive(Debug, Clone, Default)]
+pub struct BigramMetricsReport {
+    pub chars_typed: usize,
+    pub erase_count: usize,
+    pub short_pause_count: usize,
+    pub short_pause_total: Duration,
+    pub long_pause_count: usize,
+    pub long_pause_total: Duration,
+    /// Average time between consecutive typed characters
+    pub time_per_char: Duration,
+    pub words_per_hour: f64,
+    /// Per-bigram latency/error stats, ranked slowest mean latency first
+    /// (ties broken by higher error rate), so the worst transitions surface
+    /// at the top of the report regardless of overall session speed
+    pub bigram_stats: Vec<BigramLatencyStats>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LatencyAccumulator {
+    total: Duration,
+    reps: u32,
+    errors: u32,
+}
+
+/// Records raw keystroke timings during a bigram drill and attributes them
+/// to both overall session metrics and the specific two-character `pattern`
+/// each keystroke completes.
+#[derive(Debug, Default)]
+pub struct BigramMetricsRecorder {
+    /// Elapsed time (since drill start) of every keystroke, typed or erased,
+    /// in arrival order; used to detect pauses between any two keystrokes
+    timestamps: Vec<Duration>,
+    erase_count: usize,
+    chars_typed: usize,
+    /// The most recently typed character and when it arrived, so the next
+    /// typed character can be attributed to the bigram it completes
+    previous: Option<(char, Duration)>,
+    latencies: HashMap<String, LatencyAccumulator>,
+}
+
+impl BigramMetricsRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a typed (non-erase) keystroke. `at` is elapsed time since the
+    /// drill started, matching `engine::types::CharInput::timestamp`.
+    pub fn record_typed(&mut self, c: char, is_correct: bool, at: Duration) {
+        self.timestamps.push(at);
+        self.chars_typed += 1;
+
+        if let Some((prev_char, prev_at)) = self.previous {
+            let pattern: String = [prev_char, c].iter().collect();
+            let latency = at.saturating_sub(prev_at);
+            let accumulator = self.latencies.entry(pattern).or_insert(LatencyAccumulator {
+                total: Duration::ZERO,
+                reps: 0,
+                errors: 0,
+            });
+            accumulator.total += latency;
+            accumulator.reps += 1;
+            if !is_correct {
+                accumulator.errors += 1;
+            }
+        }
+
+        self.previous = Some((c, at));
+    }
+
+    /// Record an erase (backspace) keystroke. Breaks bigram attribution:
+    /// the next typed character is paired with whatever follows the erase,
+    /// not with whatever was typed before it.
+    pub fn record_erase(&mut self, at: Duration) {
+        self.timestamps.push(at);
+        self.erase_count += 1;
+        self.previous = None;
+    }
+
+    /// Build the post-session report from every keystroke recorded so far.
+    pub fn report(&self) -> BigramMetricsReport {
+        let (short_pause_count, short_pause_total, long_pause_count, long_pause_total) =
+            self.pause_stats();
+
+        let elapsed = self.timestamps.last().copied().unwrap_or_default();
+        let time_per_char = if self.chars_typed > 0 {
+            elapsed / self.chars_typed as u32
+        } else {
+            Duration::ZERO
+        };
+        let words_per_hour = words_per_hour(self.chars_typed, elapsed);
+
+        let mut bigram_stats: Vec<BigramLatencyStats> = self
+            .latencies
+            .iter()
+            .map(|(pattern, acc)| BigramLatencyStats {
+                pattern: pattern.clone(),
+                mean_latency: acc.total / acc.reps.max(1),
+                reps: acc.reps,
+                errors: acc.errors,
+            })
+            .collect();
+        bigram_stats.sort_by(|a, b| {
+            b.mean_latency
+                .cmp(&a.mean_latency)
+                .then_with(|| b.error_rate().partial_cmp(&a.error_rate()).unwrap())
+        });
+
+        BigramMetricsReport {
+            chars_typed: self.chars_typed,
+            erase_count: self.erase_count,
+            short_pause_count,
+            short_pause_total,
+            long_pause_count,
+            long_pause_total,
+            time_per_char,
+            words_per_hour,
+            bigram_stats,
+        }
+    }
+
+    /// Count and total duration of gaps between consecutive keystrokes (of
+    /// either kind) at or above the short/long pause thresholds. A long
+    /// pause also counts toward the short-pause tallies, matching how a
+    /// 1.2s gap is both "a pause over 300ms" and "a pause over 1000ms".
+    fn pause_stats(&self) -> (usize, Duration, usize, Duration) {
+        let mut short_count = 0;
+        let mut short_total = Duration::ZERO;
+        let mut long_count = 0;
+        let mut long_total = Duration::ZERO;
+
+        for window in self.timestamps.windows(2) {
+            let gap = window[1].saturating_sub(window[0]);
+            if gap >= LONG_PAUSE_THRESHOLD {
+                long_count += 1;
+                long_total += gap;
+            }
+            if gap >= SHORT_PAUSE_THRESHOLD {
+                short_count += 1;
+                short_total += gap;
+            }
+        }
+
+        (short_count, short_total, long_count, long_total)
+    }
+}
+
+/// Words per hour is just WPM scaled up: reuse `engine::scoring::calculate_wpm`
+/// so the two reports can't drift on what counts as a "word"
+fn words_per_hour(chars_typed: usize, elapsed: Duration) -> f64 {
+    calculate_wpm(chars_typed, elapsed) * 60.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_recorder_has_empty_report() {
+        let recorder = BigramMetricsRecorder::new();
+        let report = recorder.report();
+        assert_eq!(report.chars_typed, 0);
+        assert_eq!(report.erase_count, 0);
+        assert!(report.bigram_stats.is_empty());
+    }
+
+    #[test]
+    fn test_erase_is_counted_and_does_not_attribute_a_bigram() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('t', true, Duration::from_millis(0));
+        recorder.record_erase(Duration::from_millis(100));
+        recorder.record_typed('h', true, Duration::from_millis(200));
+
+        let report = recorder.report();
+        assert_eq!(report.erase_count, 1);
+        assert!(report.bigram_stats.is_empty());
+    }
+
+    #[test]
+    fn test_consecutive_typed_chars_attribute_a_bigram() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('t', true, Duration::from_millis(0));
+        recorder.record_typed('h', true, Duration::from_millis(150));
+
+        let report = recorder.report();
+        assert_eq!(report.bigram_stats.len(), 1);
+        assert_eq!(report.bigram_stats[0].pattern, "th");
+        assert_eq!(report.bigram_stats[0].mean_latency, Duration::from_millis(150));
+    }
+
+    #[test]
+    fn test_bigram_error_is_reflected_in_error_rate() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('t', true, Duration::from_millis(0));
+        recorder.record_typed('h', false, Duration::from_millis(150));
+
+        let report = recorder.report();
+        assert_eq!(report.bigram_stats[0].error_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_short_pause_is_not_double_counted_as_long() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('a', true, Duration::from_millis(0));
+        recorder.record_typed('b', true, Duration::from_millis(400));
+
+        let report = recorder.report();
+        assert_eq!(report.short_pause_count, 1);
+        assert_eq!(report.long_pause_count, 0);
+    }
+
+    #[test]
+    fn test_long_pause_also_counts_as_short_pause() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('a', true, Duration::from_millis(0));
+        recorder.record_typed('b', true, Duration::from_millis(1500));
+
+        let report = recorder.report();
+        assert_eq!(report.short_pause_count, 1);
+        assert_eq!(report.long_pause_count, 1);
+        assert_eq!(report.long_pause_total, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_bigram_stats_rank_slowest_first() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('t', true, Duration::from_millis(0));
+        recorder.record_typed('h', true, Duration::from_millis(50));
+        recorder.record_erase(Duration::from_millis(60));
+        recorder.record_typed('e', true, Duration::from_millis(70));
+        recorder.record_typed('r', true, Duration::from_millis(500));
+
+        let report = recorder.report();
+        assert_eq!(report.bigram_stats[0].pattern, "er");
+        assert_eq!(report.bigram_stats[1].pattern, "th");
+    }
+
+    #[test]
+    fn test_words_per_hour_matches_chars_over_five_per_hour() {
+        let mut recorder = BigramMetricsRecorder::new();
+        for (i, c) in "hello".chars().enumerate() {
+            recorder.record_typed(c, true, Duration::from_secs(i as u64));
+        }
+        let report = recorder.report();
+        // 5 chars = 1 "word" typed over 4 seconds
+        let expected = 1.0 / (4.0 / 3600.0);
+        assert!((report.words_per_hour - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_time_per_char_is_total_elapsed_over_chars_typed() {
+        let mut recorder = BigramMetricsRecorder::new();
+        recorder.record_typed('a', true, Duration::from_millis(0));
+        recorder.record_typed('b', true, Duration::from_millis(100));
+        recorder.record_typed('c', true, Duration::from_millis(300));
+
+        let report = recorder.report();
+        assert_eq!(report.time_per_char, Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,304 @@
+/// Registry of named bigram tables, so adding a language to practice means
+/// dropping a data file into a config directory rather than editing Rust.
+/// `bigram::french_bigrams()`/`english_bigrams()` stay as the crate's
+/// embedded defaults; a TOML or JSON file discovered by `load_overrides`
+/// can extend the registry with a new language or replace an existing
+/// one's table by name, without recompiling.
+///
+/// `Language` (the fixed `French`/`English` enum in `bigram.rs`) is left
+/// untouched: it's also the key `trigram`/`inflection`/`word_markov` use
+/// for their own, unrelated data tables, so widening it into a registry
+/// lookup here would ripple across features that have nothing to do with
+/// bigram tables. Instead this registry is keyed by plain language-name
+/// strings ("french", "english", or any custom name a data file supplies).
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::bigram::{english_bigrams, french_bigrams, Bigram};
+
+/// On-disk shape of one bigram table file (TOML or JSON share this shape)
+#[derive(Debug, Deserialize)]
+struct BigramTableFile {
+    entries: Vec<BigramEntryConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BigramEntryConfig {
+    pattern: String,
+    frequency: f32,
+    #[serde(default)]
+    examples: Vec<String>,
+}
+
+/// Bigram tables keyed by language name, seeded from the crate's embedded
+/// defaults and extendable at runtime with data files from a config
+/// directory (see `load_overrides`).
+#[derive(Debug, Clone, Default)]
+pub struct BigramRegistry {
+    tables: HashMap<String, Vec<Bigram>>,
+}
+
+impl BigramRegistry {
+    /// A registry containing just the crate's embedded French/English tables
+    pub fn with_defaults() -> Self {
+        let mut tables = HashMap::new();
+        tables.insert("french".to_string(), french_bigrams());
+        tables.insert("english".to_string(), english_bigrams());
+        Self { tables }
+    }
+
+    /// Discover and load every `.toml`/`.json` bigram table file in `dir`,
+    /// keyed by file stem (`german.toml` registers as `"german"`). A stem
+    /// that matches an existing entry (e.g. a user's own `french.toml`)
+    /// replaces that language's table entirely. A missing `dir` isn't an
+    /// error, just nothing to load. A file that fails to parse or fails
+    /// `validate_table` is skipped with a diagnostic on stderr and the
+    /// registry keeps whatever it already had for that name, the same
+    /// degrade-rather-than-fail approach `AzertyLayout::from_config_file`
+    /// uses for layout overrides.
+    pub fn load_overrides(&mut self, dir: &Path) {
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return;
+        };
+
+        // Sorted so that if a directory somehow yields two files for the
+        // same language stem (e.g. `french.toml` and `french.json`), which
+        // one wins is deterministic rather than filesystem-order-dependent.
+        let mut paths: Vec<_> = read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let is_supported = path
+                .extension()
+                .is_some_and(|ext| ext == "toml" || ext == "json");
+            if !is_supported {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) else {
+                continue;
+            };
+
+            match load_table_file(&path) {
+                Ok(bigrams) => {
+                    self.tables.insert(name, bigrams);
+                }
+                Err(message) => {
+                    eprintln!(
+                        "warning: failed to load bigram table from {}: {message}; keeping existing table",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+
+    /// The bigram table registered under `name`, if any
+    pub fn get(&self, name: &str) -> Option<&[Bigram]> {
+        self.tables.get(name).map(Vec::as_slice)
+    }
+
+    /// Every registered language name, sorted for stable display
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tables.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Parse one bigram table file and validate it before accepting it into the
+/// registry. TOML and JSON share the same `{ entries = [...] }` shape, so
+/// `path`'s extension just picks which deserializer parses it.
+fn load_table_file(path: &Path) -> Result<Vec<Bigram>, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+    let is_json = path.extension().is_some_and(|ext| ext == "json");
+    let file: BigramTableFile = if is_json {
+        serde_json::from_str(&content).map_err(|e| e.to_string())?
+    } else {
+        toml::from_str(&content).map_err(|e| e.to_string())?
+    };
+
+    let bigrams: Vec<Bigram> = file
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let examples: Vec<&str> = entry.examples.iter().map(String::as_str).collect();
+            Bigram::new(&entry.pattern, entry.frequency, &examples)
+        })
+        .collect();
+
+    validate_table(&bigrams)?;
+    Ok(bigrams)
+}
+
+/// Enforce the invariant the embedded French/English/code tables already
+/// satisfy (and their own tests check): every frequency falls in the
+/// 0.70-1.00 band, and entries are sorted by descending frequency.
+fn validate_table(bigrams: &[Bigram]) -> Result<(), String> {
+    for bigram in bigrams {
+        if !(0.70..=1.00).contains(&bigram.frequency) {
+            return Err(format!(
+                "bigram '{}' has frequency {} outside the 0.70-1.00 band",
+                bigram.pattern, bigram.frequency
+            ));
+        }
+    }
+
+    for pair in bigrams.windows(2) {
+        if pair[0].frequency < pair[1].frequency {
+            return Err(format!(
+                "entries must be sorted by descending frequency, but '{}' ({}) comes before '{}' ({})",
+                pair[0].pattern, pair[0].frequency, pair[1].pattern, pair[1].frequency
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_defaults_contains_french_and_english() {
+        let registry = BigramRegistry::with_defaults();
+        assert!(registry.get("french").is_some());
+        assert!(registry.get("english").is_some());
+        assert!(registry.get("german").is_none());
+    }
+
+    #[test]
+    fn test_load_overrides_missing_dir_is_not_an_error() {
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(Path::new("/no/such/directory"));
+        assert!(registry.get("french").is_some());
+    }
+
+    #[test]
+    fn test_load_overrides_adds_a_new_language() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("german.toml"),
+            r#"
+            [[entries]]
+            pattern = "ch"
+            frequency = 1.00
+            examples = ["ich"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        let german = registry.get("german").unwrap();
+        assert_eq!(german[0].pattern, "ch");
+    }
+
+    #[test]
+    fn test_load_overrides_replaces_an_existing_language() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("french.toml"),
+            r#"
+            [[entries]]
+            pattern = "xx"
+            frequency = 1.00
+            examples = ["xxemple"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        let french = registry.get("french").unwrap();
+        assert_eq!(french.len(), 1);
+        assert_eq!(french[0].pattern, "xx");
+    }
+
+    #[test]
+    fn test_load_overrides_accepts_json() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("spanish.json"),
+            r#"{"entries": [{"pattern": "rr", "frequency": 1.00, "examples": ["perro"]}]}"#,
+        )
+        .unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        assert_eq!(registry.get("spanish").unwrap()[0].pattern, "rr");
+    }
+
+    #[test]
+    fn test_load_overrides_ignores_unsupported_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a bigram table").unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        assert!(registry.get("notes").is_none());
+    }
+
+    #[test]
+    fn test_load_overrides_rejects_frequency_outside_band() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("bad.toml"),
+            r#"
+            [[entries]]
+            pattern = "zz"
+            frequency = 0.5
+            examples = ["jazz"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        assert!(registry.get("bad").is_none());
+    }
+
+    #[test]
+    fn test_load_overrides_rejects_ascending_order() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("bad.toml"),
+            r#"
+            [[entries]]
+            pattern = "aa"
+            frequency = 0.70
+            examples = ["aardvark"]
+
+            [[entries]]
+            pattern = "bb"
+            frequency = 1.00
+            examples = ["bubble"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = BigramRegistry::with_defaults();
+        registry.load_overrides(dir.path());
+
+        assert!(registry.get("bad").is_none());
+    }
+
+    #[test]
+    fn test_names_are_sorted() {
+        let registry = BigramRegistry::with_defaults();
+        assert_eq!(registry.names(), vec!["english", "french"]);
+    }
+}
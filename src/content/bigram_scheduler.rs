@@ -0,0 +1,284 @@
+/// Leitner/SM-2-style spaced repetition over a fixed set of bigrams, so
+/// drills keep surfacing the patterns a user struggles with instead of
+/// cycling through them uniformly. Finally gives `Bigram::frequency` a
+/// consumer: it seeds how soon a never-reviewed bigram first comes due.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::bigram::Bigram;
+
+/// Ease factor assigned to a bigram that has never been reviewed
+const INITIAL_EASE: f32 = 2.5;
+/// Ease factor never drops below this floor, however many errors accumulate
+const MIN_EASE: f32 = 1.3;
+/// Ease penalty applied on an error rep
+const EASE_PENALTY: f32 = 0.2;
+/// Fraction of `next_bigram` picks that go to a new/high-frequency bigram
+/// (box level 1) instead of the strictly lowest-due item, so common patterns
+/// keep surfacing even while they're not yet overdue
+const NEW_BIGRAM_MIX_RATIO: f64 = 0.2;
+
+/// Per-bigram review state: Leitner box level, SM-2-style ease factor, and
+/// the rep count at which it next comes due.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BigramReviewState {
+    pub box_level: u32,
+    pub ease: f32,
+    /// Rep count this bigram is next due at; lower means more urgent
+    pub due_at: u64,
+}
+
+impl BigramReviewState {
+    /// Seed state from a bigram's corpus frequency: commoner bigrams start
+    /// due sooner, so early sessions bias toward the patterns worth
+    /// practicing most.
+    fn seeded(frequency: f32) -> Self {
+        Self {
+            box_level: 1,
+            ease: INITIAL_EASE,
+            due_at: ((1.0 - frequency) * 100.0).round() as u64,
+        }
+    }
+
+    /// Grade one rep. A clean, fast rep promotes the box level and
+    /// multiplies the review interval by the ease factor; an error resets
+    /// the box to 1 and lowers ease (floored at `MIN_EASE`) so the bigram
+    /// comes back around sooner next time.
+    fn grade(&mut self, current_rep: u64, correct: bool, fast: bool) {
+        if correct && fast {
+            self.box_level += 1;
+            let interval = (self.box_level as f32 * self.ease).round() as u64;
+            self.due_at = current_rep + interval.max(1);
+        } else {
+            self.box_level = 1;
+            self.ease = (self.ease - EASE_PENALTY).max(MIN_EASE);
+            self.due_at = current_rep + 1;
+        }
+    }
+}
+
+/// Spaced-repetition scheduler over a fixed set of bigram patterns. Wraps
+/// per-bigram `BigramReviewState`, persisted to disk (see `load`/`save`) so
+/// long-term weaknesses are tracked across sessions rather than reset every
+/// time the drill starts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BigramScheduler {
+    states: HashMap<String, BigramReviewState>,
+    rep_count: u64,
+}
+
+impl BigramScheduler {
+    /// Build a scheduler seeded from `bigrams`, each pattern's initial
+    /// priority derived from its `frequency`. Patterns already tracked by
+    /// `existing` (e.g. loaded from disk) keep their saved state instead of
+    /// being reseeded, so switching corpora doesn't erase prior progress on
+    /// patterns that still appear. Entries for patterns no longer in
+    /// `bigrams` are dropped, so a stale pattern from a previous corpus
+    /// can never be handed back out by `next_bigram`.
+    pub fn new(bigrams: &[Bigram], existing: BigramScheduler) -> Self {
+        let mut old_states = existing.states;
+        let mut states = HashMap::with_capacity(bigrams.len());
+
+        for bigram in bigrams {
+            // `.entry` rather than a plain insert: if `bigrams` repeats a
+            // pattern, only the first occurrence should claim the restored
+            // state, so a later duplicate can't clobber it with a fresh seed.
+            states.entry(bigram.pattern.clone()).or_insert_with(|| {
+                old_states
+                    .remove(&bigram.pattern)
+                    .unwrap_or_else(|| BigramReviewState::seeded(bigram.frequency))
+            });
+        }
+
+        Self {
+            states,
+            rep_count: existing.rep_count,
+        }
+    }
+
+    /// Record a practice rep for `pattern`: `correct` is whether the user
+    /// typed it without a mistake, `fast` whether they cleared the drill's
+    /// speed threshold for that bigram. Unknown patterns are ignored.
+    pub fn record_rep(&mut self, pattern: &str, correct: bool, fast: bool) {
+        self.rep_count += 1;
+        let rep_count = self.rep_count;
+        if let Some(state) = self.states.get_mut(pattern) {
+            state.grade(rep_count, correct, fast);
+        }
+    }
+
+    /// Pick the next bigram to drill: usually the lowest-due pattern, but a
+    /// `NEW_BIGRAM_MIX_RATIO` fraction of picks go to a never-promoted
+    /// (box level 1) pattern instead, so common patterns keep surfacing even
+    /// before their literal due date.
+    pub fn next_bigram(&self) -> Option<&str> {
+        if self.states.is_empty() {
+            return None;
+        }
+
+        if rand::thread_rng().gen_bool(NEW_BIGRAM_MIX_RATIO) {
+            if let Some(pattern) = self.lowest_due(|state| state.box_level == 1) {
+                return Some(pattern);
+            }
+        }
+
+        self.lowest_due(|_| true)
+    }
+
+    /// Pattern with the lowest `due_at` among states matching `filter`,
+    /// ties broken alphabetically for determinism.
+    fn lowest_due(&self, filter: impl Fn(&BigramReviewState) -> bool) -> Option<&str> {
+        self.states
+            .iter()
+            .filter(|(_, state)| filter(state))
+            .min_by_key(|(pattern, state)| (state.due_at, pattern.as_str()))
+            .map(|(pattern, _)| pattern.as_str())
+    }
+
+    /// Load scheduler state from `path`, or start fresh if it's missing or
+    /// unreadable (a corrupt/absent file just means "no history yet", not a
+    /// fatal error).
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist scheduler state to `path`, writing to a sibling temp file and
+    /// renaming it into place so a crash mid-write can't truncate it.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize bigram scheduler state: {}", e),
+            )
+        })?;
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bigrams() -> Vec<Bigram> {
+        vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("qu", 0.70, &["quick"]),
+        ]
+    }
+
+    #[test]
+    fn test_higher_frequency_bigram_is_due_sooner() {
+        let scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        assert!(scheduler.states["th"].due_at < scheduler.states["qu"].due_at);
+    }
+
+    #[test]
+    fn test_correct_fast_rep_promotes_box_level() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("th", true, true);
+        assert_eq!(scheduler.states["th"].box_level, 2);
+    }
+
+    #[test]
+    fn test_error_resets_box_and_lowers_ease() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("th", true, true);
+        scheduler.record_rep("th", false, false);
+        let state = &scheduler.states["th"];
+        assert_eq!(state.box_level, 1);
+        assert_eq!(state.ease, INITIAL_EASE - EASE_PENALTY);
+    }
+
+    #[test]
+    fn test_ease_never_drops_below_floor() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        for _ in 0..20 {
+            scheduler.record_rep("th", false, false);
+        }
+        assert_eq!(scheduler.states["th"].ease, MIN_EASE);
+    }
+
+    #[test]
+    fn test_next_bigram_is_none_when_empty() {
+        let scheduler = BigramScheduler::new(&[], BigramScheduler::default());
+        assert_eq!(scheduler.next_bigram(), None);
+    }
+
+    #[test]
+    fn test_next_bigram_returns_a_known_pattern() {
+        let scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        let next = scheduler.next_bigram().unwrap();
+        assert!(next == "th" || next == "qu");
+    }
+
+    #[test]
+    fn test_loading_existing_state_preserves_progress() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("th", true, true);
+        scheduler.record_rep("th", true, true);
+
+        let reseeded = BigramScheduler::new(&sample_bigrams(), scheduler.clone());
+        assert_eq!(reseeded.states["th"].box_level, 3);
+    }
+
+    #[test]
+    fn test_reseeding_drops_patterns_absent_from_new_corpus() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("qu", true, true);
+
+        let english_only = vec![Bigram::new("th", 1.00, &["the"])];
+        let reseeded = BigramScheduler::new(&english_only, scheduler);
+
+        assert!(!reseeded.states.contains_key("qu"));
+        assert!(reseeded.states.contains_key("th"));
+    }
+
+    #[test]
+    fn test_duplicate_pattern_in_new_corpus_does_not_clobber_restored_state() {
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("th", true, true);
+
+        let with_duplicate = vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("th", 1.00, &["this"]),
+        ];
+        let reseeded = BigramScheduler::new(&with_duplicate, scheduler);
+
+        assert_eq!(reseeded.states["th"].box_level, 2);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trips() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("bigram_scheduler.json");
+
+        let mut scheduler = BigramScheduler::new(&sample_bigrams(), BigramScheduler::default());
+        scheduler.record_rep("th", true, true);
+        scheduler.save(&path).unwrap();
+
+        let loaded = BigramScheduler::load(&path);
+        assert_eq!(loaded.states["th"].box_level, 2);
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_fresh() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("does_not_exist.json");
+
+        let loaded = BigramScheduler::load(&path);
+        assert!(loaded.states.is_empty());
+    }
+}
@@ -0,0 +1,41 @@
+/// Chord definitions for simultaneous-key training (steno-style and QMK-style combos)
+
+/// A chord is a set of keys meant to be pressed together
+pub type Chord = &'static [char];
+
+/// Level 1: adjacent home row pairs (buttery_engine-style two-key combos)
+pub const LEVEL_1_CHORDS: &[Chord] = &[&['f', 'd'], &['j', 'k'], &['f', 'j'], &['d', 'k']];
+
+/// Level 2: home-row mod style combos (wider reaches, three-key chords)
+pub const LEVEL_2_CHORDS: &[Chord] = &[
+    &['a', 's'],
+    &['l', ';'],
+    &['s', 'd', 'f'],
+    &['j', 'k', 'l'],
+];
+
+/// Level 3: bracket/symbol chords (steno-style paired punctuation)
+pub const LEVEL_3_CHORDS: &[Chord] = &[&['(', ')'], &['[', ']'], &['{', '}'], &['<', '>']];
+
+/// Chord set for a given level (1-3), empty for anything else
+pub fn chords_for_level(level: u8) -> &'static [Chord] {
+    match level {
+        1 => LEVEL_1_CHORDS,
+        2 => LEVEL_2_CHORDS,
+        3 => LEVEL_3_CHORDS,
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chords_for_level_returns_expected_sets() {
+        assert_eq!(chords_for_level(1), LEVEL_1_CHORDS);
+        assert_eq!(chords_for_level(2), LEVEL_2_CHORDS);
+        assert_eq!(chords_for_level(3), LEVEL_3_CHORDS);
+        assert!(chords_for_level(4).is_empty());
+    }
+}
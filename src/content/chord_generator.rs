@@ -0,0 +1,61 @@
+/// Content generator for `LessonType::Chord` lessons
+///
+/// Output is a whitespace-separated sequence of chords, where each token's
+/// characters are the keys of that chord meant to be pressed simultaneously
+/// (e.g. "fd jk fj" — `TypingSession::add_chord_input` consumes one token at a time).
+use super::chord::chords_for_level;
+
+pub struct ChordGenerator;
+
+impl ChordGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn generate(&self, level: u8, length: usize) -> String {
+        let chords = chords_for_level(level);
+        if chords.is_empty() {
+            return String::new();
+        }
+
+        let mut result = String::new();
+        let mut idx = 0;
+
+        while result.len() < length {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            let chord = chords[idx % chords.len()];
+            result.extend(chord.iter());
+            idx += 1;
+        }
+
+        result
+    }
+}
+
+impl Default for ChordGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_produces_whitespace_separated_chords() {
+        let gen = ChordGenerator::new();
+        let content = gen.generate(1, 20);
+
+        assert!(!content.is_empty());
+        assert!(content.split(' ').all(|token| !token.is_empty()));
+    }
+
+    #[test]
+    fn test_generate_unknown_level_is_empty() {
+        let gen = ChordGenerator::new();
+        assert_eq!(gen.generate(9, 20), "");
+    }
+}
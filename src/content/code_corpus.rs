@@ -0,0 +1,104 @@
+/// Sample source bank for `CodeCorpusGenerator`: real (if small) source
+/// fragments per `ProgrammingLanguage`, grouped by construct complexity so
+/// lesson levels progress from bare expressions to full blocks.
+use super::code_symbols::ProgrammingLanguage;
+
+/// A parseable source fragment plus the complexity tier it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct CodeSample {
+    pub source: &'static str,
+    pub tier: ComplexityTier,
+}
+
+/// Construct complexity, matching the level grouping the request asks for:
+/// expressions first, then whole functions, then multi-statement blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComplexityTier {
+    Expression,
+    Function,
+    Block,
+}
+
+impl ComplexityTier {
+    /// Map a lesson `level` (1-6, same scale as `CodeSymbolGenerator`) onto
+    /// a complexity tier: levels 1-2 expressions, 3-4 functions, 5-6 blocks.
+    pub fn for_level(level: usize) -> Self {
+        match level {
+            1..=2 => ComplexityTier::Expression,
+            3..=4 => ComplexityTier::Function,
+            _ => ComplexityTier::Block,
+        }
+    }
+}
+
+const fn sample(source: &'static str, tier: ComplexityTier) -> CodeSample {
+    CodeSample { source, tier }
+}
+
+pub fn samples_for(language: ProgrammingLanguage) -> &'static [CodeSample] {
+    match language {
+        ProgrammingLanguage::Rust => RUST_SAMPLES,
+        ProgrammingLanguage::TypeScript => TYPESCRIPT_SAMPLES,
+        ProgrammingLanguage::Python => PYTHON_SAMPLES,
+    }
+}
+
+const RUST_SAMPLES: &[CodeSample] = &[
+    sample("a + b * (c - d)", ComplexityTier::Expression),
+    sample("items.iter().filter(|x| **x > 0).count()", ComplexityTier::Expression),
+    sample(
+        "fn add(x: i32, y: i32) -> i32 {\n    x + y\n}",
+        ComplexityTier::Function,
+    ),
+    sample(
+        "fn greet(name: &str) -> String {\n    format!(\"hello, {name}\")\n}",
+        ComplexityTier::Function,
+    ),
+    sample(
+        "struct Point {\n    x: i32,\n    y: i32,\n}\n\nimpl Point {\n    fn origin() -> Self {\n        Self { x: 0, y: 0 }\n    }\n}",
+        ComplexityTier::Block,
+    ),
+    sample(
+        "fn sum(values: &[i32]) -> i32 {\n    let mut total = 0;\n    for value in values {\n        total += value;\n    }\n    total\n}",
+        ComplexityTier::Block,
+    ),
+];
+
+const TYPESCRIPT_SAMPLES: &[CodeSample] = &[
+    sample("a + b * (c - d)", ComplexityTier::Expression),
+    sample("items.filter(x => x > 0).length", ComplexityTier::Expression),
+    sample(
+        "function add(x: number, y: number): number {\n    return x + y;\n}",
+        ComplexityTier::Function,
+    ),
+    sample(
+        "const greet = (name: string): string => {\n    return `hello, ${name}`;\n};",
+        ComplexityTier::Function,
+    ),
+    sample(
+        "class Point {\n    constructor(public x: number, public y: number) {}\n\n    static origin(): Point {\n        return new Point(0, 0);\n    }\n}",
+        ComplexityTier::Block,
+    ),
+    sample(
+        "function sum(values: number[]): number {\n    let total = 0;\n    for (const value of values) {\n        total += value;\n    }\n    return total;\n}",
+        ComplexityTier::Block,
+    ),
+];
+
+const PYTHON_SAMPLES: &[CodeSample] = &[
+    sample("a + b * (c - d)", ComplexityTier::Expression),
+    sample("len([x for x in items if x > 0])", ComplexityTier::Expression),
+    sample("def add(x, y):\n    return x + y", ComplexityTier::Function),
+    sample(
+        "def greet(name: str) -> str:\n    return f\"hello, {name}\"",
+        ComplexityTier::Function,
+    ),
+    sample(
+        "class Point:\n    def __init__(self, x, y):\n        self.x = x\n        self.y = y\n\n    @staticmethod\n    def origin():\n        return Point(0, 0)",
+        ComplexityTier::Block,
+    ),
+    sample(
+        "def total(values):\n    result = 0\n    for value in values:\n        result += value\n    return result",
+        ComplexityTier::Block,
+    ),
+];
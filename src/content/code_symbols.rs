@@ -9,8 +9,7 @@ pub enum ProgrammingLanguage {
 }
 
 /// Symbol category for progressive learning
-#[derive(Debug, Clone, Copy, PartialEq)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SymbolCategory {
     Brackets,   // () [] {} <>
     BasicOps,   // + - * / = !
@@ -20,10 +19,34 @@ pub enum SymbolCategory {
     Special,    // . , ; : ? @ #
 }
 
+/// All symbol categories, for code that needs to score or rank every category.
+pub const ALL_SYMBOL_CATEGORIES: [SymbolCategory; 6] = [
+    SymbolCategory::Brackets,
+    SymbolCategory::BasicOps,
+    SymbolCategory::Comparison,
+    SymbolCategory::Arrows,
+    SymbolCategory::Compound,
+    SymbolCategory::Special,
+];
+
+/// Classify a single typed key into its `SymbolCategory`, if it belongs to
+/// one. `Arrows` (`->`, `=>`, `::`) is inherently a two-key sequence, so it
+/// has no single-key classification here; its component keys (`-`, `>`, `=`,
+/// `:`) fall into their own categories instead.
+pub fn classify_symbol_category(key: char) -> Option<SymbolCategory> {
+    match key {
+        '(' | ')' | '[' | ']' | '{' | '}' => Some(SymbolCategory::Brackets),
+        '+' | '-' | '*' | '/' | '=' | '!' => Some(SymbolCategory::BasicOps),
+        '<' | '>' => Some(SymbolCategory::Comparison),
+        '&' | '|' => Some(SymbolCategory::Compound),
+        '.' | ',' | ';' | ':' | '?' | '@' | '#' => Some(SymbolCategory::Special),
+        _ => None,
+    }
+}
+
 /// Code snippet template with difficulty
 #[derive(Debug, Clone)]
 pub struct CodeSnippet {
-    #[allow(dead_code)]
     pub category: SymbolCategory,
     pub template: &'static str,
     pub difficulty: u8, // 1-6
@@ -213,6 +236,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_classify_symbol_category_brackets_and_ops() {
+        assert_eq!(classify_symbol_category('('), Some(SymbolCategory::Brackets));
+        assert_eq!(classify_symbol_category('+'), Some(SymbolCategory::BasicOps));
+        assert_eq!(classify_symbol_category('<'), Some(SymbolCategory::Comparison));
+        assert_eq!(classify_symbol_category(':'), Some(SymbolCategory::Special));
+    }
+
+    #[test]
+    fn test_classify_symbol_category_ignores_non_symbol_keys() {
+        assert_eq!(classify_symbol_category('a'), None);
+        assert_eq!(classify_symbol_category('5'), None);
+    }
+
     #[test]
     fn test_snippets_not_empty() {
         for snippet in typescript_snippets() {
@@ -0,0 +1,235 @@
+/// Frequency-ranked common-word tables backing `CommonWordGenerator`,
+/// `InflectionGenerator`, and `language_detector`'s n-gram profiles. Each
+/// list is ordered most- to least-frequent, since callers like
+/// `CommonWordGenerator::select_words_for_level` slice a frequency-ranked
+/// prefix rather than filtering or sorting at call time.
+pub struct Word {
+    pub text: String,
+}
+
+fn words_from(raw: &[&str]) -> Vec<Word> {
+    raw.iter()
+        .map(|&text| Word {
+            text: text.to_string(),
+        })
+        .collect()
+}
+
+const ENGLISH_WORDS: &[&str] = &[
+    "the", "be", "to", "of", "and", "a", "in", "that",
+    "have", "i", "it", "for", "not", "on", "with", "he",
+    "as", "you", "do", "at", "this", "but", "his", "by",
+    "from", "they", "we", "say", "her", "she", "or", "an",
+    "will", "my", "one", "all", "would", "there", "their", "what",
+    "so", "up", "out", "if", "about", "who", "get", "which",
+    "go", "me", "when", "make", "can", "like", "time", "no",
+    "just", "him", "know", "take", "people", "into", "year", "your",
+    "good", "some", "could", "them", "see", "other", "than", "then",
+    "now", "look", "only", "come", "its", "over", "think", "also",
+    "back", "after", "use", "two", "how", "our", "work", "first",
+    "well", "way", "even", "new", "want", "because", "any", "these",
+    "give", "day", "most", "us", "is", "are", "was", "were",
+    "been", "being", "has", "had", "did", "does", "man", "woman",
+    "child", "world", "life", "hand", "part", "eye", "place", "week",
+    "case", "point", "government", "company", "number", "group", "problem", "fact",
+    "find", "tell", "ask", "seem", "feel", "try", "leave", "call",
+    "last", "long", "great", "little", "own", "old", "right", "big",
+    "high", "different", "small", "large", "next", "early", "young", "important",
+    "few", "public", "bad", "same", "able", "school", "state", "family",
+    "student", "country", "city", "minute", "hour", "father", "mother", "money",
+    "story", "month", "lot", "study", "book", "word", "business", "issue",
+    "side", "kind", "area", "job", "though", "head", "house", "service",
+    "friend", "road", "heart", "power", "game", "line", "end", "member",
+    "law", "car", "community", "name", "president", "team", "idea", "body",
+    "information", "parent", "face", "others", "level", "office", "door", "health",
+    "person", "art", "war", "history", "party", "result", "change", "morning",
+    "reason", "research", "girl", "guy", "moment", "air", "teacher", "force",
+    "education", "foot", "boy", "age", "policy", "process", "music", "market",
+    "sense", "nation", "plan", "college", "interest", "death", "experience", "effect",
+    "rate", "effort", "talk", "third", "warm", "chance", "order", "technology",
+    "practice", "staff", "voice", "matter", "physical", "relationship", "million", "land",
+    "increase", "stay", "speak", "husband", "section", "become", "individual", "visit",
+    "accept", "eat", "cover", "letter", "tonight", "property", "especially", "argue",
+    "wish", "sport", "condition", "ability", "drug", "hotel", "region", "season",
+    "truck", "board", "setting", "surface", "village", "sell", "action", "factor",
+    "yourself", "represent", "speech", "cell", "style", "security", "step", "huge",
+    "possible", "decision", "strategy", "mission", "shoot", "camera", "marriage", "site",
+    "finish", "meal", "bank", "wear", "explain", "bed", "chief", "scene",
+    "rock", "device", "commercial", "agency", "answer", "baby", "wait", "specific",
+    "glass", "player", "wide", "sign", "possibility", "rather", "direct", "forward",
+    "hit", "product", "treatment", "fall", "note", "pay", "career", "option",
+    "fish", "support", "involve", "material", "design", "although", "bit", "entire",
+    "recently", "subject", "approach", "audience", "theory", "beat", "necessary", "image",
+    "value", "mind", "similar", "wife", "fight", "success", "throughout", "participant",
+    "financial", "reflect", "hard", "range", "official", "stock", "relate", "building",
+    "street", "red", "weapon", "notice", "test", "exist", "grow", "soldier",
+    "oil", "positive", "dark", "pull", "officer", "key", "employee", "customer",
+    "drop", "risk", "box", "relation", "generation", "firm", "interesting", "space",
+    "seek", "cup", "vote", "somebody", "threat", "film", "blue", "station",
+    "wrong", "manage", "writer", "appear", "finally", "future", "discuss", "concern",
+    "quality", "ago", "remember", "purpose", "arm", "weight", "charge", "main",
+    "recognize", "radio", "suggest", "operation", "structure", "loss", "agent", "significant",
+    "admit", "series", "meet", "seven", "return", "fresh", "avoid", "summer",
+    "score", "quickly", "middle", "item", "deal", "sea", "safe", "cut",
+    "crime", "southern", "western", "clearly", "boss", "respond", "seat", "maintain",
+    "establish", "beautiful", "apple", "river", "mountain", "forest", "garden", "window",
+    "table", "chair", "lamp", "clock", "phone", "computer", "screen", "keyboard",
+    "mouse", "printer", "paper", "pencil", "pen", "shelf", "wall", "floor",
+    "ceiling", "roof", "kitchen", "bedroom", "bathroom", "garage", "yard", "fence",
+    "gate", "path", "bridge", "tunnel", "highway", "avenue", "corner", "block",
+    "tower", "castle", "palace", "temple", "church", "museum", "library", "theater",
+    "stadium", "airport", "harbor", "island", "beach", "ocean", "lake", "pond",
+    "stream", "valley", "hill", "cliff", "desert", "jungle", "meadow", "field",
+    "farm", "barn", "tractor", "plow", "seed", "harvest", "fruit", "vegetable",
+    "bread", "butter", "cheese", "milk", "egg", "meat", "chicken", "rice",
+    "wheat", "corn", "potato", "tomato", "onion", "carrot", "pepper", "salt",
+    "sugar", "coffee", "tea", "juice", "water", "wine", "beer", "soda",
+    "cake", "cookie", "candy", "chocolate", "winter", "spring", "autumn", "weather",
+    "rain", "snow", "wind", "storm", "cloud", "sun", "moon", "star",
+    "sky", "horizon", "sunrise", "sunset", "rainbow", "thunder", "lightning", "fog",
+    "mist", "dew", "frost", "ice", "ember", "fire", "flame", "smoke",
+    "ash", "animal", "bird", "insect", "reptile", "mammal", "lion", "tiger",
+    "bear", "wolf", "fox", "deer", "rabbit", "squirrel", "horse", "cow",
+    "sheep", "goat", "pig", "duck", "goose", "turkey", "eagle", "hawk",
+    "owl", "sparrow", "crow", "dove", "parrot", "snake", "lizard", "turtle",
+    "frog", "toad", "spider", "ant", "bee", "butterfly", "moth", "worm",
+    "color", "orange", "yellow", "green", "purple", "pink", "brown", "black",
+    "white", "gray", "silver", "gold", "bronze", "copper", "iron", "steel",
+    "wood", "stone", "plastic", "cotton", "wool", "silk", "leather", "rubber",
+    "metal", "shape", "circle", "square", "triangle", "rectangle", "oval", "diamond",
+    "curve", "angle", "edge", "center", "top", "bottom", "front", "left",
+    "inside", "outside", "above", "below", "zero", "three", "four", "five",
+    "six", "eight", "nine", "ten", "eleven", "twelve", "twenty", "thirty",
+    "forty", "fifty", "hundred", "thousand", "second", "fourth", "fifth", "previous",
+    "night", "afternoon", "evening", "noon", "midnight", "dawn", "dusk", "decade",
+    "century", "calendar", "sister", "brother", "daughter", "son", "uncle", "aunt",
+    "cousin", "grandmother", "grandfather", "nephew", "niece", "neighbor", "stranger", "doctor",
+    "nurse", "lawyer", "engineer", "scientist", "artist", "musician", "actor", "dancer",
+    "singer", "athlete", "chef", "baker", "farmer", "fisherman", "sailor", "pilot",
+    "driver", "police", "firefighter", "judge", "mayor", "king", "queen", "prince",
+    "princess", "knight",
+];
+
+const FRENCH_WORDS: &[&str] = &[
+    "le", "de", "un", "être", "et", "à", "il", "avoir",
+    "ne", "je", "son", "que", "se", "qui", "ce", "dans",
+    "en", "du", "elle", "au", "pour", "pas", "vous", "par",
+    "sur", "faire", "plus", "dire", "me", "on", "mon", "lui",
+    "nous", "comme", "mais", "pouvoir", "avec", "tout", "y", "aller",
+    "voir", "bien", "où", "sans", "tu", "ou", "leur", "homme",
+    "si", "deux", "donc", "les", "des", "une", "même", "alors",
+    "encore", "toujours", "pendant", "pourquoi", "chaque", "quelque", "comment", "ensemble",
+    "entre", "années", "journée", "nouvelle", "temps", "vie", "monde", "main",
+    "œil", "heure", "jour", "petit", "grand", "femme", "enfant", "pays",
+    "chose", "question", "fois", "histoire", "travail", "place", "nombre", "groupe",
+    "part", "exemple", "raison", "gouvernement", "fait", "problème", "cas", "famille",
+    "école", "moment", "semaine", "mois", "année", "argent", "mot", "livre",
+    "affaire", "côté", "sorte", "tête", "maison", "service", "ami", "route",
+    "guerre", "ville", "communauté", "nom", "président", "membre", "loi", "voiture",
+    "résultat", "idée", "corps", "information", "dos", "parent", "visage", "autre",
+    "niveau", "bureau", "porte", "santé", "personne", "art", "parti", "matin",
+    "changement", "recherche", "fille", "garçon", "air", "professeur", "force", "éducation",
+    "pied", "âge", "politique", "processus", "musique", "marché", "intérêt", "mort",
+    "expérience", "effet", "usage", "taux", "effort", "parole", "troisième", "chaud",
+    "chance", "ordre", "technologie", "pratique", "personnel", "voix", "matière", "physique",
+    "relation", "million", "terre", "augmentation", "rester", "parler", "mari", "section",
+    "devenir", "individuel", "visite", "accepter", "manger", "couverture", "lettre", "soir",
+    "propriété", "spécialement", "discuter", "souhaiter", "sport", "condition", "capacité", "drogue",
+    "hôtel", "région", "saison", "camion", "comité", "réglage", "surface", "village",
+    "vendre", "action", "facteur", "vous-même", "représenter", "discours", "cellule", "style",
+    "sécurité", "étape", "énorme", "possible", "décision", "stratégie", "mission", "tirer",
+    "caméra", "mariage", "site", "finir", "repas", "banque", "porter", "expliquer",
+    "lit", "chef", "scène", "roche", "appareil", "commercial", "agence", "réponse",
+    "bébé", "attendre", "spécifique", "verre", "joueur", "large", "signe", "possibilité",
+    "plutôt", "direct", "avant", "frapper", "produit", "traitement", "tomber", "note",
+    "payer", "carrière", "option", "poisson", "soutien", "genre", "impliquer", "matériau",
+    "conception", "morceau", "entier", "récemment", "sujet", "battre", "noter", "nécessaire",
+    "image", "argumenter", "valeur", "esprit", "similaire", "épouse", "combat", "succès",
+    "partout", "participant", "financier", "réfléchir", "dur", "gamme", "officiel", "stock",
+    "relier", "bâtiment", "rue", "rouge", "arme", "remarquer", "avis", "test",
+    "exister", "grandir", "soldat", "pétrole", "positif", "sombre", "officier", "clé",
+    "employé", "client", "goutte", "risque", "boîte", "génération", "entreprise", "approche",
+    "intéressant", "espace", "chercher", "coupe", "vote", "quelqu'un", "menace", "film",
+    "bleu", "gare", "mal", "gérer", "écrivain", "apparaître", "finalement", "futur",
+    "concerner", "qualité", "passé", "souvenir", "objectif", "bras", "poids", "charge",
+    "principal", "reconnaître", "radio", "suggérer", "opération", "structure", "perte", "agent",
+    "important", "admettre", "série", "rencontrer", "sept", "retour", "frais", "éviter",
+    "été", "score", "rapidement", "milieu", "article", "mer", "sûr", "couper",
+    "crime", "méridional", "occidental", "clairement", "patron", "répondre", "siège", "maintenir",
+    "établir", "beau", "pomme", "rivière", "montagne", "forêt", "jardin", "fenêtre",
+    "table", "chaise", "lampe", "horloge", "téléphone", "ordinateur", "écran", "clavier",
+    "souris", "imprimante", "papier", "crayon", "stylo", "étagère", "mur", "sol",
+    "plafond", "toit", "cuisine", "chambre", "salle", "garage", "cour", "clôture",
+    "portail", "chemin", "pont", "tunnel", "autoroute", "avenue", "coin", "bloc",
+    "tour", "château", "palais", "temple", "église", "musée", "bibliothèque", "théâtre",
+    "stade", "aéroport", "port", "île", "plage", "océan", "lac", "étang",
+    "ruisseau", "vallée", "colline", "falaise", "désert", "jungle", "prairie", "champ",
+    "ferme", "grange", "tracteur", "charrue", "graine", "récolte", "fruit", "légume",
+    "pain", "beurre", "fromage", "lait", "œuf", "viande", "poulet", "riz",
+    "blé", "maïs", "tomate", "oignon", "carotte", "poivre", "sel", "sucre",
+    "café", "thé", "jus", "eau", "vin", "bière", "soda", "gâteau",
+    "biscuit", "bonbon", "chocolat", "hiver", "printemps", "automne", "météo", "pluie",
+    "neige", "vent", "tempête", "nuage", "soleil", "lune", "étoile", "ciel",
+    "horizon", "lever", "coucher", "arc-en-ciel", "tonnerre", "éclair", "brouillard", "brume",
+    "rosée", "givre", "glace", "braise", "feu", "flamme", "fumée", "cendre",
+    "animal", "oiseau", "insecte", "reptile", "mammifère", "lion", "tigre", "ours",
+    "loup", "renard", "cerf", "lapin", "écureuil", "cheval", "vache", "mouton",
+    "chèvre", "cochon", "canard", "oie", "dinde", "aigle", "faucon", "hibou",
+    "moineau", "corbeau", "colombe", "perroquet", "serpent", "lézard", "tortue", "grenouille",
+    "crapaud", "araignée", "fourmi", "abeille", "papillon", "mite", "ver", "couleur",
+    "orange", "jaune", "vert", "violet", "rose", "marron", "noir", "blanc",
+    "gris", "or", "bronze", "cuivre", "fer", "acier", "bois", "pierre",
+    "plastique", "coton", "laine", "soie", "cuir", "caoutchouc", "métal", "forme",
+    "cercle", "carré", "triangle", "rectangle", "ovale", "diamant", "ligne", "point",
+    "courbe", "angle", "bord", "centre", "haut", "bas", "arrière", "gauche",
+    "droite", "intérieur", "extérieur", "dessus", "dessous", "zéro", "trois", "quatre",
+    "cinq", "six", "huit", "neuf", "dix", "onze", "douze", "vingt",
+    "trente", "quarante", "cinquante", "cent", "mille", "premier", "deuxième", "quatrième",
+    "cinquième", "dernier", "suivant", "nuit", "après-midi", "midi", "minuit", "aube",
+    "crépuscule", "minute", "seconde", "décennie", "siècle", "calendrier", "mère", "père",
+    "sœur", "frère", "fils", "oncle", "tante", "cousin", "grand-mère", "grand-père",
+    "neveu", "nièce", "voisin", "étranger", "élève", "médecin", "infirmière", "avocat",
+    "ingénieur", "scientifique", "artiste", "musicien", "acteur", "danseur", "chanteur", "athlète",
+    "boulanger", "fermier", "pêcheur", "marin", "pilote", "chauffeur", "police", "pompier",
+    "juge", "maire", "roi", "reine", "prince", "princesse", "chevalier",
+];
+
+/// The 500+ most common English words, most frequent first.
+pub fn english_words() -> Vec<Word> {
+    words_from(ENGLISH_WORDS)
+}
+
+/// The 500+ most common French words, most frequent first.
+pub fn french_words() -> Vec<Word> {
+    words_from(FRENCH_WORDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_words_leads_with_the() {
+        let words = english_words();
+        assert_eq!(words[0].text, "the");
+    }
+
+    #[test]
+    fn test_english_words_has_at_least_500_entries() {
+        assert!(english_words().len() >= 500);
+    }
+
+    #[test]
+    fn test_french_words_has_at_least_500_entries() {
+        assert!(french_words().len() >= 500);
+    }
+
+    #[test]
+    fn test_word_lists_contain_no_duplicates() {
+        let mut texts: Vec<&str> = english_words().iter().map(|w| w.text.as_str()).collect();
+        let before = texts.len();
+        texts.sort_unstable();
+        texts.dedup();
+        assert_eq!(texts.len(), before, "english_words() has a duplicate entry");
+    }
+}
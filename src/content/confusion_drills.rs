@@ -0,0 +1,438 @@
+/// Targeted practice built from a user's own mistakes, using the
+/// candidate-generation idea behind Norvig-style spelling correction:
+/// track which (expected, typed) substitutions a user actually makes, then
+/// generate candidate bigrams at edit distance 1 from the confused
+/// characters and rank them by how often the confusion recurs. Output is a
+/// plain `Vec<Bigram>`, so the rest of the rendering/scoring path needs no
+/// changes to consume it.
+use std::collections::{HashMap, HashSet};
+
+use super::bigram::Bigram;
+use crate::engine::analytics::KeyStats;
+
+/// Example words pulled in per generated drill bigram, same cap
+/// `bigram::bigrams_from_corpus` uses
+const MAX_EXAMPLES: usize = 10;
+
+/// How many distinct confusion pairs a word drill draws from at most
+const MAX_DRILL_CONFUSIONS: usize = 20;
+/// How many candidate words are pulled per confused key from the trie
+const MAX_WORDS_PER_KEY: usize = 20;
+/// How many words a confusion word drill strings together at most
+const MAX_DRILL_WORDS: usize = 20;
+
+/// Tracks how often a user types `typed` where `expected` was called for,
+/// one tally per (expected, typed) substitution pair.
+#[derive(Debug, Clone, Default)]
+pub struct ConfusionTracker {
+    counts: HashMap<(char, char), u32>,
+}
+
+impl ConfusionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a tracker straight from accumulated `KeyStats.mistype_map`
+    /// entries, so the per-key "what was typed instead" tallies recorded
+    /// during real sessions feed directly into confusion drills without
+    /// the caller re-deriving substitution counts itself.
+    pub fn from_key_stats(key_stats: &HashMap<char, KeyStats>) -> Self {
+        let mut tracker = Self::new();
+
+        for stats in key_stats.values() {
+            for (&typed, &count) in &stats.mistype_map {
+                if stats.key != typed {
+                    *tracker.counts.entry((stats.key, typed)).or_insert(0) += count as u32;
+                }
+            }
+        }
+
+        tracker
+    }
+
+    /// Record a single substitution mistake. A match (`expected == typed`)
+    /// isn't a confusion and is ignored.
+    pub fn record_substitution(&mut self, expected: char, typed: char) {
+        if expected != typed {
+            *self.counts.entry((expected, typed)).or_insert(0) += 1;
+        }
+    }
+
+    /// The `limit` most frequent confusion pairs, highest count first, ties
+    /// broken lexicographically for determinism.
+    pub fn top_confusions(&self, limit: usize) -> Vec<(char, char, u32)> {
+        let mut pairs: Vec<(char, char, u32)> = self
+            .counts
+            .iter()
+            .map(|(&(expected, typed), &count)| (expected, typed, count))
+            .collect();
+        pairs.sort_by(|a, b| {
+            b.2.cmp(&a.2)
+                .then_with(|| a.0.cmp(&b.0))
+                .then_with(|| a.1.cmp(&b.1))
+        });
+        pairs.truncate(limit);
+        pairs
+    }
+
+    /// Build a synthetic drill set from the `limit` most frequent
+    /// confusions. Each confused (expected, typed) pair yields both
+    /// character orderings as a candidate bigram (a single substitution
+    /// away from the pair itself), deduplicated, with `frequency` rescaled
+    /// into the usual 0.70-1.00 band by accumulated error weight so the
+    /// worst confusions sort to the top of the drill. `examples` pulls
+    /// words containing the candidate pattern out of `corpus`, falling back
+    /// to the bare pattern if nothing in `corpus` contains it.
+    pub fn confusion_drills(&self, corpus: &[Bigram], limit: usize) -> Vec<Bigram> {
+        let confusions = self.top_confusions(limit);
+        let max_count = confusions.iter().map(|&(_, _, count)| count).max().unwrap_or(0);
+        let min_count = confusions.iter().map(|&(_, _, count)| count).min().unwrap_or(0);
+        let range = (max_count - min_count) as f32;
+
+        let mut seen_patterns = HashSet::new();
+        let mut drills = Vec::new();
+
+        for (expected, typed, count) in confusions {
+            for pattern in [
+                [expected, typed].iter().collect::<String>(),
+                [typed, expected].iter().collect::<String>(),
+            ] {
+                if !seen_patterns.insert(pattern.clone()) {
+                    continue;
+                }
+
+                let frequency = if range == 0.0 {
+                    1.00
+                } else {
+                    0.70 + (count - min_count) as f32 / range * 0.30
+                };
+
+                let examples = examples_containing(corpus, &pattern);
+                let example_refs: Vec<&str> = examples.iter().map(String::as_str).collect();
+                drills.push(Bigram::new(&pattern, frequency, &example_refs));
+            }
+        }
+
+        drills
+    }
+}
+
+/// Words from `corpus`'s examples that contain `pattern`, shortest first and
+/// capped at `MAX_EXAMPLES` (matching `bigrams_from_corpus`'s convention).
+/// Falls back to the bare pattern when nothing in `corpus` contains it, so
+/// the `Bigram::examples`-contains-`pattern` invariant the rest of the crate
+/// relies on always holds.
+fn examples_containing(corpus: &[Bigram], pattern: &str) -> Vec<String> {
+    let mut matches: Vec<String> = corpus
+        .iter()
+        .flat_map(|bigram| &bigram.examples)
+        .filter(|word| word.to_lowercase().contains(pattern))
+        .cloned()
+        .collect();
+
+    matches.sort_by_key(|word| word.len());
+    matches.dedup();
+    matches.truncate(MAX_EXAMPLES);
+
+    if matches.is_empty() {
+        vec![pattern.to_string()]
+    } else {
+        matches
+    }
+}
+
+/// A single node in `WordTrie`: children keyed by character, plus the
+/// weighted words whose indexed suffix ends exactly at this node.
+#[derive(Debug, Default)]
+struct WordTrieNode {
+    children: HashMap<char, WordTrieNode>,
+    words: Vec<(String, f32)>,
+}
+
+/// A character trie over an example word pool, indexed by every suffix of
+/// every word (not just its prefix), so a single-character lookup from the
+/// root efficiently enumerates every word containing that character
+/// anywhere, ranked by weight. Built once per word pool and reused across
+/// drill generations.
+#[derive(Debug, Default)]
+pub struct WordTrie {
+    root: WordTrieNode,
+}
+
+impl WordTrie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a trie from `(word, weight)` pairs, e.g. a bigram corpus's
+    /// example words weighted by the bigram's `frequency`.
+    pub fn from_words<'a>(words: impl IntoIterator<Item = (&'a str, f32)>) -> Self {
+        let mut trie = Self::new();
+        for (word, weight) in words {
+            trie.insert(word, weight);
+        }
+        trie
+    }
+
+    /// Index every suffix of `word` so a lookup by any character it
+    /// contains, not just its first one, finds it.
+    pub fn insert(&mut self, word: &str, weight: f32) {
+        let chars: Vec<char> = word.chars().collect();
+
+        for start in 0..chars.len() {
+            let mut node = &mut self.root;
+            for &c in &chars[start..] {
+                node = node.children.entry(c).or_default();
+            }
+            node.words.push((word.to_string(), weight));
+        }
+    }
+
+    /// Every distinct word containing `key`, ranked by descending weight
+    /// (ties broken lexicographically for determinism) and capped at
+    /// `limit`.
+    pub fn words_containing(&self, key: char, limit: usize) -> Vec<&str> {
+        let Some(start) = self.root.children.get(&key) else {
+            return Vec::new();
+        };
+
+        let mut found: Vec<(&str, f32)> = Vec::new();
+        collect_words(start, &mut found);
+
+        let mut best: HashMap<&str, f32> = HashMap::new();
+        for (word, weight) in found {
+            let entry = best.entry(word).or_insert(weight);
+            if weight > *entry {
+                *entry = weight;
+            }
+        }
+
+        let mut ranked: Vec<(&str, f32)> = best.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranked.truncate(limit);
+        ranked.into_iter().map(|(word, _)| word).collect()
+    }
+}
+
+/// Depth-first collection of every `(word, weight)` stored under `node`'s subtree.
+fn collect_words<'a>(node: &'a WordTrieNode, out: &mut Vec<(&'a str, f32)>) {
+    for (word, weight) in &node.words {
+        out.push((word.as_str(), *weight));
+    }
+    for child in node.children.values() {
+        collect_words(child, out);
+    }
+}
+
+/// Drill content densely packed with words containing the user's most
+/// confused keys, so practice deliberately forces the confusable characters
+/// apart instead of treating them like any other key. Alternates between the
+/// `expected` and `typed` side of each top confusion pair (so e.g. an `n`/`m`
+/// mix-up drills both "name" and "mane"-style words), capped at
+/// `MAX_DRILL_WORDS` words and truncated to `length` characters.
+pub fn confusion_word_drill(tracker: &ConfusionTracker, pool: &WordTrie, length: usize) -> String {
+    let confusions = tracker.top_confusions(MAX_DRILL_CONFUSIONS);
+    if confusions.is_empty() {
+        return String::new();
+    }
+
+    // Look up each confused key's candidate words up front, then round-robin
+    // one word at a time across keys, so an early key with a deep word pool
+    // can't exhaust the drill cap before later/other-side keys contribute.
+    let mut by_key: HashMap<char, Vec<&str>> = HashMap::new();
+    let mut keys: Vec<char> = Vec::new();
+    for (expected, typed, _) in &confusions {
+        for key in [*expected, *typed] {
+            by_key
+                .entry(key)
+                .or_insert_with(|| pool.words_containing(key, MAX_WORDS_PER_KEY));
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    let mut words: Vec<&str> = Vec::new();
+    let mut cursor = 0;
+    'outer: loop {
+        let mut made_progress = false;
+        for &key in &keys {
+            if let Some(&word) = by_key[&key].get(cursor) {
+                words.push(word);
+                made_progress = true;
+                if words.len() >= MAX_DRILL_WORDS {
+                    break 'outer;
+                }
+            }
+        }
+        if !made_progress {
+            break;
+        }
+        cursor += 1;
+    }
+
+    let mut result = String::new();
+    let mut char_count = 0;
+    let mut idx = 0;
+    while !words.is_empty() && char_count < length {
+        if !result.is_empty() {
+            result.push(' ');
+            char_count += 1;
+        }
+        let word = words[idx % words.len()];
+        result.push_str(word);
+        char_count += word.chars().count();
+        idx += 1;
+    }
+
+    result.chars().take(length).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::bigram::french_bigrams;
+
+    #[test]
+    fn test_matching_substitution_is_not_recorded() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('e', 'e');
+        assert!(tracker.top_confusions(10).is_empty());
+    }
+
+    #[test]
+    fn test_top_confusions_ranks_by_count_descending() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('e', 'a');
+        tracker.record_substitution('o', 'i');
+        tracker.record_substitution('o', 'i');
+
+        let top = tracker.top_confusions(10);
+        assert_eq!(top[0], ('o', 'i', 2));
+        assert_eq!(top[1], ('e', 'a', 1));
+    }
+
+    #[test]
+    fn test_top_confusions_respects_limit() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('e', 'a');
+        tracker.record_substitution('o', 'i');
+
+        assert_eq!(tracker.top_confusions(1).len(), 1);
+    }
+
+    #[test]
+    fn test_confusion_drills_produces_both_orderings() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('e', 'a');
+
+        let drills = tracker.confusion_drills(&[], 10);
+        let patterns: Vec<&str> = drills.iter().map(|b| b.pattern.as_str()).collect();
+        assert!(patterns.contains(&"ea"));
+        assert!(patterns.contains(&"ae"));
+    }
+
+    #[test]
+    fn test_confusion_drills_frequency_stays_in_expected_band() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('e', 'a');
+        tracker.record_substitution('o', 'i');
+        tracker.record_substitution('o', 'i');
+
+        for bigram in tracker.confusion_drills(&[], 10) {
+            assert!(bigram.frequency >= 0.70 && bigram.frequency <= 1.00);
+        }
+    }
+
+    #[test]
+    fn test_confusion_drills_pulls_examples_from_corpus() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('u', 'i');
+
+        let drills = tracker.confusion_drills(&french_bigrams(), 10);
+        let qu_drill = drills.iter().find(|b| b.pattern == "ui").unwrap();
+        assert!(qu_drill.examples.iter().any(|word| word.contains("ui")));
+    }
+
+    #[test]
+    fn test_confusion_drills_falls_back_to_bare_pattern_with_no_corpus_match() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('z', 'q');
+
+        let drills = tracker.confusion_drills(&[], 10);
+        let drill = drills.iter().find(|b| b.pattern == "zq").unwrap();
+        assert_eq!(drill.examples, vec!["zq".to_string()]);
+    }
+
+    #[test]
+    fn test_no_confusions_produces_no_drills() {
+        let tracker = ConfusionTracker::new();
+        assert!(tracker.confusion_drills(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn test_from_key_stats_reads_mistype_map() {
+        let mut n_stats = KeyStats::new('n');
+        n_stats.mistype_map.insert('m', 3);
+        let mut key_stats = HashMap::new();
+        key_stats.insert('n', n_stats);
+
+        let tracker = ConfusionTracker::from_key_stats(&key_stats);
+        assert_eq!(tracker.top_confusions(10), vec![('n', 'm', 3)]);
+    }
+
+    #[test]
+    fn test_from_key_stats_ignores_matching_mistype_entry() {
+        let mut n_stats = KeyStats::new('n');
+        n_stats.mistype_map.insert('n', 5); // shouldn't happen, but guard anyway
+        let mut key_stats = HashMap::new();
+        key_stats.insert('n', n_stats);
+
+        let tracker = ConfusionTracker::from_key_stats(&key_stats);
+        assert!(tracker.top_confusions(10).is_empty());
+    }
+
+    #[test]
+    fn test_word_trie_finds_words_containing_key() {
+        let trie = WordTrie::from_words([("name", 1.0), ("mane", 1.0), ("lost", 1.0)]);
+
+        let words = trie.words_containing('m', 10);
+        assert!(words.contains(&"name"));
+        assert!(words.contains(&"mane"));
+        assert!(!words.contains(&"lost"));
+    }
+
+    #[test]
+    fn test_word_trie_ranks_by_weight_descending() {
+        let trie = WordTrie::from_words([("mane", 1.0), ("mud", 5.0)]);
+
+        let words = trie.words_containing('m', 10);
+        assert_eq!(words, vec!["mud", "mane"]);
+    }
+
+    #[test]
+    fn test_word_trie_missing_key_yields_no_words() {
+        let trie = WordTrie::from_words([("name", 1.0)]);
+        assert!(trie.words_containing('z', 10).is_empty());
+    }
+
+    #[test]
+    fn test_confusion_word_drill_targets_confused_keys() {
+        let mut tracker = ConfusionTracker::new();
+        tracker.record_substitution('n', 'm');
+
+        let trie = WordTrie::from_words([("name", 1.0), ("mane", 1.0), ("lost", 1.0)]);
+        let drill = confusion_word_drill(&tracker, &trie, 40);
+
+        assert!(!drill.is_empty());
+        assert!(drill.split_whitespace().all(|w| w.contains('n') || w.contains('m')));
+    }
+
+    #[test]
+    fn test_confusion_word_drill_empty_tracker_yields_empty_string() {
+        let tracker = ConfusionTracker::new();
+        let trie = WordTrie::from_words([("name", 1.0)]);
+        assert_eq!(confusion_word_drill(&tracker, &trie, 40), String::new());
+    }
+}
@@ -0,0 +1,171 @@
+/// Derives a `Vec<Trigram>` straight from an arbitrary user-supplied text
+/// file (a book, their own source tree, whatever) instead of requiring a
+/// hand-curated table or corpus_scan/bigram_registry-style pre-seeded list.
+/// Companion to `bigram::bigrams_from_corpus`, adapted to 3-char windows and
+/// `Trigram`'s own 0.0-1.0 frequency range.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::trigram::Trigram;
+
+/// Examples kept per trigram, matching `bigram::bigrams_from_corpus`'s cap
+const MAX_EXAMPLES: usize = 10;
+
+/// How many top trigrams a corpus scan keeps by default, matching the
+/// hardcoded `english_trigrams()`/`french_trigrams()` list size
+pub const DEFAULT_TOP_N: usize = 25;
+
+/// Read `path` as UTF-8 text and derive its top `top_n` trigrams (see
+/// `trigrams_from_text`).
+pub fn trigrams_from_file(path: &Path, top_n: usize) -> io::Result<Vec<Trigram>> {
+    let text = fs::read_to_string(path)?;
+    Ok(trigrams_from_text(&text, top_n))
+}
+
+/// Derive a frequency-ordered `Vec<Trigram>` from arbitrary `text`.
+/// Lowercases the corpus and splits it into words on non-letter boundaries
+/// (accented characters count as letters and stay inside words), slides a
+/// 3-char window over each word of at least 3 characters to count every
+/// trigram, and keeps a small set of the shortest distinct words containing
+/// each trigram (capped at `MAX_EXAMPLES`) as `examples`. Operates on `char`
+/// vectors rather than byte offsets throughout, so multibyte accented
+/// letters (é, è, ê) form valid trigrams rather than splitting a codepoint.
+/// Keeps the top `top_n` trigrams by raw count, normalized into the 0.0-1.0
+/// `frequency` range by dividing each count by the single highest count, so
+/// the most common trigram always lands at exactly `1.0`.
+pub fn trigrams_from_text(text: &str, top_n: usize) -> Vec<Trigram> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    let mut examples: HashMap<String, Vec<String>> = HashMap::new();
+
+    let lowercased = text.to_lowercase();
+    for word in lowercased.split(|c: char| !c.is_alphabetic()) {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < 3 {
+            continue;
+        }
+
+        for window in chars.windows(3) {
+            let pattern: String = window.iter().collect();
+            *counts.entry(pattern.clone()).or_insert(0) += 1;
+
+            let word_examples = examples.entry(pattern).or_default();
+            if !word_examples.iter().any(|w| w == word) {
+                word_examples.push(word.to_string());
+                word_examples.sort_by_key(|w| w.len());
+                word_examples.truncate(MAX_EXAMPLES);
+            }
+        }
+    }
+
+    let mut ranked: Vec<(String, u64)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(top_n);
+
+    let max_count = ranked.first().map(|(_, count)| *count).unwrap_or(0);
+
+    ranked
+        .into_iter()
+        .map(|(pattern, count)| {
+            let frequency = if max_count == 0 {
+                0.0
+            } else {
+                count as f32 / max_count as f32
+            };
+            let words = examples.remove(&pattern).unwrap_or_default();
+            let example_refs: Vec<&str> = words.iter().map(String::as_str).collect();
+            Trigram::new(&pattern, frequency, &example_refs)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigrams_from_text_orders_by_frequency() {
+        let corpus = "the the the and and cat";
+        let trigrams = trigrams_from_text(corpus, 5);
+
+        assert_eq!(trigrams[0].pattern, "the");
+        assert_eq!(trigrams[0].frequency, 1.0);
+    }
+
+    #[test]
+    fn test_trigrams_from_text_normalizes_by_max_count() {
+        let corpus = "the the the and and cat";
+        let trigrams = trigrams_from_text(corpus, 5);
+
+        let and_trigram = trigrams.iter().find(|t| t.pattern == "and").unwrap();
+        assert_eq!(and_trigram.frequency, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_trigrams_from_text_ignores_words_shorter_than_three() {
+        let corpus = "a an to the";
+        let trigrams = trigrams_from_text(corpus, 10);
+
+        assert!(!trigrams.iter().any(|t| t.pattern.len() < 3));
+    }
+
+    #[test]
+    fn test_trigrams_from_text_caps_top_n() {
+        let corpus = "abcdefghijklmnopqrstuvwxyz abcdefghijklmnopqrstuvwxyz0123456789";
+        let trigrams = trigrams_from_text(corpus, 3);
+
+        assert_eq!(trigrams.len(), 3);
+    }
+
+    #[test]
+    fn test_trigrams_from_text_caps_examples_and_contains_pattern() {
+        let corpus = "theater theater theater thesis thermal thimble thirsty";
+        let trigrams = trigrams_from_text(corpus, 5);
+
+        let the_trigram = trigrams.iter().find(|t| t.pattern == "the").unwrap();
+        assert!(the_trigram.examples.len() <= MAX_EXAMPLES);
+        for example in &the_trigram.examples {
+            assert!(example.contains("the"));
+        }
+    }
+
+    #[test]
+    fn test_trigrams_from_text_prefers_shortest_examples() {
+        let corpus = "xaaaaaaaaaaa xaa bbb xaa";
+        let trigrams = trigrams_from_text(corpus, 5);
+
+        let xaa_trigram = trigrams.iter().find(|t| t.pattern == "xaa").unwrap();
+        assert_eq!(xaa_trigram.examples[0], "xaa");
+    }
+
+    #[test]
+    fn test_trigrams_from_text_keeps_accented_words_intact() {
+        let trigrams = trigrams_from_text("été étude étude été", 3);
+
+        assert!(trigrams.iter().any(|t| t.pattern == "été"));
+    }
+
+    #[test]
+    fn test_trigrams_from_text_empty_corpus_is_empty() {
+        assert!(trigrams_from_text("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_trigrams_from_file_reads_and_derives() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("corpus.txt");
+        fs::write(&path, "the the the and and cat").unwrap();
+
+        let trigrams = trigrams_from_file(&path, 5).unwrap();
+        assert_eq!(trigrams[0].pattern, "the");
+    }
+
+    #[test]
+    fn test_trigrams_from_file_missing_file_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("does_not_exist.txt");
+
+        assert!(trigrams_from_file(&path, 5).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+/// Turns the static `french_bigrams`/`english_bigrams`/`code_bigrams` tables
+/// into corpus-driven ones: given a user-supplied text (a book, their own
+/// source tree, whatever), replace each bigram's hand-curated `examples`
+/// with words actually pulled from that corpus, so practice vocabulary is
+/// relevant to the user instead of fixed.
+///
+/// Unlike `bigram::bigrams_from_corpus` (which *derives* a whole new bigram
+/// table + frequencies from a corpus by counting every two-letter window),
+/// this module takes an existing, fixed list of bigram patterns and just
+/// hunts for example occurrences of each one. The search is a single
+/// byte-level pass per pattern: a SWAR (SIMD-within-a-register) probe finds
+/// candidate positions of the pattern's first byte eight at a time, and only
+/// those candidates pay the cost of verifying the rest of the pattern, so
+/// scanning a large corpus stays cheap even though most 8-byte windows don't
+/// contain a match.
+use super::bigram::Bigram;
+
+/// Examples kept per bigram, matching `bigrams_from_corpus`'s cap
+const MAX_EXAMPLES: usize = 10;
+
+/// Replace each bigram's `examples` with up to `MAX_EXAMPLES` shortest
+/// distinct whitespace-delimited words pulled from `corpus` that contain its
+/// `pattern` (case-insensitively). A bigram with no match in `corpus` keeps
+/// its original `examples` untouched, so the
+/// `examples`-contains-`pattern` invariant the rest of the crate relies on
+/// always holds even against a corpus that happens not to use that pattern.
+pub fn populate_examples_from_corpus(bigrams: &[Bigram], corpus: &str) -> Vec<Bigram> {
+    let lowercased = corpus.to_lowercase();
+    let haystack = lowercased.as_bytes();
+
+    bigrams
+        .iter()
+        .map(|bigram| {
+            let examples = examples_for_pattern(haystack, bigram.pattern.as_bytes());
+            if examples.is_empty() {
+                bigram.clone()
+            } else {
+                Bigram {
+                    pattern: bigram.pattern.clone(),
+                    frequency: bigram.frequency,
+                    examples,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Scan `haystack` for every occurrence of `pattern`, collecting the
+/// surrounding word at each hit, deduplicated and capped at
+/// `MAX_EXAMPLES` shortest.
+fn examples_for_pattern(haystack: &[u8], pattern: &[u8]) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut pos = 0;
+
+    while let Some(match_start) = find_pattern(haystack, pattern, pos) {
+        let match_end = match_start + pattern.len();
+        pos = match_end;
+
+        let Some(word) = word_around(haystack, match_start, match_end) else {
+            continue;
+        };
+
+        if words.iter().any(|w| w == word) {
+            continue;
+        }
+
+        words.push(word.to_string());
+        words.sort_by_key(|w| w.len());
+        words.truncate(MAX_EXAMPLES);
+    }
+
+    words
+}
+
+/// The whitespace-delimited span of `haystack` containing
+/// `haystack[match_start..match_end]`, as a `&str`. Splits only on ASCII
+/// whitespace bytes, which never occur inside a multi-byte UTF-8 sequence,
+/// so the returned span always lands on a valid `str` boundary even though
+/// this operates on raw bytes.
+fn word_around(haystack: &[u8], match_start: usize, match_end: usize) -> Option<&str> {
+    let mut start = match_start;
+    while start > 0 && !haystack[start - 1].is_ascii_whitespace() {
+        start -= 1;
+    }
+
+    let mut end = match_end;
+    while end < haystack.len() && !haystack[end].is_ascii_whitespace() {
+        end += 1;
+    }
+
+    std::str::from_utf8(&haystack[start..end]).ok()
+}
+
+/// First index at or after `start` where `pattern` occurs in `haystack`, or
+/// `None` if it doesn't occur again. Repeatedly probes for `pattern`'s first
+/// byte via `find_byte`'s SWAR scan, verifying the remaining bytes only at
+/// each candidate.
+fn find_pattern(haystack: &[u8], pattern: &[u8], start: usize) -> Option<usize> {
+    if pattern.is_empty() || pattern.len() > haystack.len() {
+        return None;
+    }
+
+    let first_byte = pattern[0];
+    let mut pos = start;
+
+    while let Some(candidate) = find_byte(haystack, pos, first_byte) {
+        let end = candidate + pattern.len();
+        if end <= haystack.len() && &haystack[candidate..end] == pattern {
+            return Some(candidate);
+        }
+        pos = candidate + 1;
+    }
+
+    None
+}
+
+/// First index at or after `start` where `haystack` contains byte `target`,
+/// or `None`. Tests 8 bytes at a time with the classic SWAR
+/// has-zero-byte trick (XOR against a broadcast of `target`, then a match
+/// turns that lane to `0x00`), only falling back to a byte-by-byte scan of a
+/// chunk once that chunk is known to contain a hit.
+fn find_byte(haystack: &[u8], start: usize, target: u8) -> Option<usize> {
+    let broadcast = u64::from_ne_bytes([target; 8]);
+    let mut i = start;
+
+    while i + 8 <= haystack.len() {
+        let chunk = u64::from_ne_bytes(haystack[i..i + 8].try_into().unwrap());
+        if has_zero_byte(chunk ^ broadcast) {
+            if let Some(offset) = haystack[i..i + 8].iter().position(|&b| b == target) {
+                return Some(i + offset);
+            }
+        }
+        i += 8;
+    }
+
+    haystack[i..]
+        .iter()
+        .position(|&b| b == target)
+        .map(|offset| i + offset)
+}
+
+/// Whether any of `v`'s 8 bytes is `0x00`
+fn has_zero_byte(v: u64) -> bool {
+    const LOW_BITS: u64 = 0x0101010101010101;
+    const HIGH_BITS: u64 = 0x8080808080808080;
+    v.wrapping_sub(LOW_BITS) & !v & HIGH_BITS != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_byte_finds_first_match() {
+        let haystack = b"the quick brown fox";
+        assert_eq!(find_byte(haystack, 0, b'q'), Some(4));
+    }
+
+    #[test]
+    fn test_find_byte_respects_start() {
+        let haystack = b"aaaaaaaaaab";
+        assert_eq!(find_byte(haystack, 5, b'b'), Some(10));
+    }
+
+    #[test]
+    fn test_find_byte_none_when_absent() {
+        let haystack = b"abcdefgh";
+        assert_eq!(find_byte(haystack, 0, b'z'), None);
+    }
+
+    #[test]
+    fn test_find_byte_matches_across_chunk_boundary() {
+        // 9 bytes forces the scan past one full 8-byte SWAR chunk
+        let haystack = b"aaaaaaaaz";
+        assert_eq!(find_byte(haystack, 0, b'z'), Some(8));
+    }
+
+    #[test]
+    fn test_find_pattern_finds_multi_byte_pattern() {
+        let haystack = b"the theater";
+        assert_eq!(find_pattern(haystack, b"th", 0), Some(0));
+        assert_eq!(find_pattern(haystack, b"th", 1), Some(4));
+    }
+
+    #[test]
+    fn test_find_pattern_none_when_absent() {
+        assert_eq!(find_pattern(b"hello world", b"xyz", 0), None);
+    }
+
+    #[test]
+    fn test_word_around_extracts_whitespace_delimited_span() {
+        let haystack = b"the quick brown fox";
+        // "brown" starts at byte 10, "ow" sits inside it
+        let word = word_around(haystack, 12, 14).unwrap();
+        assert_eq!(word, "brown");
+    }
+
+    #[test]
+    fn test_populate_examples_replaces_with_corpus_words() {
+        let bigrams = vec![Bigram::new("th", 1.00, &["placeholder"])];
+        let corpus = "the theater thinks";
+
+        let populated = populate_examples_from_corpus(&bigrams, corpus);
+        assert!(populated[0].examples.contains(&"the".to_string()));
+        assert!(!populated[0].examples.contains(&"placeholder".to_string()));
+    }
+
+    #[test]
+    fn test_populate_examples_keeps_original_when_no_match() {
+        let bigrams = vec![Bigram::new("zq", 1.00, &["zqzq"])];
+        let corpus = "the quick brown fox";
+
+        let populated = populate_examples_from_corpus(&bigrams, corpus);
+        assert_eq!(populated[0].examples, vec!["zqzq".to_string()]);
+    }
+
+    #[test]
+    fn test_populate_examples_is_case_insensitive() {
+        let bigrams = vec![Bigram::new("th", 1.00, &["placeholder"])];
+        let corpus = "THE Theater";
+
+        let populated = populate_examples_from_corpus(&bigrams, corpus);
+        assert!(!populated[0].examples.is_empty());
+    }
+
+    #[test]
+    fn test_populate_examples_deduplicates_and_caps_shortest_first() {
+        let bigrams = vec![Bigram::new("th", 1.00, &["placeholder"])];
+        let corpus = "theater theater theater the thin thing thus";
+
+        let populated = populate_examples_from_corpus(&bigrams, corpus);
+        let examples = &populated[0].examples;
+
+        let unique: std::collections::HashSet<&String> = examples.iter().collect();
+        assert_eq!(unique.len(), examples.len());
+        assert_eq!(examples[0], "the");
+    }
+
+    #[test]
+    fn test_populate_examples_preserves_frequency() {
+        let bigrams = vec![Bigram::new("th", 0.85, &["placeholder"])];
+        let populated = populate_examples_from_corpus(&bigrams, "the theater");
+        assert_eq!(populated[0].frequency, 0.85);
+    }
+}
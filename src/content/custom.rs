@@ -1,18 +1,55 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
 
+use serde::Deserialize;
+
+use super::lesson::CustomMetadata;
+use super::lesson::Difficulty;
 use super::lesson::Lesson;
 use super::lesson::LessonType;
+use super::markdown::{self, ContentMode};
 
 const MAX_FILE_SIZE: usize = 1_048_576; // 1MB
 
-/// Metadata extracted from YAML front matter
-#[derive(Debug, Clone, Default)]
+/// How often `CustomLessonLoader::watch` re-scans its roots for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Metadata extracted from YAML front matter. Deserialized directly from the
+/// block between the `---` delimiters, so unknown keys are ignored and a
+/// known key with the wrong type fails the whole parse (see
+/// `parse_markdown_file`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
 pub struct CustomLessonMetadata {
     pub title: Option<String>,
     pub description: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub repeat: bool,
+    /// How the body is turned into practice text: `prose` (default) strips
+    /// markdown markup down to readable text, `verbatim` preserves fenced
+    /// code blocks exactly
+    pub mode: ContentMode,
+}
+
+impl CustomLessonMetadata {
+    /// The subset of front matter that isn't already folded into `Lesson`'s
+    /// own `title`/`description` fields.
+    fn to_custom_metadata(&self) -> CustomMetadata {
+        CustomMetadata {
+            difficulty: self.difficulty,
+            tags: self.tags.clone(),
+            language: self.language.clone(),
+            repeat: self.repeat,
+        }
+    }
 }
 
 /// Parsed markdown file with metadata and content
@@ -26,8 +63,8 @@ pub struct ParsedMarkdown {
 #[derive(Debug)]
 pub enum ParseError {
     IoError(io::Error),
-    /// Public API: Reserved for future strict YAML validation
-    #[allow(dead_code)]
+    /// The block between the `---` delimiters isn't valid YAML, or a known
+    /// key (e.g. `difficulty`) has the wrong type
     InvalidFrontMatter(String),
     EmptyContent,
     FileTooLarge(usize),
@@ -59,6 +96,10 @@ impl From<io::Error> for ParseError {
 /// ---
 /// title: My Lesson
 /// description: Practice custom content
+/// difficulty: medium
+/// tags: [french, accents]
+/// language: fr
+/// repeat: true
 /// ---
 ///
 /// Actual content to practice goes here.
@@ -80,33 +121,33 @@ fn parse_markdown_file(path: &Path) -> Result<ParsedMarkdown, ParseError> {
         return Err(ParseError::EmptyContent);
     }
 
-    let mut lesson_metadata = CustomLessonMetadata::default();
+    let lesson_metadata: CustomLessonMetadata;
     let body_content: String;
 
     // Check if file starts with front matter delimiter
     if lines.first() == Some(&"---") {
         // Find the closing delimiter
         if let Some(end_index) = lines.iter().skip(1).position(|&line| line == "---") {
-            // Parse front matter (between first and second ---)
-            for line in &lines[1..end_index + 1] {
-                if let Some((key, value)) = parse_yaml_line(line) {
-                    match key.as_str() {
-                        "title" => lesson_metadata.title = Some(value),
-                        "description" => lesson_metadata.description = Some(value),
-                        _ => {} // Ignore unknown keys
-                    }
-                }
-            }
+            // Parse front matter (between first and second ---) as YAML
+            let front_matter = lines[1..end_index + 1].join("\n");
+            lesson_metadata = if front_matter.trim().is_empty() {
+                CustomLessonMetadata::default()
+            } else {
+                serde_yaml::from_str(&front_matter)
+                    .map_err(|e| ParseError::InvalidFrontMatter(e.to_string()))?
+            };
 
             // Extract body content after second ---
             let body_lines = &lines[end_index + 2..];
             body_content = body_lines.join("\n").trim().to_string();
         } else {
             // No closing delimiter, treat entire content as body
+            lesson_metadata = CustomLessonMetadata::default();
             body_content = content.trim().to_string();
         }
     } else {
         // No front matter, entire content is body
+        lesson_metadata = CustomLessonMetadata::default();
         body_content = content.trim().to_string();
     }
 
@@ -121,75 +162,140 @@ fn parse_markdown_file(path: &Path) -> Result<ParsedMarkdown, ParseError> {
     })
 }
 
-/// Parse a single YAML line in "key: value" format
-fn parse_yaml_line(line: &str) -> Option<(String, String)> {
-    let parts: Vec<&str> = line.splitn(2, ':').collect();
-    if parts.len() == 2 {
-        let key = parts[0].trim().to_string();
-        let value = parts[1].trim().to_string();
-        if !key.is_empty() && !value.is_empty() {
-            return Some((key, value));
-        }
-    }
-    None
-}
-
-/// Scan a directory for markdown files and convert them to Lessons
-fn scan_directory(dir: &Path) -> Vec<Lesson> {
-    // Return empty vec if directory doesn't exist
-    if !dir.exists() {
-        return Vec::new();
-    }
-
-    let mut lessons = Vec::new();
+/// Recursively collect every `.md` file under `dir` along with its last
+/// modification time, descending into subdirectories so lessons can be
+/// organized into folders. Symlinks are skipped rather than followed, so a
+/// symlink loop under a watched root can't send the recursion (re-run on
+/// every `watch` poll tick) into an infinite descent.
+fn collect_markdown_files(dir: &Path) -> Vec<(PathBuf, SystemTime)> {
+    let mut files = Vec::new();
 
-    // Read directory entries
     let entries = match fs::read_dir(dir) {
         Ok(entries) => entries,
-        Err(_) => return Vec::new(),
+        Err(_) => return files,
     };
 
     for entry in entries.flatten() {
-        let path = entry.path();
-
-        // Only process .md files
-        if path.extension().and_then(|s| s.to_str()) != Some("md") {
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+        if file_type.is_symlink() {
             continue;
         }
 
-        // Parse the markdown file
-        match parse_markdown_file(&path) {
-            Ok(parsed) => {
-                // Use title from metadata or filename (without extension)
-                let title = parsed.metadata.title.unwrap_or_else(|| {
-                    path.file_stem()
-                        .and_then(|s| s.to_str())
-                        .unwrap_or("Untitled")
-                        .to_string()
-                });
-
-                let description = parsed.metadata.description.unwrap_or_default();
-
-                // Create lesson with custom content
-                let lesson = Lesson {
-                    title,
-                    description,
-                    keys: Vec::new(), // Not applicable for custom lessons
-                    lesson_type: LessonType::Custom {
-                        content: parsed.content,
-                    },
-                };
+        let path = entry.path();
+        if file_type.is_dir() {
+            files.extend(collect_markdown_files(&path));
+        } else if path.extension().and_then(|s| s.to_str()) == Some("md") {
+            let modified = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((path, modified));
+        }
+    }
 
-                lessons.push(lesson);
-            }
-            Err(e) => {
-                // Print warning to stderr and continue
-                eprintln!("Warning: Failed to load \"{}\": {}", path.display(), e);
+    files
+}
+
+/// Build a hierarchical title from a file's path relative to its scan
+/// `root`, e.g. `root/french/basics/accents.md` with title "Accents"
+/// becomes `"french / basics / Accents"`; a file directly under `root`
+/// keeps its title unchanged.
+fn hierarchical_title(root: &Path, path: &Path, title: String) -> String {
+    let category = path
+        .strip_prefix(root)
+        .ok()
+        .and_then(|relative| relative.parent())
+        .map(|parent| {
+            parent
+                .components()
+                .filter_map(|component| component.as_os_str().to_str())
+                .collect::<Vec<_>>()
+                .join(" / ")
+        })
+        .filter(|category| !category.is_empty());
+
+    match category {
+        Some(category) => format!("{} / {}", category, title),
+        None => title,
+    }
+}
+
+/// Parse a single markdown file into a `Lesson`, warning to stderr and
+/// returning `None` on any parse failure
+fn load_lesson_file(root: &Path, path: &Path) -> Option<Lesson> {
+    match parse_markdown_file(path) {
+        Ok(parsed) => {
+            // Use title from metadata or filename (without extension)
+            let title = parsed.metadata.title.clone().unwrap_or_else(|| {
+                path.file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("Untitled")
+                    .to_string()
+            });
+            let title = hierarchical_title(root, path, title);
+
+            let description = parsed.metadata.description.clone().unwrap_or_default();
+            let custom_metadata = parsed.metadata.to_custom_metadata();
+            let content = markdown::normalize(&parsed.content, parsed.metadata.mode);
+
+            if content.trim().is_empty() {
+                eprintln!(
+                    "Warning: Failed to load \"{}\": normalized content is empty",
+                    path.display()
+                );
+                return None;
             }
+
+            Some(
+                Lesson::new(
+                    LessonType::Custom { content },
+                    title,
+                    description,
+                    Vec::new(), // Not applicable for custom lessons
+                )
+                .with_custom_metadata(custom_metadata),
+            )
+        }
+        Err(e) => {
+            // Print warning to stderr and continue
+            eprintln!("Warning: Failed to load \"{}\": {}", path.display(), e);
+            None
         }
     }
+}
+
+/// Recursively scan a directory for markdown files and convert them to Lessons
+fn scan_directory(dir: &Path) -> Vec<Lesson> {
+    if !dir.exists() {
+        return Vec::new();
+    }
 
-    lessons
+    collect_markdown_files(dir)
+        .into_iter()
+        .filter_map(|(path, _)| load_lesson_file(dir, &path))
+        .collect()
+}
+
+/// The directories `CustomLessonLoader` scans and watches: the user's
+/// config directory and the current directory's `./custom/`
+fn custom_lesson_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+
+    if let Ok(home) = std::env::var("HOME") {
+        roots.push(
+            PathBuf::from(home)
+                .join(".config")
+                .join("typer-cli")
+                .join("custom"),
+        );
+    }
+
+    roots.push(PathBuf::from("./custom"));
+
+    roots
 }
 
 /// Deduplicate lesson titles by appending (1), (2), (3) suffixes
@@ -215,6 +321,28 @@ fn deduplicate_titles(lessons: &mut [Lesson]) {
     }
 }
 
+/// Rename any of `lessons`'s titles that collide with one already in
+/// `existing`, appending (1), (2), ... suffixes until the title is free.
+/// Unlike `deduplicate_titles`, `existing`'s own titles are never touched —
+/// this is for merging custom lessons into a caller's pre-built list (e.g.
+/// the built-in lessons) without renaming the built-ins out from under any
+/// session history keyed by their title.
+pub(crate) fn rename_conflicting_titles(lessons: &mut [Lesson], existing: &HashSet<String>) {
+    let mut seen_titles = existing.clone();
+
+    for lesson in lessons.iter_mut() {
+        if seen_titles.contains(&lesson.title) {
+            let base = lesson.title.clone();
+            let mut suffix = 1;
+            while seen_titles.contains(&lesson.title) {
+                lesson.title = format!("{} ({})", base, suffix);
+                suffix += 1;
+            }
+        }
+        seen_titles.insert(lesson.title.clone());
+    }
+}
+
 /// Loader for custom user-provided lessons
 pub struct CustomLessonLoader;
 
@@ -223,58 +351,125 @@ impl CustomLessonLoader {
     pub fn load_all() -> Vec<Lesson> {
         let mut lessons = Vec::new();
 
-        // Load from config directory: ~/.config/typer-cli/custom/
-        if let Ok(home) = std::env::var("HOME") {
-            let custom_dir = PathBuf::from(home)
-                .join(".config")
-                .join("typer-cli")
-                .join("custom");
-            lessons.extend(scan_directory(&custom_dir));
+        for root in custom_lesson_roots() {
+            lessons.extend(scan_directory(&root));
         }
 
-        // Load from current directory: ./custom/
-        let current_dir = PathBuf::from("./custom");
-        lessons.extend(scan_directory(&current_dir));
-
         // Deduplicate titles across both sources
         deduplicate_titles(&mut lessons);
 
         lessons
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Watch `~/.config/typer-cli/custom/` and `./custom/` (recursively)
+    /// for created, modified, or removed `.md` files. On any change,
+    /// re-parses just the files that changed, re-runs `deduplicate_titles`
+    /// over the full set, and calls `callback` with the refreshed lesson
+    /// list — so a running UI can refresh without restarting. The watcher
+    /// stops and its background thread is joined when the returned
+    /// `WatchHandle` is dropped.
+    pub fn watch(callback: impl Fn(Vec<Lesson>) + Send + 'static) -> WatchHandle {
+        watch_roots(custom_lesson_roots(), WATCH_POLL_INTERVAL, callback)
+    }
+}
 
-    #[test]
-    fn test_parse_yaml_line_valid() {
-        assert_eq!(
-            parse_yaml_line("title: My Lesson"),
-            Some(("title".to_string(), "My Lesson".to_string()))
-        );
+/// Spawns the polling background thread behind `CustomLessonLoader::watch`;
+/// factored out so tests can watch a temp directory on a fast interval
+/// instead of the real config/cwd roots.
+fn watch_roots(
+    roots: Vec<PathBuf>,
+    interval: Duration,
+    callback: impl Fn(Vec<Lesson>) + Send + 'static,
+) -> WatchHandle {
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread_stop = Arc::clone(&stop);
+
+    let thread = thread::spawn(move || {
+        let mut cache: HashMap<PathBuf, (SystemTime, Option<Lesson>)> = HashMap::new();
+
+        while !thread_stop.load(Ordering::Relaxed) {
+            if refresh_lesson_cache(&roots, &mut cache) {
+                // Sort by path first so lesson order — and therefore the
+                // (1)/(2) suffixes `deduplicate_titles` assigns to same-titled
+                // lessons — stays stable across polls, instead of drifting
+                // with HashMap's unspecified iteration order.
+                let mut entries: Vec<(&PathBuf, &Lesson)> = cache
+                    .iter()
+                    .filter_map(|(p, (_, lesson))| lesson.as_ref().map(|l| (p, l)))
+                    .collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                let mut lessons: Vec<Lesson> =
+                    entries.into_iter().map(|(_, lesson)| lesson.clone()).collect();
+                deduplicate_titles(&mut lessons);
+                callback(lessons);
+            }
+            thread::sleep(interval);
+        }
+    });
 
-        assert_eq!(
-            parse_yaml_line("description: Practice typing"),
-            Some(("description".to_string(), "Practice typing".to_string()))
-        );
+    WatchHandle {
+        stop,
+        thread: Some(thread),
     }
+}
 
-    #[test]
-    fn test_parse_yaml_line_with_colon_in_value() {
-        assert_eq!(
-            parse_yaml_line("title: Lesson: Advanced"),
-            Some(("title".to_string(), "Lesson: Advanced".to_string()))
-        );
+/// Re-scan `roots` and update `cache` in place: (re-)parse any file that's
+/// new or whose modification time changed — caching a parse failure's
+/// mtime too, so a persistently-invalid file is only re-parsed (and
+/// re-warned about) once per edit, not on every poll tick — and drop any
+/// file that's gone. Returns whether anything in `cache` changed.
+fn refresh_lesson_cache(
+    roots: &[PathBuf],
+    cache: &mut HashMap<PathBuf, (SystemTime, Option<Lesson>)>,
+) -> bool {
+    let mut seen = HashSet::new();
+    let mut changed = false;
+
+    for root in roots {
+        if !root.exists() {
+            continue;
+        }
+
+        for (path, mtime) in collect_markdown_files(root) {
+            seen.insert(path.clone());
+
+            let up_to_date = cache
+                .get(&path)
+                .is_some_and(|(cached_mtime, _)| *cached_mtime == mtime);
+            if up_to_date {
+                continue;
+            }
+
+            cache.insert(path.clone(), (mtime, load_lesson_file(root, &path)));
+            changed = true;
+        }
     }
 
-    #[test]
-    fn test_parse_yaml_line_invalid() {
-        assert_eq!(parse_yaml_line("invalid line"), None);
-        assert_eq!(parse_yaml_line("key:"), None);
-        assert_eq!(parse_yaml_line(":value"), None);
-        assert_eq!(parse_yaml_line(""), None);
+    let before = cache.len();
+    cache.retain(|path, _| seen.contains(path));
+    changed || cache.len() != before
+}
+
+/// Handle to a background `CustomLessonLoader::watch` task. Dropping it
+/// signals the watcher to stop and blocks until its thread exits.
+pub struct WatchHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
 
     #[test]
     fn test_parse_front_matter_full() {
@@ -333,6 +528,223 @@ mod tests {
         fs::remove_file(temp_file).unwrap();
     }
 
+    #[test]
+    fn test_parse_front_matter_rich_metadata() {
+        let content = "---\ntitle: Accents\ndifficulty: hard\ntags: [french, accents]\nlanguage: fr\nrepeat: true\n---\n\nContent here";
+        let temp_file = std::env::temp_dir().join("test_rich.md");
+        fs::write(&temp_file, content).unwrap();
+
+        let result = parse_markdown_file(&temp_file).unwrap();
+        assert_eq!(result.metadata.difficulty, Some(Difficulty::Hard));
+        assert_eq!(result.metadata.tags, vec!["french", "accents"]);
+        assert_eq!(result.metadata.language, Some("fr".to_string()));
+        assert!(result.metadata.repeat);
+
+        fs::remove_file(temp_file).unwrap();
+    }
+
+    #[test]
+    fn test_parse_front_matter_rich_metadata_defaults() {
+        let content = "---\ntitle: Plain\n---\n\nContent here";
+        let temp_file = std::env::temp_dir().join("test_rich_defaults.md");
+        fs::write(&temp_file, content).unwrap();
+
+        let result = parse_markdown_file(&temp_file).unwrap();
+        assert_eq!(result.metadata.difficulty, None);
+        assert!(result.metadata.tags.is_empty());
+        assert_eq!(result.metadata.language, None);
+        assert!(!result.metadata.repeat);
+
+        fs::remove_file(temp_file).unwrap();
+    }
+
+    #[test]
+    fn test_parse_front_matter_rejects_wrong_typed_known_key() {
+        let content = "---\ntitle: Bad\ndifficulty: [not, a, string]\n---\n\nContent here";
+        let temp_file = std::env::temp_dir().join("test_bad_type.md");
+        fs::write(&temp_file, content).unwrap();
+
+        let result = parse_markdown_file(&temp_file);
+        assert!(matches!(result, Err(ParseError::InvalidFrontMatter(_))));
+
+        fs::remove_file(temp_file).unwrap();
+    }
+
+    #[test]
+    fn test_parse_front_matter_rejects_unparsable_yaml() {
+        let content = "---\ntitle: [unclosed\n---\n\nContent here";
+        let temp_file = std::env::temp_dir().join("test_bad_yaml.md");
+        fs::write(&temp_file, content).unwrap();
+
+        let result = parse_markdown_file(&temp_file);
+        assert!(matches!(result, Err(ParseError::InvalidFrontMatter(_))));
+
+        fs::remove_file(temp_file).unwrap();
+    }
+
+    #[test]
+    fn test_scan_directory_threads_custom_metadata_into_lesson() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_scan_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("drill.md"),
+            "---\ntitle: Drill\ndifficulty: easy\ntags: [warmup]\nrepeat: true\n---\n\nPractice text",
+        )
+        .unwrap();
+
+        let lessons = scan_directory(&dir);
+        assert_eq!(lessons.len(), 1);
+        let metadata = lessons[0].custom_metadata.as_ref().unwrap();
+        assert_eq!(metadata.difficulty, Some(Difficulty::Easy));
+        assert_eq!(metadata.tags, vec!["warmup"]);
+        assert!(metadata.repeat);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scan_directory_normalizes_content_to_prose_by_default() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_scan_prose_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("drill.md"), "# Heading\n\nSome **bold** text").unwrap();
+
+        let lessons = scan_directory(&dir);
+        assert_eq!(lessons.len(), 1);
+        match &lessons[0].lesson_type {
+            LessonType::Custom { content } => {
+                assert_eq!(content, "Heading\n\nSome bold text");
+            }
+            _ => panic!("expected a Custom lesson"),
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scan_directory_preserves_code_block_verbatim_when_requested() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_scan_verbatim_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("drill.md"),
+            "---\nmode: verbatim\n---\n\nIntro text\n\n```\nfn main() {\n    println!(\"hi\");\n}\n```",
+        )
+        .unwrap();
+
+        let lessons = scan_directory(&dir);
+        assert_eq!(lessons.len(), 1);
+        match &lessons[0].lesson_type {
+            LessonType::Custom { content } => {
+                assert_eq!(content, "fn main() {\n    println!(\"hi\");\n}");
+            }
+            _ => panic!("expected a Custom lesson"),
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scan_directory_skips_file_that_normalizes_to_empty_content() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_scan_empty_after_normalize_test");
+        fs::create_dir_all(&dir).unwrap();
+        // Passes the raw-body EmptyContent check (it's not literally empty)
+        // but normalizes down to nothing: a bare heading marker with no text.
+        fs::write(dir.join("drill.md"), "#").unwrap();
+
+        let lessons = scan_directory(&dir);
+        assert!(lessons.is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_collect_markdown_files_recurses_into_subdirectories() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_recurse_test");
+        fs::create_dir_all(dir.join("french/basics")).unwrap();
+        fs::write(dir.join("top.md"), "Top level").unwrap();
+        fs::write(dir.join("french/basics/accents.md"), "Nested").unwrap();
+        fs::write(dir.join("french/not_markdown.txt"), "ignored").unwrap();
+
+        let mut files: Vec<String> = collect_markdown_files(&dir)
+            .into_iter()
+            .map(|(p, _)| p.strip_prefix(&dir).unwrap().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+
+        assert_eq!(files, vec!["french/basics/accents.md", "top.md"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_hierarchical_title_includes_subdirectory_path() {
+        let root = Path::new("/custom");
+        let path = Path::new("/custom/french/basics/accents.md");
+        assert_eq!(
+            hierarchical_title(root, path, "Accents".to_string()),
+            "french / basics / Accents"
+        );
+    }
+
+    #[test]
+    fn test_hierarchical_title_root_file_has_no_category() {
+        let root = Path::new("/custom");
+        let path = Path::new("/custom/accents.md");
+        assert_eq!(
+            hierarchical_title(root, path, "Accents".to_string()),
+            "Accents"
+        );
+    }
+
+    #[test]
+    fn test_scan_directory_recurses_and_builds_hierarchical_title() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_scan_recurse_test");
+        fs::create_dir_all(dir.join("french")).unwrap();
+        fs::write(dir.join("french/accents.md"), "Practice text").unwrap();
+
+        let lessons = scan_directory(&dir);
+        assert_eq!(lessons.len(), 1);
+        assert_eq!(lessons[0].title, "french / accents");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watch_calls_back_on_created_modified_and_removed_files() {
+        let dir = std::env::temp_dir().join("typer_cli_custom_watch_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let updates = Arc::new(Mutex::new(Vec::<Vec<String>>::new()));
+        let updates_clone = Arc::clone(&updates);
+
+        let handle = watch_roots(vec![dir.clone()], Duration::from_millis(20), move |lessons| {
+            let mut titles: Vec<String> = lessons.into_iter().map(|l| l.title).collect();
+            titles.sort();
+            updates_clone.lock().unwrap().push(titles);
+        });
+
+        // Created
+        fs::write(dir.join("one.md"), "First lesson").unwrap();
+        thread::sleep(Duration::from_millis(150));
+
+        // Modified (new title via front matter)
+        fs::write(dir.join("one.md"), "---\ntitle: Renamed\n---\n\nFirst lesson").unwrap();
+        thread::sleep(Duration::from_millis(150));
+
+        // Removed
+        fs::remove_file(dir.join("one.md")).unwrap();
+        thread::sleep(Duration::from_millis(150));
+
+        drop(handle);
+
+        let seen = updates.lock().unwrap().clone();
+        assert!(seen.contains(&vec!["one".to_string()]));
+        assert!(seen.contains(&vec!["Renamed".to_string()]));
+        assert!(seen.iter().any(|titles| titles.is_empty()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_parse_markdown_preserves_formatting() {
         let content = "---\ntitle: Test\n---\n\nLine 1\n  Indented line\nLine 3";
@@ -348,22 +760,22 @@ mod tests {
     #[test]
     fn test_deduplicate_titles_none() {
         let mut lessons = vec![
-            Lesson {
-                title: "Lesson 1".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+            Lesson::new(
+                LessonType::Custom {
                     content: "content".to_string(),
                 },
-            },
-            Lesson {
-                title: "Lesson 2".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+                "Lesson 1".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Custom {
                     content: "content".to_string(),
                 },
-            },
+                "Lesson 2".to_string(),
+                "".to_string(),
+                vec![],
+            ),
         ];
 
         deduplicate_titles(&mut lessons);
@@ -375,22 +787,22 @@ mod tests {
     #[test]
     fn test_deduplicate_titles_two() {
         let mut lessons = vec![
-            Lesson {
-                title: "Same".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+            Lesson::new(
+                LessonType::Custom {
                     content: "content1".to_string(),
                 },
-            },
-            Lesson {
-                title: "Same".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+                "Same".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Custom {
                     content: "content2".to_string(),
                 },
-            },
+                "Same".to_string(),
+                "".to_string(),
+                vec![],
+            ),
         ];
 
         deduplicate_titles(&mut lessons);
@@ -402,30 +814,30 @@ mod tests {
     #[test]
     fn test_deduplicate_titles_three() {
         let mut lessons = vec![
-            Lesson {
-                title: "Duplicate".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+            Lesson::new(
+                LessonType::Custom {
                     content: "content1".to_string(),
                 },
-            },
-            Lesson {
-                title: "Duplicate".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+                "Duplicate".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Custom {
                     content: "content2".to_string(),
                 },
-            },
-            Lesson {
-                title: "Duplicate".to_string(),
-                description: "".to_string(),
-                keys: vec![],
-                lesson_type: LessonType::Custom {
+                "Duplicate".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Custom {
                     content: "content3".to_string(),
                 },
-            },
+                "Duplicate".to_string(),
+                "".to_string(),
+                vec![],
+            ),
         ];
 
         deduplicate_titles(&mut lessons);
@@ -434,4 +846,70 @@ mod tests {
         assert_eq!(lessons[1].title, "Duplicate (2)");
         assert_eq!(lessons[2].title, "Duplicate (3)");
     }
+
+    #[test]
+    fn test_rename_conflicting_titles_leaves_existing_untouched() {
+        let existing: HashSet<String> = ["Built-in".to_string()].into_iter().collect();
+        let mut lessons = vec![Lesson::new(
+            LessonType::Custom {
+                content: "content".to_string(),
+            },
+            "Unrelated".to_string(),
+            "".to_string(),
+            vec![],
+        )];
+
+        rename_conflicting_titles(&mut lessons, &existing);
+
+        assert_eq!(lessons[0].title, "Unrelated");
+    }
+
+    #[test]
+    fn test_rename_conflicting_titles_renames_collision_with_existing() {
+        let existing: HashSet<String> = ["Built-in".to_string()].into_iter().collect();
+        let mut lessons = vec![Lesson::new(
+            LessonType::Custom {
+                content: "content".to_string(),
+            },
+            "Built-in".to_string(),
+            "".to_string(),
+            vec![],
+        )];
+
+        rename_conflicting_titles(&mut lessons, &existing);
+
+        assert_eq!(lessons[0].title, "Built-in (1)");
+        assert!(existing.contains("Built-in"));
+    }
+
+    #[test]
+    fn test_rename_conflicting_titles_skips_a_suffix_already_taken_by_a_sibling() {
+        // "Built-in (1)" is already a distinct, legitimate custom lesson
+        // title; the lesson colliding with "Built-in" must not be renamed
+        // into it.
+        let existing: HashSet<String> = ["Built-in".to_string()].into_iter().collect();
+        let mut lessons = vec![
+            Lesson::new(
+                LessonType::Custom {
+                    content: "content1".to_string(),
+                },
+                "Built-in (1)".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Custom {
+                    content: "content2".to_string(),
+                },
+                "Built-in".to_string(),
+                "".to_string(),
+                vec![],
+            ),
+        ];
+
+        rename_conflicting_titles(&mut lessons, &existing);
+
+        assert_eq!(lessons[0].title, "Built-in (1)");
+        assert_eq!(lessons[1].title, "Built-in (2)");
+    }
 }
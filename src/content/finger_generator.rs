@@ -1,10 +1,13 @@
 use crate::content::lesson::FingerPairType;
-use crate::keyboard::azerty::{AzertyLayout, Finger, RowType};
+use crate::keyboard::{Finger, KeyboardLayout, RowType};
 use rand::seq::SliceRandom;
+use std::collections::HashMap;
 
-/// Extract keys assigned to a specific finger pair at a given difficulty level
+/// Extract keys assigned to a specific finger pair at a given difficulty level.
+/// Generic over `KeyboardLayout` so a Colemak or Dvorak user's finger-pair
+/// lessons drill their own layout's key assignments, not AZERTY's.
 pub fn get_finger_pair_keys(
-    layout: &AzertyLayout,
+    layout: &dyn KeyboardLayout,
     finger_pair: FingerPairType,
     level: u8,
     with_shift: bool,
@@ -30,7 +33,7 @@ pub fn get_finger_pair_keys(
 
     let mut keys = Vec::new();
 
-    for row in &layout.rows {
+    for row in layout.rows() {
         if !allowed_rows.contains(&row.row_type) {
             continue;
         }
@@ -79,6 +82,97 @@ pub fn generate_finger_drills(keys: &[char], length: usize, with_shift: bool) ->
     }
 }
 
+/// Weight every key gets before any error-rate bias is applied
+const BASE_KEY_WEIGHT: f32 = 1.0;
+
+/// How strongly a key's recent error rate (0.0-1.0) raises its sampling weight
+const ERROR_RATE_WEIGHT_FACTOR: f32 = 5.0;
+
+/// Upper bound on a single key's weight, so one wildly mistyped key doesn't
+/// crowd out every other key in the drill
+const MAX_KEY_WEIGHT: f32 = 10.0;
+
+/// Generate finger-pair drill content the same 3-phase way as
+/// `generate_finger_drills`'s shift path, but sampling each key with a
+/// frequency proportional to its recent error rate (e.g. from
+/// `AdaptiveAnalytics::key_stats`) instead of the fixed case-bucket weights.
+/// Keys missing from `error_rates` are treated as error-free. Feeds the
+/// `Adaptive` category's weak keys back into the finger-pair drills.
+pub fn generate_adaptive_finger_drills(
+    keys: &[char],
+    error_rates: &HashMap<char, f32>,
+    length: usize,
+) -> String {
+    if keys.is_empty() {
+        return String::new();
+    }
+
+    let mut rng = rand::thread_rng();
+    let pool = build_weighted_pool(keys, error_rates);
+
+    let mut patterns = Vec::new();
+
+    // Phase 1: Repetitions
+    for _ in 0..20 {
+        let c = pool.choose(&mut rng).unwrap();
+        patterns.push(format!("{}{}", c, c));
+    }
+
+    // Phase 2: Pairs
+    for _ in 0..30 {
+        let c1 = pool.choose(&mut rng).unwrap();
+        let c2 = pool.choose(&mut rng).unwrap();
+        patterns.push(format!("{}{}", c1, c2));
+    }
+
+    // Phase 3: Triplets
+    for _ in 0..50 {
+        let c1 = pool.choose(&mut rng).unwrap();
+        let c2 = pool.choose(&mut rng).unwrap();
+        let c3 = pool.choose(&mut rng).unwrap();
+        patterns.push(format!("{}{}{}", c1, c2, c3));
+    }
+
+    // Generate content by cycling through patterns
+    let mut result = String::new();
+    let mut idx = 0;
+    while result.len() < length {
+        if !result.is_empty() {
+            result.push(' ');
+            if result.len() >= length {
+                break;
+            }
+        }
+        let pattern = &patterns[idx % patterns.len()];
+        if result.len() + pattern.len() > length {
+            break;
+        }
+        result.push_str(pattern);
+        idx += 1;
+    }
+
+    result
+}
+
+/// Build a sampling pool where each key appears a count proportional to
+/// `BASE_KEY_WEIGHT + ERROR_RATE_WEIGHT_FACTOR * error_rate`, clamped to
+/// `MAX_KEY_WEIGHT`, so frequently-missed keys are drawn more often.
+fn build_weighted_pool(keys: &[char], error_rates: &HashMap<char, f32>) -> Vec<char> {
+    /// How many pool entries one unit of weight buys; higher gives finer
+    /// proportional control over relative sampling frequency.
+    const POOL_SCALE: f32 = 10.0;
+
+    let mut pool = Vec::new();
+    for &key in keys {
+        let error_rate = error_rates.get(&key).copied().unwrap_or(0.0);
+        let weight = (BASE_KEY_WEIGHT + ERROR_RATE_WEIGHT_FACTOR * error_rate)
+            .clamp(BASE_KEY_WEIGHT, MAX_KEY_WEIGHT);
+        let count = (weight * POOL_SCALE).round().max(1.0) as usize;
+        pool.extend(std::iter::repeat(key).take(count));
+    }
+    pool
+}
+
 /// Generate drills with only base characters (3-phase pattern)
 fn generate_base_drills(keys: &[char], length: usize) -> String {
     let mut result = String::new();
@@ -227,6 +321,7 @@ fn generate_shift_drills(keys: &[char], length: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keyboard::AzertyLayout;
 
     #[test]
     fn test_middle_home_row_no_shift() {
@@ -375,4 +470,83 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_build_weighted_pool_empty_error_rates_gives_equal_counts() {
+        let pool = build_weighted_pool(&['d', 'k'], &HashMap::new());
+        let d_count = pool.iter().filter(|&&c| c == 'd').count();
+        let k_count = pool.iter().filter(|&&c| c == 'k').count();
+        assert_eq!(d_count, k_count);
+    }
+
+    #[test]
+    fn test_build_weighted_pool_favors_high_error_rate_key() {
+        let mut error_rates = HashMap::new();
+        error_rates.insert('d', 1.0);
+
+        let pool = build_weighted_pool(&['d', 'k'], &error_rates);
+        let d_count = pool.iter().filter(|&&c| c == 'd').count();
+        let k_count = pool.iter().filter(|&&c| c == 'k').count();
+
+        assert!(
+            d_count > k_count,
+            "high error-rate key should be sampled more"
+        );
+    }
+
+    #[test]
+    fn test_build_weighted_pool_clamps_extreme_error_rate() {
+        let mut error_rates = HashMap::new();
+        error_rates.insert('d', 1000.0);
+
+        let pool = build_weighted_pool(&['d'], &error_rates);
+        let expected_max = (MAX_KEY_WEIGHT * 10.0).round() as usize;
+        assert_eq!(pool.len(), expected_max);
+    }
+
+    #[test]
+    fn test_generate_adaptive_finger_drills_empty_keys() {
+        assert_eq!(
+            generate_adaptive_finger_drills(&[], &HashMap::new(), 50),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_generate_adaptive_finger_drills_respects_length() {
+        let mut error_rates = HashMap::new();
+        error_rates.insert('d', 0.8);
+
+        let content = generate_adaptive_finger_drills(&['d', 'k'], &error_rates, 80);
+        assert!(!content.is_empty());
+        assert!(content.len() <= 80);
+    }
+
+    #[test]
+    fn test_generate_adaptive_finger_drills_weighted_key_appears_more() {
+        let mut error_rates = HashMap::new();
+        error_rates.insert('d', 1.0);
+
+        let content = generate_adaptive_finger_drills(&['d', 'k'], &error_rates, 2000);
+        let d_count = content.chars().filter(|&c| c == 'd').count();
+        let k_count = content.chars().filter(|&c| c == 'k').count();
+
+        assert!(d_count > k_count, "weighted key should dominate the drill");
+    }
+
+    #[test]
+    fn test_resolves_keys_from_selected_layout_not_just_azerty() {
+        use crate::keyboard::QwertyLayout;
+
+        let azerty_keys =
+            get_finger_pair_keys(&AzertyLayout::new(), FingerPairType::Index, 3, false);
+        let qwerty_keys =
+            get_finger_pair_keys(&QwertyLayout::new(), FingerPairType::Index, 3, false);
+
+        assert!(!qwerty_keys.is_empty());
+        assert_ne!(
+            azerty_keys, qwerty_keys,
+            "QWERTY's index-finger number row should differ from AZERTY's"
+        );
+    }
 }
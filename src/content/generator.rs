@@ -1,6 +1,19 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::bigram::Language;
 use super::bigram_generator::BigramGenerator;
+use super::chord_generator::ChordGenerator;
+use super::code_corpus_generator::CodeCorpusGenerator;
 use super::code_generator::CodeSymbolGenerator;
-use super::lesson::{Lesson, LessonType};
+use super::common_word_generator::CommonWordGenerator;
+use super::finger_generator::{generate_finger_drills, get_finger_pair_keys};
+use super::identifier_generator::IdentifierGenerator;
+use super::inflection_generator::InflectionGenerator;
+use super::lesson::{get_shifted_char, Lesson, LessonType, KEY_PAIR_GROUPS, KEY_PAIR_LESSONS};
+use super::trigram_generator::TrigramGenerator;
+use super::word_markov;
+use crate::keyboard::{AzertyLayout, KeyboardLayout};
 
 /// Trait pour générer du contenu de leçon
 pub trait ContentGenerator {
@@ -10,51 +23,120 @@ pub trait ContentGenerator {
 impl ContentGenerator for Lesson {
     fn generate(&self, length: usize) -> String {
         match &self.lesson_type {
-            LessonType::HomeRow { level } => match level {
-                1 => generate_two_key_drills(&self.keys, length),
-                2..=5 => generate_progressive_drills(&self.keys, length),
-                6 => generate_words(&self.keys, length),
-                _ => String::new(),
-            },
+            LessonType::KeyPair { .. } => generate_key_pair_drill(&self.keys, length),
+            LessonType::KeyPairGroup { group_id, with_shift } => {
+                let layout = AzertyLayout::new();
+                let keys = keys_for_group(&layout, *group_id, *with_shift);
+                generate_finger_drills(&keys, length, *with_shift)
+            }
+            LessonType::Trigram { language, level } => {
+                let generator = TrigramGenerator::new(*language);
+                generator.generate(*level, length)
+            }
+            LessonType::CommonWords { language, level } => {
+                let generator = CommonWordGenerator::new(*language);
+                generator.generate(*level, length)
+            }
+            // App intercepts `Adaptive` lessons before ever calling
+            // `generate()` on them (see `App::run`), so this arm only
+            // exists to keep the match exhaustive.
+            LessonType::Adaptive => String::new(),
             LessonType::Bigram {
                 bigram_type,
                 language,
                 level,
             } => {
-                let generator = BigramGenerator::new(*bigram_type, *language);
+                let generator = BigramGenerator::new(bigram_type.clone(), *language);
                 generator.generate(*level, length)
             }
             LessonType::CodeSymbols { language, level } => {
                 let generator = CodeSymbolGenerator::new(*language);
                 generator.generate(*level, length)
             }
+            LessonType::CodeSnippet { language, level } => {
+                let generator = CodeCorpusGenerator::new(*language);
+                generator.generate(*level, length)
+            }
+            LessonType::Chord { level } => {
+                let generator = ChordGenerator::new();
+                generator.generate(*level, length)
+            }
+            LessonType::Identifier { style, level, .. } => {
+                let generator = IdentifierGenerator::new(*style);
+                generator.generate(*level, length)
+            }
+            LessonType::Inflection { language, level } => {
+                let generator = InflectionGenerator::new(*language);
+                generator.generate(*level, length)
+            }
+            // `App::start_lesson` loads a custom lesson's full content
+            // directly rather than chunking it through here, and
+            // `App::generate_more_content` never re-requests more of it
+            // (both to avoid re-appending the same prefix on every refill —
+            // unlike every other lesson type, this content is fixed, not
+            // freshly generated per call). This arm still truncates to
+            // `length` so the lesson-list preview pane, which does call
+            // `generate()` directly, gets a proper excerpt.
+            LessonType::Custom { content } => content.chars().take(length).collect(),
+            LessonType::FingerPair {
+                finger_pair,
+                level,
+                with_shift,
+            } => {
+                // `ContentGenerator::generate` doesn't carry the user's selected
+                // `KeyboardLayout`, so this defaults to AZERTY; `get_finger_pair_keys`
+                // itself is layout-generic (see `content::finger_generator`).
+                let layout = AzertyLayout::new();
+                let keys = get_finger_pair_keys(&layout, *finger_pair, *level, *with_shift);
+                generate_finger_drills(&keys, length, *with_shift)
+            }
         }
     }
 }
 
-/// Générer des drills avec 2 touches (niveau 1-4)
-/// Pattern: "ff jj ff jj dd kk dd kk"
-fn generate_two_key_drills(keys: &[char], length: usize) -> String {
-    if keys.len() != 2 {
-        return String::new();
+/// Collect the keys taught across a `KeyPairGroup`'s member lessons
+/// (`KeyPairGroup` itself carries no keys — see `Lesson::key_pair_group_lessons`).
+/// When `with_shift` is set, each letter key's uppercase form on `layout` is
+/// appended too, via the same `get_shifted_char` lookup `FingerPair` lessons
+/// use. Non-letter keys are left as-is: the key-pair lessons already list a
+/// punctuation key's shifted glyph as its own separate key where relevant
+/// (see e.g. lesson 14's `'/'`/`':'` pair), so blindly shifting them again
+/// would substitute in an unrelated character (AZERTY's number row, say).
+fn keys_for_group(layout: &dyn KeyboardLayout, group_id: u8, with_shift: bool) -> Vec<char> {
+    let Some(group) = KEY_PAIR_GROUPS.iter().find(|g| g.group_id == group_id) else {
+        return Vec::new();
+    };
+    let (start, end) = group.lesson_range;
+
+    let mut keys: Vec<char> = KEY_PAIR_LESSONS
+        .iter()
+        .filter(|def| def.id >= start && def.id <= end)
+        .flat_map(|def| def.keys.iter().copied())
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    if with_shift {
+        let shifted: Vec<char> = keys
+            .iter()
+            .filter(|c| c.is_alphabetic())
+            .filter_map(|&c| get_shifted_char(layout, c))
+            .filter(|c| !keys.contains(c))
+            .collect();
+        keys.extend(shifted);
     }
 
-    let mut result = String::new();
-    let pattern = [
-        format!("{}{}", keys[0], keys[0]),
-        format!("{}{}", keys[1], keys[1]),
-    ];
+    keys
+}
 
-    let mut idx = 0;
-    while result.len() < length {
-        if !result.is_empty() {
-            result.push(' ');
-        }
-        result.push_str(&pattern[idx % pattern.len()]);
-        idx += 1;
+/// Drill content for an individual key-pair lesson's key set: progressively
+/// built words restricted to those keys (see `Lesson::key_pair_lessons`).
+fn generate_key_pair_drill(keys: &[char], length: usize) -> String {
+    if keys.is_empty() {
+        return String::new();
     }
 
-    result.chars().take(length).collect()
+    generate_words(keys, length, Language::French)
 }
 
 /// Générer des drills progressifs avec les touches disponibles
@@ -103,24 +185,36 @@ fn generate_progressive_drills(keys: &[char], length: usize) -> String {
     result.chars().take(length).collect()
 }
 
-/// Générer des mots simples français avec les touches home row
-/// Mots possibles avec q,s,d,f,g,h,j,k,l,m: limité mais quelques mots existent
-fn generate_words(_keys: &[char], length: usize) -> String {
-    // Mots courts français possibles avec home row AZERTY
-    // Note: très limité, principalement pour démonstration
-    let words = vec![
-        "la", "le", "de", "se", "me", "je", "mal", "sel", "les", "des", "mes",
-    ];
+/// Derive a deterministic RNG seed from the allowed keys + length so
+/// repeated calls with the same arguments reproduce the same content
+fn seed_for_words(keys: &[char], length: usize) -> u64 {
+    let keys_sum: u64 = keys.iter().map(|&c| c as u64).sum();
+    (keys_sum << 32) | (length as u64)
+}
+
+/// Générer des mots pseudo-aléatoires restreints aux touches déjà
+/// débloquées, via un modèle de Markov par caractère (voir
+/// `content::word_markov`). Se rabat sur des répétitions de touches si le
+/// jeu de touches est trop restreint pour former des mots.
+fn generate_words(keys: &[char], length: usize, language: Language) -> String {
+    if keys.is_empty() {
+        return String::new();
+    }
 
+    let mut rng = StdRng::seed_from_u64(seed_for_words(keys, length));
     let mut result = String::new();
-    let mut idx = 0;
 
     while result.len() < length {
+        let Some(word) = word_markov::generate_word(&mut rng, language, keys) else {
+            // Not enough of the allowed keys chain into real words; fall
+            // back to simple progressive drills on the same key set.
+            return generate_progressive_drills(keys, length);
+        };
+
         if !result.is_empty() {
             result.push(' ');
         }
-        result.push_str(words[idx % words.len()]);
-        idx += 1;
+        result.push_str(&word);
     }
 
     result.chars().take(length).collect()
@@ -130,13 +224,6 @@ fn generate_words(_keys: &[char], length: usize) -> String {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_generate_two_key_drills() {
-        let result = generate_two_key_drills(&['f', 'j'], 15);
-        assert!(result.starts_with("ff jj ff jj"));
-        assert!(result.len() <= 15);
-    }
-
     #[test]
     fn test_generate_progressive_drills() {
         let keys = vec!['f', 'j', 'd', 'k'];
@@ -154,33 +241,67 @@ mod tests {
 
     #[test]
     fn test_generate_words() {
-        let keys = vec!['q', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm'];
-        let result = generate_words(&keys, 20);
-        assert!(result.contains("la") || result.contains("le") || result.contains("de"));
+        let keys = vec!['t', 'h', 'e', 'a', 'n', 'd', 's', 'o', 'r', 'i'];
+        let result = generate_words(&keys, 20, Language::English);
+        assert!(!result.is_empty());
         assert!(result.len() <= 20);
     }
 
+    #[test]
+    fn test_generate_words_is_deterministic() {
+        let keys = vec!['t', 'h', 'e', 'a', 'n', 'd', 's', 'o', 'r', 'i'];
+        let first = generate_words(&keys, 30, Language::English);
+        let second = generate_words(&keys, 30, Language::English);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_generate_words_falls_back_when_keys_too_restricted() {
+        let keys = vec!['q', 'x'];
+        let result = generate_words(&keys, 20, Language::English);
+        assert!(!result.is_empty());
+        assert!(result.chars().all(|c| c == 'q' || c == 'x' || c == ' '));
+    }
+
     #[test]
     fn test_lesson_content_generator() {
-        let lessons = Lesson::home_row_lessons();
+        let lessons = Lesson::key_pair_lessons();
 
-        // Test niveau 1 (f, j only)
+        // Lesson 1: f-d j-k
         let content1 = lessons[0].generate(20);
         assert!(!content1.is_empty());
         assert!(content1.contains('f'));
         assert!(content1.contains('j'));
 
-        // Test niveau 2 (f, j, d, k - progressive)
+        // Lesson 2: f-g j-h
         let content2 = lessons[1].generate(30);
         assert!(!content2.is_empty());
         assert!(content2.len() <= 30);
 
-        // Test niveau 5 (all keys)
+        // Lesson 5: f-r j-u
         let content5 = lessons[4].generate(30);
         assert!(!content5.is_empty());
 
-        // Test niveau 6 (words)
+        // Lesson 6: f-t j-y
         let content6 = lessons[5].generate(25);
         assert!(!content6.is_empty());
     }
+
+    #[test]
+    fn test_key_pair_group_lesson_draws_from_its_member_lessons_keys() {
+        let lessons = Lesson::key_pair_group_lessons(false);
+        let content = lessons[0].generate(30);
+        assert!(!content.is_empty());
+    }
+
+    #[test]
+    fn test_key_pair_group_with_shift_produces_uppercase_keys() {
+        let lessons = Lesson::key_pair_group_lessons(true);
+        let content = lessons[0].generate(200);
+        assert!(!content.is_empty());
+        assert!(
+            content.chars().any(|c| c.is_uppercase()),
+            "shift variant should mix in uppercase keys"
+        );
+    }
 }
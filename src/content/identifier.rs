@@ -0,0 +1,124 @@
+/// Case-style identifier practice for programmers: the shift timing in
+/// `PascalCase`, the underscore reaches in `MAX_BUFFER_SIZE`, and the hyphens
+/// in `kebab-case` each force a distinct character transition real code
+/// typing drills on, which `code_symbol_lessons` alone doesn't cover.
+
+/// Naming convention a base identifier's tokens are rendered into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaseStyle {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl CaseStyle {
+    /// Render space-separated `tokens` (e.g. "get http response") into this
+    /// naming convention
+    pub fn render(&self, tokens: &str) -> String {
+        let words: Vec<&str> = tokens.split(' ').filter(|w| !w.is_empty()).collect();
+
+        match self {
+            CaseStyle::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            CaseStyle::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            CaseStyle::Snake => words
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            CaseStyle::ScreamingSnake => words
+                .iter()
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            CaseStyle::Kebab => words
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("-"),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+/// Realistic identifier corpus: each entry is a base concept as
+/// space-separated tokens, rendered into whichever `CaseStyle` a lesson drills
+pub const IDENTIFIER_CORPUS: &[&str] = &[
+    "user name",
+    "is valid",
+    "file path",
+    "session token",
+    "error message",
+    "default locale",
+    "get http response",
+    "max retry count",
+    "total item count",
+    "api base url",
+    "current page index",
+    "request timeout ms",
+    "max buffer size",
+    "auth header name",
+    "should auto save",
+    "response status code",
+    "is authenticated",
+    "connection pool size",
+    "retry backoff ms",
+    "cache ttl seconds",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case_lowercases_first_token() {
+        assert_eq!(CaseStyle::Camel.render("get http response"), "getHttpResponse");
+    }
+
+    #[test]
+    fn test_pascal_case_capitalizes_every_token() {
+        assert_eq!(CaseStyle::Pascal.render("get http response"), "GetHttpResponse");
+    }
+
+    #[test]
+    fn test_snake_case_joins_with_underscore() {
+        assert_eq!(CaseStyle::Snake.render("max retry count"), "max_retry_count");
+    }
+
+    #[test]
+    fn test_screaming_snake_uppercases_and_joins() {
+        assert_eq!(
+            CaseStyle::ScreamingSnake.render("max retry count"),
+            "MAX_RETRY_COUNT"
+        );
+    }
+
+    #[test]
+    fn test_kebab_case_joins_with_hyphen() {
+        assert_eq!(CaseStyle::Kebab.render("user name"), "user-name");
+    }
+
+    #[test]
+    fn test_single_token_identifier() {
+        assert_eq!(CaseStyle::Pascal.render("user"), "User");
+        assert_eq!(CaseStyle::Camel.render("user"), "user");
+    }
+}
@@ -0,0 +1,93 @@
+/// Content generator for identifier-casing practice lessons
+use super::identifier::{CaseStyle, IDENTIFIER_CORPUS};
+
+pub struct IdentifierGenerator {
+    style: CaseStyle,
+}
+
+impl IdentifierGenerator {
+    pub fn new(style: CaseStyle) -> Self {
+        Self { style }
+    }
+
+    /// Generate identifier-casing practice content for a given level.
+    /// Level 1: 2-token identifiers, level 2: up to 3 tokens, level 3+: the
+    /// full corpus.
+    pub fn generate(&self, level: usize, length: usize) -> String {
+        let max_tokens = match level {
+            1 => 2,
+            2 => 3,
+            _ => usize::MAX,
+        };
+
+        let candidates: Vec<&str> = IDENTIFIER_CORPUS
+            .iter()
+            .copied()
+            .filter(|tokens| tokens.split(' ').count() <= max_tokens)
+            .collect();
+
+        if candidates.is_empty() {
+            return String::new();
+        }
+
+        let mut result = String::new();
+        let mut idx = 0;
+
+        while result.chars().count() < length {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(&self.style.render(candidates[idx % candidates.len()]));
+            idx += 1;
+        }
+
+        result.chars().take(length).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_one_only_uses_two_token_identifiers() {
+        let gen = IdentifierGenerator::new(CaseStyle::Snake);
+        let content = gen.generate(1, 200);
+
+        for word in content.split_whitespace() {
+            assert!(
+                word.split('_').count() <= 2,
+                "'{}' has more than 2 tokens at level 1",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn test_level_three_allows_longer_identifiers() {
+        let gen = IdentifierGenerator::new(CaseStyle::ScreamingSnake);
+        let content = gen.generate(3, 200);
+
+        assert!(
+            content.split_whitespace().any(|w| w.split('_').count() > 2),
+            "level 3 should include identifiers longer than 2 tokens"
+        );
+    }
+
+    #[test]
+    fn test_respects_length_constraint() {
+        let gen = IdentifierGenerator::new(CaseStyle::Kebab);
+        let content = gen.generate(2, 40);
+
+        assert!(content.chars().count() <= 40);
+    }
+
+    #[test]
+    fn test_generate_uses_requested_case_style() {
+        let gen = IdentifierGenerator::new(CaseStyle::Pascal);
+        let content = gen.generate(1, 60);
+
+        assert!(!content.contains('_'));
+        assert!(!content.contains('-'));
+    }
+}
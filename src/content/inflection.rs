@@ -0,0 +1,212 @@
+/// Rule-based morphology: turns a dictionary base form into its plural (and,
+/// for French, gender/number) inflection, so `CommonWords` drills aren't
+/// limited to static singular word lists.
+use super::bigram::Language;
+
+/// A suffix rule: if a word ends with `match_suffix`, drop the last `drop`
+/// characters and append `append`. Rule lists are scanned longest-match-first.
+struct SuffixRule {
+    match_suffix: &'static str,
+    drop: usize,
+    append: &'static str,
+}
+
+const ENGLISH_RULES: &[SuffixRule] = &[
+    SuffixRule {
+        match_suffix: "ch",
+        drop: 0,
+        append: "es",
+    },
+    SuffixRule {
+        match_suffix: "sh",
+        drop: 0,
+        append: "es",
+    },
+    SuffixRule {
+        match_suffix: "y",
+        drop: 1,
+        append: "ies",
+    },
+    SuffixRule {
+        match_suffix: "s",
+        drop: 0,
+        append: "es",
+    },
+    SuffixRule {
+        match_suffix: "x",
+        drop: 0,
+        append: "es",
+    },
+    SuffixRule {
+        match_suffix: "z",
+        drop: 0,
+        append: "es",
+    },
+];
+
+const ENGLISH_IRREGULARS: &[(&str, &str)] = &[
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("child", "children"),
+];
+
+const ENGLISH_INVARIANTS: &[&str] = &["sheep", "fish", "deer"];
+
+const FRENCH_RULES: &[SuffixRule] = &[
+    SuffixRule {
+        match_suffix: "eau",
+        drop: 0,
+        append: "x",
+    },
+    SuffixRule {
+        match_suffix: "eu",
+        drop: 0,
+        append: "x",
+    },
+    SuffixRule {
+        match_suffix: "al",
+        drop: 2,
+        append: "aux",
+    },
+];
+
+const FRENCH_IRREGULARS: &[(&str, &str)] = &[
+    ("oeil", "yeux"),
+    ("ciel", "cieux"),
+    ("monsieur", "messieurs"),
+];
+
+const FRENCH_INVARIANTS: &[&str] = &["souris", "tapis", "bras"];
+
+/// Inflect `word` (assumed a lowercase dictionary base form) into its plural
+/// form for `language`. Irregulars and invariants are checked first so they
+/// never fall through to the suffix rules.
+pub fn inflect(word: &str, language: Language) -> String {
+    let lower = word.to_lowercase();
+
+    let (invariants, irregulars, rules): (&[&str], &[(&str, &str)], &[SuffixRule]) = match language
+    {
+        Language::English => (ENGLISH_INVARIANTS, ENGLISH_IRREGULARS, ENGLISH_RULES),
+        Language::French => (FRENCH_INVARIANTS, FRENCH_IRREGULARS, FRENCH_RULES),
+    };
+
+    if invariants.contains(&lower.as_str()) {
+        return lower;
+    }
+
+    if let Some((_, plural)) = irregulars.iter().find(|(base, _)| *base == lower) {
+        return plural.to_string();
+    }
+
+    apply_suffix_rules(&lower, rules)
+}
+
+/// Pluralize an English word or phrase. Handles compound phrases: if `word`
+/// contains a space (e.g. "pair of aces"), only the head is inflected and the
+/// trailing segment is carried through unchanged ("pairs of aces"), rather
+/// than running the whole phrase through the suffix rules.
+pub fn pluralize(word: &str) -> String {
+    match word.split_once(' ') {
+        Some((head, rest)) if !rest.is_empty() => {
+            format!("{} {}", inflect(head, Language::English), rest)
+        }
+        _ => inflect(word, Language::English),
+    }
+}
+
+fn apply_suffix_rules(word: &str, rules: &[SuffixRule]) -> String {
+    for rule in rules {
+        if word.ends_with(rule.match_suffix) {
+            let char_count = word.chars().count();
+            let keep = char_count.saturating_sub(rule.drop);
+            let truncated: String = word.chars().take(keep).collect();
+            return format!("{}{}", truncated, rule.append);
+        }
+    }
+
+    format!("{}s", word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_default_rule_appends_s() {
+        assert_eq!(inflect("cat", Language::English), "cats");
+    }
+
+    #[test]
+    fn test_english_y_rule_drops_and_appends_ies() {
+        assert_eq!(inflect("city", Language::English), "cities");
+    }
+
+    #[test]
+    fn test_english_sibilant_rules_append_es() {
+        assert_eq!(inflect("bus", Language::English), "buses");
+        assert_eq!(inflect("box", Language::English), "boxes");
+        assert_eq!(inflect("buzz", Language::English), "buzzes");
+        assert_eq!(inflect("church", Language::English), "churches");
+        assert_eq!(inflect("dish", Language::English), "dishes");
+    }
+
+    #[test]
+    fn test_english_irregulars_skip_suffix_rules() {
+        assert_eq!(inflect("foot", Language::English), "feet");
+        assert_eq!(inflect("mouse", Language::English), "mice");
+        assert_eq!(inflect("child", Language::English), "children");
+    }
+
+    #[test]
+    fn test_english_invariants_are_unchanged() {
+        assert_eq!(inflect("sheep", Language::English), "sheep");
+        assert_eq!(inflect("fish", Language::English), "fish");
+    }
+
+    #[test]
+    fn test_english_irregulars_cover_tooth_man_louse() {
+        assert_eq!(inflect("tooth", Language::English), "teeth");
+        assert_eq!(inflect("man", Language::English), "men");
+        assert_eq!(inflect("louse", Language::English), "lice");
+    }
+
+    #[test]
+    fn test_pluralize_single_word_matches_inflect() {
+        assert_eq!(pluralize("cat"), "cats");
+        assert_eq!(pluralize("foot"), "feet");
+    }
+
+    #[test]
+    fn test_pluralize_compound_phrase_only_inflects_head() {
+        assert_eq!(pluralize("pair of aces"), "pairs of aces");
+    }
+
+    #[test]
+    fn test_french_al_rule_drops_and_appends_aux() {
+        assert_eq!(inflect("cheval", Language::French), "chevaux");
+    }
+
+    #[test]
+    fn test_french_eau_and_eu_rules_append_x() {
+        assert_eq!(inflect("bateau", Language::French), "bateaux");
+        assert_eq!(inflect("cheveu", Language::French), "cheveux");
+    }
+
+    #[test]
+    fn test_french_default_rule_appends_s() {
+        assert_eq!(inflect("chien", Language::French), "chiens");
+    }
+
+    #[test]
+    fn test_french_irregulars_skip_suffix_rules() {
+        assert_eq!(inflect("oeil", Language::French), "yeux");
+    }
+
+    #[test]
+    fn test_french_invariants_are_unchanged() {
+        assert_eq!(inflect("souris", Language::French), "souris");
+    }
+}
@@ -0,0 +1,94 @@
+/// Content generator for morphology/inflection drills: mixes a word's base
+/// (singular) form with its inflected (plural) form so typists train the
+/// inflected endings too, not just the dictionary forms.
+use super::bigram::Language;
+use super::common_word::{english_words, french_words, Word};
+use super::inflection::inflect;
+use rand::Rng;
+
+pub struct InflectionGenerator {
+    language: Language,
+    words: Vec<Word>,
+}
+
+impl InflectionGenerator {
+    pub fn new(language: Language) -> Self {
+        let words = match language {
+            Language::French => french_words(),
+            Language::English => english_words(),
+        };
+
+        Self { language, words }
+    }
+
+    /// Generate content mixing base and inflected forms. Level scales how
+    /// large a word pool the drill draws from.
+    pub fn generate(&self, level: usize, length: usize) -> String {
+        let count = match level {
+            1 => 50,
+            2 => 150,
+            _ => 500,
+        };
+
+        let pool: Vec<&Word> = self.words.iter().take(count.min(self.words.len())).collect();
+        if pool.is_empty() {
+            return String::new();
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut result = String::new();
+        let mut idx = 0;
+
+        while result.chars().count() < length {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+
+            let word = pool[idx % pool.len()];
+            if rng.gen_bool(0.5) {
+                result.push_str(&inflect(&word.text, self.language));
+            } else {
+                result.push_str(&word.text);
+            }
+
+            idx += 1;
+        }
+
+        result.chars().take(length).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_mixes_base_and_inflected_forms() {
+        let gen = InflectionGenerator::new(Language::English);
+        let content = gen.generate(1, 400);
+        let base_words = english_words();
+        let pool: std::collections::HashSet<&str> =
+            base_words.iter().take(50).map(|w| w.text.as_str()).collect();
+
+        let has_inflected_form = content
+            .split_whitespace()
+            .any(|w| !pool.contains(w));
+
+        assert!(has_inflected_form, "drill should include at least one inflected form");
+    }
+
+    #[test]
+    fn test_respects_length_constraint() {
+        let gen = InflectionGenerator::new(Language::French);
+        let content = gen.generate(2, 60);
+        assert!(content.chars().count() <= 60);
+    }
+
+    #[test]
+    fn test_level_scales_pool_size() {
+        let gen = InflectionGenerator::new(Language::English);
+        let content = gen.generate(1, 5000);
+        let words: std::collections::HashSet<&str> = content.split_whitespace().collect();
+        assert!(words.len() <= 100, "level 1 should only draw from a small pool");
+    }
+}
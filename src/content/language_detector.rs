@@ -0,0 +1,159 @@
+/// Automatic language detection for imported custom practice text, so a user
+/// pasting arbitrary text gets the right `Language` (and its word/bigram
+/// tables) without a manual French/English pick.
+use super::bigram::Language;
+use super::common_word::{english_words, french_words};
+use std::collections::HashMap;
+
+/// Below this many letters, n-gram scores are unreliable; fall back to the
+/// diacritic/stopword heuristic instead.
+const MIN_LETTERS_FOR_NGRAM: usize = 20;
+
+const FRENCH_STOPWORDS: &[&str] = &[
+    "le", "la", "les", "de", "des", "et", "un", "une", "est", "pour", "avec", "dans", "que", "qui",
+];
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "the", "and", "is", "of", "to", "in", "for", "with", "that", "this", "are",
+];
+
+/// Character n-gram (bigram + trigram) frequency profile for one language,
+/// built once from its word list since the crate carries no raw text corpus
+/// to mine n-grams from directly.
+struct NgramProfile {
+    bigrams: HashMap<String, u32>,
+    trigrams: HashMap<String, u32>,
+    bigram_total: u32,
+    trigram_total: u32,
+}
+
+impl NgramProfile {
+    fn build(words: &[&str]) -> Self {
+        let mut bigrams: HashMap<String, u32> = HashMap::new();
+        let mut trigrams: HashMap<String, u32> = HashMap::new();
+
+        for word in words {
+            let lower = word.to_lowercase();
+            let chars: Vec<char> = lower.chars().collect();
+
+            for window in chars.windows(2) {
+                *bigrams.entry(window.iter().collect()).or_insert(0) += 1;
+            }
+            for window in chars.windows(3) {
+                *trigrams.entry(window.iter().collect()).or_insert(0) += 1;
+            }
+        }
+
+        let bigram_total = bigrams.values().sum();
+        let trigram_total = trigrams.values().sum();
+
+        Self {
+            bigrams,
+            trigrams,
+            bigram_total,
+            trigram_total,
+        }
+    }
+
+    /// Add-one smoothed log-probability of `ngram` under this profile
+    fn log_probability(table: &HashMap<String, u32>, total: u32, ngram: &str) -> f64 {
+        let count = table.get(ngram).copied().unwrap_or(0);
+        let vocab_size = table.len() as f64;
+        ((count as f64 + 1.0) / (total as f64 + vocab_size)).ln()
+    }
+
+    fn score(&self, bigrams: &[String], trigrams: &[String]) -> f64 {
+        let bigram_score: f64 = bigrams
+            .iter()
+            .map(|g| Self::log_probability(&self.bigrams, self.bigram_total, g))
+            .sum();
+        let trigram_score: f64 = trigrams
+            .iter()
+            .map(|g| Self::log_probability(&self.trigrams, self.trigram_total, g))
+            .sum();
+
+        bigram_score + trigram_score
+    }
+}
+
+/// Detect the most likely `Language` of freeform user text, for auto-picking
+/// the right `CommonWordGenerator` when custom practice text is imported.
+pub fn detect_language(text: &str) -> Language {
+    let letters: String = text.chars().filter(|c| c.is_alphabetic()).collect();
+
+    if letters.chars().count() < MIN_LETTERS_FOR_NGRAM {
+        return detect_by_heuristic(text);
+    }
+
+    let lower = letters.to_lowercase();
+    let chars: Vec<char> = lower.chars().collect();
+    let bigrams: Vec<String> = chars.windows(2).map(|w| w.iter().collect()).collect();
+    let trigrams: Vec<String> = chars.windows(3).map(|w| w.iter().collect()).collect();
+
+    let english_words = english_words();
+    let french_words = french_words();
+    let english_refs: Vec<&str> = english_words.iter().map(|w| w.text.as_str()).collect();
+    let french_refs: Vec<&str> = french_words.iter().map(|w| w.text.as_str()).collect();
+
+    let english_profile = NgramProfile::build(&english_refs);
+    let french_profile = NgramProfile::build(&french_refs);
+
+    let english_score = english_profile.score(&bigrams, &trigrams);
+    let french_score = french_profile.score(&bigrams, &trigrams);
+
+    if french_score >= english_score {
+        Language::French
+    } else {
+        Language::English
+    }
+}
+
+/// For short samples, n-gram scores are too noisy to trust: fall back to
+/// looking for French diacritics or a handful of common stopwords.
+fn detect_by_heuristic(text: &str) -> Language {
+    let lower = text.to_lowercase();
+
+    if lower.contains(['é', 'è', 'à', 'ç', 'ê', 'ô', 'û', 'î', 'â', 'ù', 'ë', 'ï', 'ü']) {
+        return Language::French;
+    }
+
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let french_hits = words.iter().filter(|w| FRENCH_STOPWORDS.contains(w)).count();
+    let english_hits = words
+        .iter()
+        .filter(|w| ENGLISH_STOPWORDS.contains(w))
+        .count();
+
+    if french_hits > english_hits {
+        Language::French
+    } else {
+        Language::English
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_text_falls_back_to_diacritic_heuristic() {
+        assert_eq!(detect_language("café"), Language::French);
+    }
+
+    #[test]
+    fn test_short_text_falls_back_to_stopword_heuristic() {
+        assert_eq!(detect_language("the cat"), Language::English);
+        assert_eq!(detect_language("le chat"), Language::French);
+    }
+
+    #[test]
+    fn test_long_english_text_detected_via_ngrams() {
+        let text = "The quick brown fox jumps over the lazy dog while thinking about nothing";
+        assert_eq!(detect_language(text), Language::English);
+    }
+
+    #[test]
+    fn test_long_french_text_detected_via_ngrams() {
+        let text = "Le renard brun rapide saute par-dessus le chien paresseux sans rien penser";
+        assert_eq!(detect_language(text), Language::French);
+    }
+}
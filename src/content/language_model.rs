@@ -0,0 +1,143 @@
+/// Statistical language auto-detection built on the crate's existing
+/// bigram/trigram frequency tables (`BigramType`, `Language`, used throughout
+/// `LessonType::Bigram`/`Trigram`), so importing custom text can auto-select
+/// the right lesson language instead of forcing a manual choice.
+use super::bigram::{english_bigrams, french_bigrams, Bigram, Language};
+use super::trigram::{english_trigrams, french_trigrams, Trigram};
+use std::collections::HashMap;
+
+/// Laplace smoothing so an unseen n-gram doesn't zero out the log-probability
+const ALPHA: f64 = 1e-6;
+
+/// Below this many 2-/3-grams, the sample is too short to trust
+const MIN_GRAMS: usize = 10;
+
+/// Minimum gap between the top two candidates' mean log-likelihoods to
+/// report a confident detection rather than `None`
+const MIN_CONFIDENCE_GAP: f64 = 0.15;
+
+/// Relative-frequency n-gram model for one language, built from its curated
+/// bigram/trigram tables rather than a raw corpus
+struct LanguageModel {
+    bigrams: HashMap<String, f64>,
+    trigrams: HashMap<String, f64>,
+}
+
+impl LanguageModel {
+    fn build(bigrams: &[Bigram], trigrams: &[Trigram]) -> Self {
+        let bigram_total: f32 = bigrams.iter().map(|b| b.frequency).sum();
+        let trigram_total: f32 = trigrams.iter().map(|t| t.frequency).sum();
+
+        Self {
+            bigrams: bigrams
+                .iter()
+                .map(|b| (b.pattern.clone(), (b.frequency / bigram_total) as f64))
+                .collect(),
+            trigrams: trigrams
+                .iter()
+                .map(|t| (t.pattern.clone(), (t.frequency / trigram_total) as f64))
+                .collect(),
+        }
+    }
+
+    /// Mean log-probability of `bigrams`/`trigrams` under this model, with
+    /// `ALPHA` Laplace smoothing for grams the model never saw
+    fn mean_log_likelihood(&self, bigrams: &[String], trigrams: &[String]) -> f64 {
+        let log_probs: Vec<f64> = bigrams
+            .iter()
+            .map(|g| self.bigrams.get(g).copied().unwrap_or(0.0))
+            .chain(trigrams.iter().map(|g| self.trigrams.get(g).copied().unwrap_or(0.0)))
+            .map(|freq| (freq + ALPHA).ln())
+            .collect();
+
+        if log_probs.is_empty() {
+            return f64::NEG_INFINITY;
+        }
+
+        log_probs.iter().sum::<f64>() / log_probs.len() as f64
+    }
+}
+
+/// Extract overlapping lowercase 2-grams and 3-grams from `text`, ignoring
+/// non-alphabetic characters
+fn extract_grams(text: &str) -> (Vec<String>, Vec<String>) {
+    let lower = text.to_lowercase();
+    let chars: Vec<char> = lower.chars().filter(|c| c.is_alphabetic()).collect();
+
+    let bigrams = chars.windows(2).map(|w| w.iter().collect()).collect();
+    let trigrams = chars.windows(3).map(|w| w.iter().collect()).collect();
+
+    (bigrams, trigrams)
+}
+
+/// Detect `text`'s language via n-gram Naive-Bayes over the crate's curated
+/// bigram/trigram frequency tables. Returns `None` when the sample is too
+/// short (fewer than `MIN_GRAMS` grams) or the top two candidates are too
+/// close to call; the `f64` is the confidence gap either way.
+///
+/// Distinct from `language_detector::detect_language` (which always commits
+/// to a language): this is for a caller that wants to fall back to a
+/// manual choice rather than guess when the sample is too ambiguous.
+/// Public API: not yet wired to a call site.
+#[allow(dead_code)]
+pub fn detect_language(text: &str) -> (Option<Language>, f64) {
+    let (bigrams, trigrams) = extract_grams(text);
+
+    if bigrams.len() + trigrams.len() < MIN_GRAMS {
+        return (None, 0.0);
+    }
+
+    let english_model = LanguageModel::build(&english_bigrams(), &english_trigrams());
+    let french_model = LanguageModel::build(&french_bigrams(), &french_trigrams());
+
+    let english_score = english_model.mean_log_likelihood(&bigrams, &trigrams);
+    let french_score = french_model.mean_log_likelihood(&bigrams, &trigrams);
+
+    let gap = (english_score - french_score).abs();
+    if gap < MIN_CONFIDENCE_GAP {
+        return (None, gap);
+    }
+
+    let language = if english_score >= french_score {
+        Language::English
+    } else {
+        Language::French
+    };
+
+    (Some(language), gap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_sample_returns_none() {
+        let (language, confidence) = detect_language("hi");
+        assert_eq!(language, None);
+        assert_eq!(confidence, 0.0);
+    }
+
+    #[test]
+    fn test_long_english_text_detected_with_confidence() {
+        let text = "the quick brown fox jumps over the lazy dog while thinking about nothing else";
+        let (language, confidence) = detect_language(text);
+        assert_eq!(language, Some(Language::English));
+        assert!(confidence > 0.0);
+    }
+
+    #[test]
+    fn test_long_french_text_detected_with_confidence() {
+        let text = "le renard brun rapide saute par dessus le chien paresseux sans rien penser";
+        let (language, confidence) = detect_language(text);
+        assert_eq!(language, Some(Language::French));
+        assert!(confidence > 0.0);
+    }
+
+    #[test]
+    fn test_ambiguous_short_gram_count_returns_none() {
+        // Fewer than MIN_GRAMS extracted grams even though the word count looks plausible
+        let (language, _) = detect_language("at it is");
+        assert_eq!(language, None);
+    }
+}
@@ -1,5 +1,9 @@
+use serde::Deserialize;
+
 use super::bigram::{BigramType, Language};
 use super::code_symbols::ProgrammingLanguage;
+use super::identifier::CaseStyle;
+use crate::keyboard::KeyboardLayout;
 
 /// Definition of a single key pair lesson
 #[derive(Debug, Clone)]
@@ -152,182 +156,12 @@ pub const KEY_PAIR_GROUPS: [KeyPairGroupDef; 4] = [
     },
 ];
 
-/// Map between normal and shifted characters for AZERTY
-#[derive(Debug, Clone)]
-pub struct ShiftedCharMap {
-    pub normal: char,
-    pub shifted: char,
-}
-
-/// AZERTY keyboard shift mappings
-pub const AZERTY_SHIFT_MAP: &[ShiftedCharMap] = &[
-    // Letters become uppercase
-    ShiftedCharMap {
-        normal: 'a',
-        shifted: 'A',
-    },
-    ShiftedCharMap {
-        normal: 'b',
-        shifted: 'B',
-    },
-    ShiftedCharMap {
-        normal: 'c',
-        shifted: 'C',
-    },
-    ShiftedCharMap {
-        normal: 'd',
-        shifted: 'D',
-    },
-    ShiftedCharMap {
-        normal: 'e',
-        shifted: 'E',
-    },
-    ShiftedCharMap {
-        normal: 'f',
-        shifted: 'F',
-    },
-    ShiftedCharMap {
-        normal: 'g',
-        shifted: 'G',
-    },
-    ShiftedCharMap {
-        normal: 'h',
-        shifted: 'H',
-    },
-    ShiftedCharMap {
-        normal: 'i',
-        shifted: 'I',
-    },
-    ShiftedCharMap {
-        normal: 'j',
-        shifted: 'J',
-    },
-    ShiftedCharMap {
-        normal: 'k',
-        shifted: 'K',
-    },
-    ShiftedCharMap {
-        normal: 'l',
-        shifted: 'L',
-    },
-    ShiftedCharMap {
-        normal: 'm',
-        shifted: 'M',
-    },
-    ShiftedCharMap {
-        normal: 'n',
-        shifted: 'N',
-    },
-    ShiftedCharMap {
-        normal: 'o',
-        shifted: 'O',
-    },
-    ShiftedCharMap {
-        normal: 'p',
-        shifted: 'P',
-    },
-    ShiftedCharMap {
-        normal: 'q',
-        shifted: 'Q',
-    },
-    ShiftedCharMap {
-        normal: 'r',
-        shifted: 'R',
-    },
-    ShiftedCharMap {
-        normal: 's',
-        shifted: 'S',
-    },
-    ShiftedCharMap {
-        normal: 't',
-        shifted: 'T',
-    },
-    ShiftedCharMap {
-        normal: 'u',
-        shifted: 'U',
-    },
-    ShiftedCharMap {
-        normal: 'v',
-        shifted: 'V',
-    },
-    ShiftedCharMap {
-        normal: 'w',
-        shifted: 'W',
-    },
-    ShiftedCharMap {
-        normal: 'x',
-        shifted: 'X',
-    },
-    ShiftedCharMap {
-        normal: 'y',
-        shifted: 'Y',
-    },
-    ShiftedCharMap {
-        normal: 'z',
-        shifted: 'Z',
-    },
-    // AZERTY number row (symbols by default, numbers when shifted)
-    ShiftedCharMap {
-        normal: '&',
-        shifted: '1',
-    },
-    ShiftedCharMap {
-        normal: 'é',
-        shifted: '2',
-    },
-    ShiftedCharMap {
-        normal: '"',
-        shifted: '3',
-    },
-    ShiftedCharMap {
-        normal: '\'',
-        shifted: '4',
-    },
-    ShiftedCharMap {
-        normal: '(',
-        shifted: '5',
-    },
-    ShiftedCharMap {
-        normal: '-',
-        shifted: '6',
-    },
-    ShiftedCharMap {
-        normal: 'è',
-        shifted: '7',
-    },
-    ShiftedCharMap {
-        normal: '_',
-        shifted: '8',
-    },
-    ShiftedCharMap {
-        normal: 'ç',
-        shifted: '9',
-    },
-    ShiftedCharMap {
-        normal: 'à',
-        shifted: '0',
-    },
-    // Other symbols
-    ShiftedCharMap {
-        normal: ';',
-        shifted: '.',
-    },
-    ShiftedCharMap {
-        normal: ':',
-        shifted: '/',
-    },
-    ShiftedCharMap {
-        normal: '!',
-        shifted: '§',
-    },
-];
-
-/// Get shifted variant of a character
-pub fn get_shifted_char(c: char) -> Option<char> {
-    AZERTY_SHIFT_MAP
-        .iter()
-        .find(|map| map.normal == c)
-        .map(|map| map.shifted)
+/// Get the shifted variant of a character on `layout`, e.g. `'a'` -> `'A'`
+/// or AZERTY's `'&'` -> `'1'`. Delegates to the active `KeyboardLayout` so
+/// key-pair and finger-pair lessons honor whichever layout the user picked,
+/// instead of assuming a single hardcoded arrangement.
+pub fn get_shifted_char(layout: &dyn KeyboardLayout, c: char) -> Option<char> {
+    layout.find_key(c).and_then(|key| key.shift_variant)
 }
 
 /// Finger pair combinations for bilateral training (left + right)
@@ -368,6 +202,13 @@ pub enum LessonType {
         language: ProgrammingLanguage,
         level: usize,
     },
+    /// Syntactically realistic code drills parsed from a tree-sitter
+    /// grammar, as opposed to `CodeSymbols`'s flat symbol sequences. Level
+    /// controls construct complexity (see `code_corpus::ComplexityTier`).
+    CodeSnippet {
+        language: ProgrammingLanguage,
+        level: usize,
+    },
     Adaptive,
     /// Finger-based training by finger pair, level, and shift variant
     FingerPair {
@@ -375,6 +216,48 @@ pub enum LessonType {
         level: u8,        // 1=Home Row, 2=Extended, 3=All Keys
         with_shift: bool, // false=base chars, true=mixed case+symbols
     },
+    /// Simultaneous-key ("chord") training for stenography and QMK-style combos
+    Chord {
+        level: u8, // 1=home row pairs, 2=home-row mods, 3=bracket/symbol chords
+    },
+    /// Naming-convention drills (camelCase, PascalCase, snake_case, etc.)
+    Identifier {
+        language: ProgrammingLanguage,
+        style: CaseStyle,
+        level: usize,
+    },
+    /// Morphology drills mixing a word's base form with its plural (and, for
+    /// French, gender/number) inflection
+    Inflection {
+        language: Language,
+        level: usize,
+    },
+    /// A user-provided markdown lesson (see `content::custom`), already
+    /// normalized to plain practice text at load time
+    Custom {
+        content: String,
+    },
+}
+
+/// Difficulty level declared in a custom lesson's YAML front matter, so the
+/// lesson menu can filter/sort custom content by how hard it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// Rich metadata parsed from a custom lesson's YAML front matter (beyond the
+/// `title`/`description` already folded into `Lesson`'s own fields), kept
+/// around so the lesson menu can filter and sort custom content by it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomMetadata {
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub repeat: bool,
 }
 
 /// Représente une leçon de typing
@@ -390,6 +273,11 @@ pub struct Lesson {
     /// Public API: Lesson metadata for future UI tooltips and analytics export
     #[allow(dead_code)]
     pub keys: Vec<char>,
+    /// Front-matter metadata for custom lessons (see `CustomLessonLoader`);
+    /// `None` for every built-in lesson type.
+    /// Public API: consumed by a future lesson-menu filter/sort UI
+    #[allow(dead_code)]
+    pub custom_metadata: Option<CustomMetadata>,
 }
 
 impl Lesson {
@@ -404,9 +292,16 @@ impl Lesson {
             title,
             description,
             keys,
+            custom_metadata: None,
         }
     }
 
+    /// Attach front-matter metadata parsed for a custom lesson
+    pub fn with_custom_metadata(mut self, metadata: CustomMetadata) -> Self {
+        self.custom_metadata = Some(metadata);
+        self
+    }
+
     /// Create code symbol lessons for a programming language
     pub fn code_symbol_lessons(language: ProgrammingLanguage) -> Vec<Lesson> {
         let lang_name = match language {
@@ -455,21 +350,108 @@ impl Lesson {
         ]
     }
 
+    /// Create code-corpus lessons for a programming language: levels 1-2
+    /// drill expressions, 3-4 whole functions, 5-6 full multi-statement
+    /// blocks (see `code_corpus::ComplexityTier::for_level`)
+    pub fn code_snippet_lessons(language: ProgrammingLanguage) -> Vec<Lesson> {
+        let lang_name = match language {
+            ProgrammingLanguage::TypeScript => "TypeScript",
+            ProgrammingLanguage::Rust => "Rust",
+            ProgrammingLanguage::Python => "Python",
+        };
+
+        let descriptions = [
+            "Expressions",
+            "Expressions",
+            "Functions",
+            "Functions",
+            "Full blocks",
+            "Full blocks",
+        ];
+
+        (1..=6)
+            .zip(descriptions)
+            .map(|(level, description)| {
+                Lesson::new(
+                    LessonType::CodeSnippet { language, level },
+                    format!("{} Code - Level {}", lang_name, level),
+                    description.to_string(),
+                    vec![],
+                )
+            })
+            .collect()
+    }
+
+    /// Create identifier-casing lessons for a programming language: one
+    /// length progression (levels 1-3) per naming convention
+    pub fn identifier_lessons(language: ProgrammingLanguage) -> Vec<Lesson> {
+        let lang_name = match language {
+            ProgrammingLanguage::TypeScript => "TypeScript",
+            ProgrammingLanguage::Rust => "Rust",
+            ProgrammingLanguage::Python => "Python",
+        };
+
+        let styles = [
+            (CaseStyle::Camel, "camelCase"),
+            (CaseStyle::Pascal, "PascalCase"),
+            (CaseStyle::Snake, "snake_case"),
+            (CaseStyle::ScreamingSnake, "SCREAMING_SNAKE"),
+            (CaseStyle::Kebab, "kebab-case"),
+        ];
+
+        let mut lessons = Vec::new();
+        for (style, style_name) in styles {
+            lessons.push(Lesson::new(
+                LessonType::Identifier {
+                    language,
+                    style,
+                    level: 1,
+                },
+                format!("{} {} - Level 1", lang_name, style_name),
+                "Short identifiers (2 tokens)".to_string(),
+                vec![],
+            ));
+            lessons.push(Lesson::new(
+                LessonType::Identifier {
+                    language,
+                    style,
+                    level: 2,
+                },
+                format!("{} {} - Level 2", lang_name, style_name),
+                "Medium identifiers (up to 3 tokens)".to_string(),
+                vec![],
+            ));
+            lessons.push(Lesson::new(
+                LessonType::Identifier {
+                    language,
+                    style,
+                    level: 3,
+                },
+                format!("{} {} - Level 3", lang_name, style_name),
+                "Full identifier corpus".to_string(),
+                vec![],
+            ));
+        }
+
+        lessons
+    }
+
     /// Create bigram lessons for a specific language or code
     pub fn bigram_lessons(bigram_type: BigramType, language: Option<Language>) -> Vec<Lesson> {
-        let lang_name = match bigram_type {
+        let lang_name = match &bigram_type {
             BigramType::Natural => match language {
                 Some(Language::French) => "French",
                 Some(Language::English) => "English",
                 None => "Natural",
             },
             BigramType::Code => "Code",
+            BigramType::Custom(_) => "Custom",
         };
 
         vec![
             Lesson::new(
                 LessonType::Bigram {
-                    bigram_type,
+                    bigram_type: bigram_type.clone(),
                     language,
                     level: 1,
                 },
@@ -479,7 +461,7 @@ impl Lesson {
             ),
             Lesson::new(
                 LessonType::Bigram {
-                    bigram_type,
+                    bigram_type: bigram_type.clone(),
                     language,
                     level: 2,
                 },
@@ -489,7 +471,7 @@ impl Lesson {
             ),
             Lesson::new(
                 LessonType::Bigram {
-                    bigram_type,
+                    bigram_type: bigram_type.clone(),
                     language,
                     level: 3,
                 },
@@ -499,12 +481,12 @@ impl Lesson {
             ),
             Lesson::new(
                 LessonType::Bigram {
-                    bigram_type,
+                    bigram_type: bigram_type.clone(),
                     language,
                     level: 4,
                 },
                 format!("{} Bigrams - Level 4", lang_name),
-                match (bigram_type, language) {
+                match (&bigram_type, language) {
                     (BigramType::Natural, Some(Language::French)) => {
                         "Mixed mode: All 40 bigrams (with accents)".to_string()
                     }
@@ -589,6 +571,42 @@ impl Lesson {
                 "All 500 most common words".to_string(),
                 vec![],
             ),
+            Lesson::new(
+                LessonType::CommonWords { language, level: 5 },
+                format!("{} Words - Confusion Drill", lang_name),
+                "Visually similar word pairs (the/thy, form/from)".to_string(),
+                vec![],
+            ),
+        ]
+    }
+
+    /// Create morphology/inflection lessons for a language: one length
+    /// progression (levels 1-3) mixing base words with their plural forms
+    pub fn inflection_lessons(language: Language) -> Vec<Lesson> {
+        let lang_name = match language {
+            Language::French => "French",
+            Language::English => "English",
+        };
+
+        vec![
+            Lesson::new(
+                LessonType::Inflection { language, level: 1 },
+                format!("{} Inflections - Level 1", lang_name),
+                "Top 50 words, mixed singular/plural".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Inflection { language, level: 2 },
+                format!("{} Inflections - Level 2", lang_name),
+                "Top 150 words, mixed singular/plural".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Inflection { language, level: 3 },
+                format!("{} Inflections - Level 3", lang_name),
+                "Full word pool, mixed singular/plural".to_string(),
+                vec![],
+            ),
         ]
     }
 
@@ -703,6 +721,30 @@ impl Lesson {
         }
     }
 
+    /// Create the 3 chord training lessons (home row pairs, home-row mods, bracket chords)
+    pub fn chord_lessons() -> Vec<Lesson> {
+        vec![
+            Lesson::new(
+                LessonType::Chord { level: 1 },
+                "Chords - Home Row Pairs".to_string(),
+                "Press adjacent home row keys simultaneously".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Chord { level: 2 },
+                "Chords - Home Row Mods".to_string(),
+                "Wider reaches and three-key combos".to_string(),
+                vec![],
+            ),
+            Lesson::new(
+                LessonType::Chord { level: 3 },
+                "Chords - Bracket Pairs".to_string(),
+                "Steno-style paired punctuation".to_string(),
+                vec![],
+            ),
+        ]
+    }
+
     fn finger_pair_description(_pair: FingerPairType, level: u8, with_shift: bool) -> String {
         let level_desc = match level {
             1 => "Home row keys only",
@@ -744,13 +786,58 @@ mod tests {
         assert_eq!(lessons_with_shift.len(), 4);
     }
 
+    #[test]
+    fn test_identifier_lessons_count() {
+        let lessons = Lesson::identifier_lessons(ProgrammingLanguage::Rust);
+        // 5 case styles x 3 levels
+        assert_eq!(lessons.len(), 15);
+    }
+
+    #[test]
+    fn test_identifier_lesson_first_is_camel_level_one() {
+        let lessons = Lesson::identifier_lessons(ProgrammingLanguage::Rust);
+        assert_eq!(
+            lessons[0].lesson_type,
+            LessonType::Identifier {
+                language: ProgrammingLanguage::Rust,
+                style: CaseStyle::Camel,
+                level: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_inflection_lessons_count() {
+        let lessons = Lesson::inflection_lessons(Language::English);
+        assert_eq!(lessons.len(), 3);
+    }
+
+    #[test]
+    fn test_inflection_lesson_first_is_level_one() {
+        let lessons = Lesson::inflection_lessons(Language::French);
+        assert_eq!(
+            lessons[0].lesson_type,
+            LessonType::Inflection {
+                language: Language::French,
+                level: 1,
+            }
+        );
+    }
+
     #[test]
     fn test_shifted_char_mapping() {
-        assert_eq!(get_shifted_char('a'), Some('A'));
-        assert_eq!(get_shifted_char('z'), Some('Z'));
-        assert_eq!(get_shifted_char('&'), Some('1'));
-        assert_eq!(get_shifted_char(';'), Some('.'));
-        assert_eq!(get_shifted_char('x'), Some('X')); // lowercase letters map to uppercase
-        assert_eq!(get_shifted_char('@'), None); // symbols without mapping return None
+        let layout = crate::keyboard::AzertyLayout::new();
+        assert_eq!(get_shifted_char(&layout, 'a'), Some('A'));
+        assert_eq!(get_shifted_char(&layout, 'z'), Some('Z'));
+        assert_eq!(get_shifted_char(&layout, '&'), Some('1'));
+        assert_eq!(get_shifted_char(&layout, ';'), Some('.'));
+        assert_eq!(get_shifted_char(&layout, 'x'), Some('X')); // lowercase letters map to uppercase
+        assert_eq!(get_shifted_char(&layout, '@'), None); // symbols without mapping return None
+    }
+
+    #[test]
+    fn test_shifted_char_honors_selected_layout() {
+        let qwerty = crate::keyboard::QwertyLayout::new();
+        assert_eq!(get_shifted_char(&qwerty, 'a'), Some('A'));
     }
 }
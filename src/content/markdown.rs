@@ -0,0 +1,557 @@
+//! Event-driven markdown-to-practice-text normalization.
+//!
+//! `parse_markdown_file` used to hand the raw body straight to
+//! `TypingSession`, so `#` headings, `[links](url)`, `**bold**`, and fenced
+//! code blocks all landed in the practice text as literal syntax. This walks
+//! the body as a stream of start/end/text events (a small, hand-rolled
+//! subset of CommonMark covering headings, emphasis/strong, inline code,
+//! links, list items, and fenced code blocks) and a render pass reacts to
+//! those events rather than rebuilding a document tree — the same
+//! render-handler shape a full markdown crate's event API would have, just
+//! scoped to what custom lessons actually use.
+
+use serde::Deserialize;
+
+/// How `normalize` should turn a parsed markdown body into practice text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentMode {
+    /// Strip markup down to readable text: emphasis/strong markers dropped,
+    /// heading markers dropped, links replaced by their label, list markers
+    /// collapsed. Fenced code blocks are kept as plain text (fences
+    /// dropped) rather than excluded, so a lesson mixing prose and a short
+    /// snippet still reads as one body.
+    Prose,
+    /// Preserve fenced code blocks exactly — including indentation and
+    /// symbols — for users practicing source code. Falls back to `Prose`
+    /// rendering when the body has no fenced code block at all, so a
+    /// `verbatim` lesson without one doesn't end up with empty content.
+    Verbatim,
+}
+
+impl Default for ContentMode {
+    fn default() -> Self {
+        Self::Prose
+    }
+}
+
+/// A single structural or textual event emitted while walking the body
+#[derive(Debug, Clone, PartialEq)]
+enum MarkdownEvent {
+    StartHeading,
+    EndHeading,
+    StartListItem,
+    EndListItem,
+    StartEmphasis,
+    EndEmphasis,
+    StartStrong,
+    EndStrong,
+    StartCodeBlock,
+    EndCodeBlock,
+    /// Plain or inline-code text, rendered verbatim by every mode
+    Text(String),
+    /// One raw line inside a fenced code block, kept byte-exact by `Verbatim`
+    CodeLine(String),
+    /// The boundary between two consecutive lines of the same paragraph, so
+    /// `Prose` can join them with a space instead of fusing their text
+    SoftBreak,
+    /// A blank source line, rendered as a paragraph break
+    Blank,
+}
+
+/// Normalize a custom lesson's markdown `body` into practice text under `mode`
+pub fn normalize(body: &str, mode: ContentMode) -> String {
+    let events = parse_events(body);
+    match mode {
+        ContentMode::Prose => render_prose(&events),
+        ContentMode::Verbatim => render_verbatim(&events),
+    }
+}
+
+/// Walk `body` line by line, classifying each line (fenced code, heading,
+/// list item, or paragraph text) and inline-parsing its text content
+fn parse_events(body: &str) -> Vec<MarkdownEvent> {
+    let mut events = Vec::new();
+    let mut in_code_block = false;
+    let mut fence = String::new();
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+
+        if in_code_block {
+            if is_closing_fence(trimmed, &fence) {
+                in_code_block = false;
+                events.push(MarkdownEvent::EndCodeBlock);
+            } else {
+                events.push(MarkdownEvent::CodeLine(line.to_string()));
+            }
+            continue;
+        }
+
+        if let Some(opened) = opening_fence(trimmed) {
+            in_code_block = true;
+            fence = opened;
+            events.push(MarkdownEvent::StartCodeBlock);
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            events.push(MarkdownEvent::Blank);
+            continue;
+        }
+
+        if let Some(rest) = strip_heading_marker(trimmed) {
+            events.push(MarkdownEvent::StartHeading);
+            parse_inline(rest, &mut events);
+            events.push(MarkdownEvent::EndHeading);
+            continue;
+        }
+
+        if let Some(rest) = strip_list_marker(trimmed) {
+            events.push(MarkdownEvent::StartListItem);
+            parse_inline(rest, &mut events);
+            events.push(MarkdownEvent::EndListItem);
+            continue;
+        }
+
+        parse_inline(trimmed, &mut events);
+        events.push(MarkdownEvent::SoftBreak);
+    }
+
+    // An unterminated fence (no closing ``` / ~~~) still closes at EOF so a
+    // malformed lesson file doesn't silently swallow its own content.
+    if in_code_block {
+        events.push(MarkdownEvent::EndCodeBlock);
+    }
+
+    events
+}
+
+/// Opening fence of a code block: 3+ backticks or tildes, returned as the
+/// exact run of marker characters so the matching close can require at
+/// least as many (a longer outer fence can then nest a shorter inner one)
+fn opening_fence(line: &str) -> Option<String> {
+    for marker in ['`', '~'] {
+        let count = line.chars().take_while(|&c| c == marker).count();
+        if count >= 3 {
+            return Some(marker.to_string().repeat(count));
+        }
+    }
+    None
+}
+
+/// A closing fence must use the same marker character as `fence` and repeat
+/// it at least as many times, with nothing but whitespace after
+fn is_closing_fence(trimmed: &str, fence: &str) -> bool {
+    let Some(marker) = fence.chars().next() else {
+        return false;
+    };
+    let count = trimmed.chars().take_while(|&c| c == marker).count();
+    count >= fence.len() && trimmed[count..].trim().is_empty()
+}
+
+/// `# `.."###### " -> the heading text, or `None` if `line` isn't a heading
+fn strip_heading_marker(line: &str) -> Option<&str> {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if hashes == 0 || hashes > 6 {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if rest.is_empty() {
+        return Some(rest);
+    }
+    rest.strip_prefix(' ')
+}
+
+/// `- `/`* `/`+ `/`1. ` -> the item text, or `None` if `line` isn't a list item
+fn strip_list_marker(line: &str) -> Option<&str> {
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        return Some(rest);
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+/// Parse one line of text for inline emphasis/strong/code/links, pushing
+/// `Text`/`StartEmphasis`/etc. events that a renderer can flatten
+fn parse_inline(line: &str, events: &mut Vec<MarkdownEvent>) {
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+    let mut buf = String::new();
+
+    macro_rules! flush {
+        () => {
+            if !buf.is_empty() {
+                events.push(MarkdownEvent::Text(std::mem::take(&mut buf)));
+            }
+        };
+    }
+
+    while i < chars.len() {
+        // Combined strong+emphasis: ***text*** or ___text___. Flattened to a
+        // single Strong span since rendering ignores nesting either way.
+        if i + 2 < chars.len() && marker_run_len(&chars, i) >= 3 {
+            let marker = chars[i];
+            if let Some(end) = find_closing_run(&chars, i + 3, marker, 3) {
+                if marker != '_' || chars[i..end + 3].iter().any(|c| c.is_whitespace()) {
+                    flush!();
+                    events.push(MarkdownEvent::StartStrong);
+                    parse_inline(&chars[i + 3..end].iter().collect::<String>(), events);
+                    events.push(MarkdownEvent::EndStrong);
+                    i = end + 3;
+                    continue;
+                }
+            }
+        }
+
+        // Strong: **text** or __text__. `_`-delimited spans with no
+        // whitespace at all (snake_case names, `__init__`) are left as
+        // literal text rather than mistaken for emphasis around a word
+        // fragment.
+        if i + 1 < chars.len() && is_emphasis_marker(chars[i]) && chars[i] == chars[i + 1] {
+            let marker = chars[i];
+            if let Some(end) = find_closing_run(&chars, i + 2, marker, 2) {
+                if marker != '_' || chars[i..end + 2].iter().any(|c| c.is_whitespace()) {
+                    flush!();
+                    events.push(MarkdownEvent::StartStrong);
+                    parse_inline(&chars[i + 2..end].iter().collect::<String>(), events);
+                    events.push(MarkdownEvent::EndStrong);
+                    i = end + 2;
+                    continue;
+                }
+            }
+        }
+
+        // Emphasis: *text* or _text_ (same no-whitespace exclusion for _,
+        // so identifiers like `is_valid_flag` pass through untouched)
+        if is_emphasis_marker(chars[i]) {
+            let marker = chars[i];
+            if let Some(end) = find_closing_single(&chars, i + 1, marker) {
+                if marker != '_' || chars[i..end + 1].iter().any(|c| c.is_whitespace()) {
+                    flush!();
+                    events.push(MarkdownEvent::StartEmphasis);
+                    parse_inline(&chars[i + 1..end].iter().collect::<String>(), events);
+                    events.push(MarkdownEvent::EndEmphasis);
+                    i = end + 1;
+                    continue;
+                }
+            }
+        }
+
+        // Inline code: `text`
+        if chars[i] == '`' {
+            if let Some(end) = chars[i + 1..].iter().position(|&c| c == '`') {
+                let end = i + 1 + end;
+                flush!();
+                let code: String = chars[i + 1..end].iter().collect();
+                events.push(MarkdownEvent::Text(code));
+                i = end + 1;
+                continue;
+            }
+        }
+
+        // Link: [label](url) -> keep only the label
+        if chars[i] == '[' {
+            if let Some(close_bracket) = chars[i + 1..].iter().position(|&c| c == ']') {
+                let close_bracket = i + 1 + close_bracket;
+                if chars.get(close_bracket + 1) == Some(&'(') {
+                    if let Some(close_paren) = chars[close_bracket + 2..].iter().position(|&c| c == ')') {
+                        let close_paren = close_bracket + 2 + close_paren;
+                        flush!();
+                        let label: String = chars[i + 1..close_bracket].iter().collect();
+                        events.push(MarkdownEvent::Text(label));
+                        i = close_paren + 1;
+                        continue;
+                    }
+                }
+            }
+        }
+
+        buf.push(chars[i]);
+        i += 1;
+    }
+
+    flush!();
+}
+
+fn is_emphasis_marker(c: char) -> bool {
+    c == '*' || c == '_'
+}
+
+/// How many times `chars[i]` repeats starting at `i` (1 for a lone marker,
+/// 2 for `**`/`__`, 3 for `***`/`___`, ...)
+fn marker_run_len(chars: &[char], i: usize) -> usize {
+    let marker = chars[i];
+    chars[i..].iter().take_while(|&&c| c == marker).count()
+}
+
+/// Find the index of a run of `count` consecutive `marker` characters
+/// starting at `from`, i.e. the closing `**`/`***`/etc. of a strong span
+fn find_closing_run(chars: &[char], from: usize, marker: char, count: usize) -> Option<usize> {
+    let mut i = from;
+    while i + count <= chars.len() {
+        if chars[i..i + count].iter().all(|&c| c == marker) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Find the index of the closing single `marker` of an emphasis span
+fn find_closing_single(chars: &[char], from: usize, marker: char) -> Option<usize> {
+    chars[from..].iter().position(|&c| c == marker).map(|p| from + p)
+}
+
+/// Flatten events into readable text: markup events contribute nothing,
+/// `Text`/`CodeLine` contribute their content, `Blank` becomes a paragraph
+/// break.
+fn render_prose(events: &[MarkdownEvent]) -> String {
+    let mut out = String::new();
+    let mut line_buf = String::new();
+    let mut pending_space = false;
+
+    for event in events {
+        match event {
+            MarkdownEvent::Text(text) => {
+                if pending_space && !line_buf.is_empty() {
+                    line_buf.push(' ');
+                }
+                pending_space = false;
+                line_buf.push_str(text);
+            }
+            MarkdownEvent::CodeLine(text) => {
+                // Each source line inside a fenced block is its own output
+                // line, even in prose mode, rather than joined with a space.
+                pending_space = false;
+                line_buf.push_str(text.trim());
+                flush_line(&mut out, &mut line_buf);
+            }
+            MarkdownEvent::SoftBreak => {
+                pending_space = true;
+            }
+            MarkdownEvent::Blank => {
+                pending_space = false;
+                flush_line(&mut out, &mut line_buf);
+            }
+            MarkdownEvent::EndHeading | MarkdownEvent::EndListItem => {
+                pending_space = false;
+                flush_line(&mut out, &mut line_buf);
+            }
+            MarkdownEvent::StartHeading
+            | MarkdownEvent::StartListItem
+            | MarkdownEvent::StartEmphasis
+            | MarkdownEvent::EndEmphasis
+            | MarkdownEvent::StartStrong
+            | MarkdownEvent::EndStrong
+            | MarkdownEvent::StartCodeBlock
+            | MarkdownEvent::EndCodeBlock => {}
+        }
+    }
+    flush_line(&mut out, &mut line_buf);
+
+    collapse_blank_runs(out.trim().to_string())
+}
+
+fn flush_line(out: &mut String, line_buf: &mut String) {
+    if !line_buf.is_empty() {
+        out.push_str(line_buf);
+        out.push('\n');
+        line_buf.clear();
+    } else if !out.is_empty() {
+        out.push('\n');
+    }
+}
+
+/// Collapse 3+ consecutive newlines (several blank source lines in a row)
+/// down to a single paragraph break
+fn collapse_blank_runs(text: String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut consecutive_newlines = 0;
+
+    for c in text.chars() {
+        if c == '\n' {
+            consecutive_newlines += 1;
+            if consecutive_newlines <= 2 {
+                out.push(c);
+            }
+        } else {
+            consecutive_newlines = 0;
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Extract just the fenced code block(s) verbatim, joined by a blank line;
+/// falls back to `render_prose` if the body has no fenced code block
+fn render_verbatim(events: &[MarkdownEvent]) -> String {
+    let mut blocks = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut in_block = false;
+
+    for event in events {
+        match event {
+            MarkdownEvent::StartCodeBlock => {
+                in_block = true;
+                current.clear();
+            }
+            MarkdownEvent::CodeLine(line) if in_block => {
+                current.push(line);
+            }
+            MarkdownEvent::EndCodeBlock if in_block => {
+                in_block = false;
+                let block = current.join("\n");
+                if !block.trim().is_empty() {
+                    blocks.push(block);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if blocks.is_empty() {
+        return render_prose(events);
+    }
+
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prose_strips_heading_markers() {
+        assert_eq!(normalize("# Hello World", ContentMode::Prose), "Hello World");
+    }
+
+    #[test]
+    fn test_prose_unwraps_strong_and_emphasis() {
+        assert_eq!(
+            normalize("This is **bold** and *italic* text", ContentMode::Prose),
+            "This is bold and italic text"
+        );
+    }
+
+    #[test]
+    fn test_prose_strips_combined_strong_and_emphasis_markers() {
+        assert_eq!(
+            normalize("This is ***really*** important", ContentMode::Prose),
+            "This is really important"
+        );
+    }
+
+    #[test]
+    fn test_prose_leaves_underscored_identifiers_untouched() {
+        assert_eq!(
+            normalize("Call the __init__ method first", ContentMode::Prose),
+            "Call the __init__ method first"
+        );
+        assert_eq!(
+            normalize("check the is_valid_flag variable", ContentMode::Prose),
+            "check the is_valid_flag variable"
+        );
+    }
+
+    #[test]
+    fn test_prose_replaces_links_with_label() {
+        assert_eq!(
+            normalize("See [the docs](https://example.com) for more", ContentMode::Prose),
+            "See the docs for more"
+        );
+    }
+
+    #[test]
+    fn test_prose_collapses_list_markers() {
+        assert_eq!(
+            normalize("- First item\n- Second item", ContentMode::Prose),
+            "First item\nSecond item"
+        );
+    }
+
+    #[test]
+    fn test_prose_strips_code_fences_but_keeps_content() {
+        assert_eq!(
+            normalize("```\nlet x = 1;\n```", ContentMode::Prose),
+            "let x = 1;"
+        );
+    }
+
+    #[test]
+    fn test_prose_joins_wrapped_paragraph_lines_with_a_space() {
+        assert_eq!(
+            normalize("Practice typing this\nsentence across two lines", ContentMode::Prose),
+            "Practice typing this sentence across two lines"
+        );
+    }
+
+    #[test]
+    fn test_prose_collapses_multiple_blank_lines() {
+        assert_eq!(
+            normalize("First\n\n\n\nSecond", ContentMode::Prose),
+            "First\n\nSecond"
+        );
+    }
+
+    #[test]
+    fn test_verbatim_preserves_code_block_indentation_exactly() {
+        let body = "Some intro text\n\n```\nfn main() {\n    println!(\"hi\");\n}\n```\n\nTrailing text";
+        assert_eq!(
+            normalize(body, ContentMode::Verbatim),
+            "fn main() {\n    println!(\"hi\");\n}"
+        );
+    }
+
+    #[test]
+    fn test_verbatim_joins_multiple_code_blocks() {
+        let body = "```\nfirst();\n```\n\nprose in between\n\n```\nsecond();\n```";
+        assert_eq!(
+            normalize(body, ContentMode::Verbatim),
+            "first();\n\nsecond();"
+        );
+    }
+
+    #[test]
+    fn test_verbatim_skips_empty_code_block_before_a_real_one() {
+        let body = "```\n```\n\nprose\n\n```\nsecond();\n```";
+        assert_eq!(normalize(body, ContentMode::Verbatim), "second();");
+    }
+
+    #[test]
+    fn test_verbatim_falls_back_to_prose_without_code_block() {
+        assert_eq!(
+            normalize("# Just prose, no fences", ContentMode::Verbatim),
+            "Just prose, no fences"
+        );
+    }
+
+    #[test]
+    fn test_content_mode_defaults_to_prose() {
+        assert_eq!(ContentMode::default(), ContentMode::Prose);
+    }
+
+    #[test]
+    fn test_unterminated_fence_still_closes_at_end_of_body() {
+        let body = "```\nunterminated code";
+        assert_eq!(normalize(body, ContentMode::Verbatim), "unterminated code");
+    }
+
+    #[test]
+    fn test_longer_outer_fence_does_not_close_on_shorter_inner_backticks() {
+        let body = "````\nHere's an example:\n```\nnested\n```\n````";
+        assert_eq!(
+            normalize(body, ContentMode::Verbatim),
+            "Here's an example:\n```\nnested\n```"
+        );
+    }
+}
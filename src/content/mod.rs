@@ -1,14 +1,52 @@
+pub mod adaptive_code_generator;
 pub mod adaptive_generator;
 pub mod bigram;
 pub mod bigram_generator;
+pub mod bigram_mastery;
+pub mod bigram_match;
+pub mod bigram_metrics;
+pub mod bigram_registry;
+pub mod bigram_scheduler;
+pub mod category;
+pub mod chord;
+pub mod chord_generator;
+pub mod code_corpus;
+pub mod code_corpus_generator;
 pub mod code_generator;
 pub mod code_symbols;
+pub mod common_word;
+pub mod common_word_generator;
+pub mod confusion_drills;
+pub mod corpus;
+pub mod corpus_scan;
+pub mod custom;
 pub mod finger_generator;
 pub mod generator;
+pub mod identifier;
+pub mod identifier_generator;
+pub mod inflection;
+pub mod inflection_generator;
+pub mod language_detector;
+pub mod language_model;
 pub mod lesson;
+pub mod markdown;
+pub mod regex_generator;
+pub mod transition_graph;
+pub mod trigram;
+pub mod trigram_generator;
+pub mod trigram_registry;
+pub mod trigram_scheduler;
+pub mod word_markov;
+pub mod wordlist;
 
-pub use adaptive_generator::AdaptiveLessonGenerator;
+pub use adaptive_code_generator::AdaptiveCodeLessonGenerator;
+pub use adaptive_generator::{AdaptiveContentMode, AdaptiveLessonGenerator};
 pub use bigram::{BigramType, Language};
+pub use category::{LessonCategory, LessonCategoryType};
 pub use code_symbols::ProgrammingLanguage;
+pub use common_word_generator::CommonWordGenerator;
 pub use generator::ContentGenerator;
+pub use identifier::CaseStyle;
 pub use lesson::Lesson;
+pub use trigram_generator::TrigramGenerator;
+pub use wordlist::Wordlist;
@@ -0,0 +1,209 @@
+/// Content generator that emits random text matching a user-supplied regular
+/// expression, for drilling a specific symbol or number pattern (e.g.
+/// `[(){}\[\];:]{20}` or `\d{3}-\d{4}`) that the built-in snippet sets don't cover.
+use rand::Rng;
+use regex_syntax::hir::{Class, Hir, HirKind};
+use regex_syntax::Parser;
+
+/// Repeat count substituted for unbounded `*`/`+`/open-ended `{n,}` so a walk
+/// always terminates, before the level-based scaling in `generate` is applied.
+const DEFAULT_MAX_REPEAT: u32 = 16;
+
+/// Error returned when the user-supplied pattern fails to parse
+#[derive(Debug)]
+pub struct InvalidPattern(String);
+
+impl std::fmt::Display for InvalidPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid regex pattern: {}", self.0)
+    }
+}
+
+pub struct RegexGenerator {
+    hir: Hir,
+}
+
+impl RegexGenerator {
+    pub fn new(pattern: &str) -> Result<Self, InvalidPattern> {
+        let hir = Parser::new()
+            .parse(pattern)
+            .map_err(|e| InvalidPattern(e.to_string()))?;
+
+        Ok(Self { hir })
+    }
+
+    /// Generate practice content matching the pattern for a given level.
+    /// `level` scales the repeat cap, so higher levels produce longer runs
+    /// out of unbounded repetitions like `\d+` or `.*`.
+    pub fn generate(&self, level: usize, length: usize) -> String {
+        let mut rng = rand::thread_rng();
+        let max_repeat = DEFAULT_MAX_REPEAT.saturating_mul(level.max(1) as u32);
+        let mut result = String::new();
+
+        while result.chars().count() < length {
+            if !result.is_empty() {
+                result.push(if rng.gen_bool(0.2) { '\n' } else { ' ' });
+            }
+
+            let mut run = String::new();
+            walk(&self.hir, &mut run, &mut rng, max_repeat);
+
+            if run.is_empty() {
+                // Pattern matched only the empty string; avoid spinning forever
+                break;
+            }
+            result.push_str(&run);
+        }
+
+        result.chars().take(length).collect()
+    }
+}
+
+/// Walk a parsed HIR node, emitting one random string it matches.
+/// Literals emit their text, classes pick a uniformly random member,
+/// alternations pick one branch at random, concatenations emit children in
+/// order, and repetitions pick a count uniformly within the quantifier's
+/// bounds (capped by `max_repeat` when unbounded).
+fn walk(hir: &Hir, out: &mut String, rng: &mut impl Rng, max_repeat: u32) {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => {}
+        HirKind::Literal(lit) => {
+            out.push_str(&String::from_utf8_lossy(&lit.0));
+        }
+        HirKind::Class(class) => {
+            if let Some(c) = pick_class_char(class, rng) {
+                out.push(c);
+            }
+        }
+        HirKind::Repetition(rep) => {
+            let max = rep
+                .max
+                .unwrap_or(rep.min.saturating_add(max_repeat))
+                .min(rep.min.saturating_add(max_repeat));
+            let count = if max > rep.min {
+                rng.gen_range(rep.min..=max)
+            } else {
+                rep.min
+            };
+
+            for _ in 0..count {
+                walk(&rep.sub, out, rng, max_repeat);
+            }
+        }
+        HirKind::Capture(cap) => walk(&cap.sub, out, rng, max_repeat),
+        HirKind::Concat(subs) => {
+            for sub in subs {
+                walk(sub, out, rng, max_repeat);
+            }
+        }
+        HirKind::Alternation(subs) => {
+            if !subs.is_empty() {
+                let idx = rng.gen_range(0..subs.len());
+                walk(&subs[idx], out, rng, max_repeat);
+            }
+        }
+    }
+}
+
+/// Pick a uniformly random character from a character class, weighting each
+/// range by how many characters it spans.
+fn pick_class_char(class: &Class, rng: &mut impl Rng) -> Option<char> {
+    match class {
+        Class::Unicode(unicode) => pick_from_ranges(
+            unicode
+                .ranges()
+                .iter()
+                .map(|r| (r.start() as u32, r.end() as u32)),
+            rng,
+        )
+        .and_then(char::from_u32),
+        Class::Bytes(bytes) => pick_from_ranges(
+            bytes.ranges().iter().map(|r| (r.start() as u32, r.end() as u32)),
+            rng,
+        )
+        .and_then(char::from_u32),
+    }
+}
+
+fn pick_from_ranges(ranges: impl Iterator<Item = (u32, u32)>, rng: &mut impl Rng) -> Option<u32> {
+    let ranges: Vec<(u32, u32)> = ranges.collect();
+    let total: u64 = ranges.iter().map(|(s, e)| (*e - *s + 1) as u64).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.gen_range(0..total);
+    for (start, end) in ranges {
+        let span = (end - start + 1) as u64;
+        if roll < span {
+            return Some(start + roll as u32);
+        }
+        roll -= span;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        assert!(RegexGenerator::new("[").is_err());
+    }
+
+    #[test]
+    fn test_literal_pattern_repeats_itself() {
+        let gen = RegexGenerator::new("ab").unwrap();
+        let content = gen.generate(1, 20);
+
+        assert!(!content.is_empty());
+        assert!(content.chars().count() <= 20);
+        for word in content.split_whitespace() {
+            assert_eq!(word, "ab");
+        }
+    }
+
+    #[test]
+    fn test_class_pattern_only_emits_class_members() {
+        let gen = RegexGenerator::new(r"[(){}\[\];:]{20}").unwrap();
+        let content = gen.generate(1, 100);
+
+        for c in content.chars() {
+            assert!(
+                "(){}[];:".contains(c) || c.is_whitespace(),
+                "unexpected character '{}' outside the class",
+                c
+            );
+        }
+    }
+
+    #[test]
+    fn test_digit_pattern_matches_template() {
+        let gen = RegexGenerator::new(r"\d{3}-\d{4}").unwrap();
+        let content = gen.generate(1, 200);
+
+        for word in content.split_whitespace() {
+            assert_eq!(word.len(), 8);
+            assert!(word.chars().nth(3) == Some('-'));
+            assert!(word.chars().enumerate().all(|(i, c)| i == 3 || c.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_higher_level_allows_longer_unbounded_runs() {
+        let gen = RegexGenerator::new(r"a+").unwrap();
+        let content = gen.generate(10, 500);
+
+        assert!(content.chars().count() <= 500);
+        assert!(content.chars().all(|c| c == 'a' || c.is_whitespace()));
+    }
+
+    #[test]
+    fn test_respects_length_constraint() {
+        let gen = RegexGenerator::new(r"[a-z]{5}").unwrap();
+        let content = gen.generate(2, 30);
+
+        assert!(content.chars().count() <= 30);
+    }
+}
@@ -0,0 +1,293 @@
+/// Synthesizes fresh practice words from a bigram set instead of cycling
+/// through the same handful of `examples`. Letters become vertices and
+/// bigrams become weighted directed edges (weight = the bigram's
+/// `frequency`); a word is grown by a weighted random walk over those
+/// edges, biased toward a caller-chosen set of "target" bigrams so the
+/// synthesized word is likely to exercise the patterns being drilled.
+use std::collections::{HashMap, HashSet};
+
+use rand::Rng;
+
+use super::bigram::Bigram;
+
+/// A word can repeat the same character at most this many times in a row
+/// before the walk is forced to pick something else, so a run of unlucky
+/// high-weight self-loops can't produce "aaaa"
+const MAX_CONSECUTIVE_REPEATS: usize = 2;
+
+/// Upper bound on backtracking steps per word, so a sparse graph that keeps
+/// dead-ending can't loop forever trying to reach the requested length
+const MAX_BACKTRACKS_FACTOR: usize = 4;
+
+/// A synthesized practice word plus which of the caller's target bigrams it
+/// actually ended up containing
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedWord {
+    pub word: String,
+    pub target_bigrams_exercised: Vec<(char, char)>,
+}
+
+/// Character-transition graph built from a bigram set's frequencies
+#[derive(Debug, Clone, Default)]
+pub struct TransitionGraph {
+    edges: HashMap<char, Vec<(char, f32)>>,
+}
+
+impl TransitionGraph {
+    /// Build a graph where `edges[a]` holds every `(b, weight)` this bigram
+    /// set records a transition for, weight taken from `Bigram::frequency`.
+    /// Patterns that aren't exactly two characters are skipped; they can't
+    /// contribute a single-step edge.
+    pub fn from_bigrams(bigrams: &[Bigram]) -> Self {
+        let mut edges: HashMap<char, Vec<(char, f32)>> = HashMap::new();
+
+        for bigram in bigrams {
+            let chars: Vec<char> = bigram.pattern.chars().collect();
+            if let [a, b] = chars[..] {
+                edges.entry(a).or_default().push((b, bigram.frequency));
+            }
+        }
+
+        Self { edges }
+    }
+
+    /// Generate `count` words of `word_length` characters, each via an
+    /// independent weighted random walk starting from a random vertex.
+    /// `target_bigrams` are the (from, to) transitions to bias toward by
+    /// `boost_factor` (their edge weight is multiplied by it before
+    /// sampling); a word that hits a dead end or can't be grown without
+    /// breaking the repeat cap backtracks one character and tries again,
+    /// up to a generous retry budget, and is dropped if it still can't
+    /// reach `word_length`.
+    pub fn generate_words(
+        &self,
+        count: usize,
+        word_length: usize,
+        target_bigrams: &[(char, char)],
+        boost_factor: f32,
+    ) -> Vec<GeneratedWord> {
+        let seeds: Vec<char> = self.edges.keys().copied().collect();
+        if seeds.is_empty() || word_length == 0 {
+            return Vec::new();
+        }
+
+        let targets: HashSet<(char, char)> = target_bigrams.iter().copied().collect();
+        let mut rng = rand::thread_rng();
+
+        (0..count)
+            .filter_map(|_| {
+                let seed = seeds[rng.gen_range(0..seeds.len())];
+                self.walk(seed, word_length, &targets, boost_factor, &mut rng)
+            })
+            .map(|(word, exercised)| GeneratedWord {
+                word,
+                target_bigrams_exercised: exercised,
+            })
+            .collect()
+    }
+
+    /// Grow one word from `seed` via weighted random walk, backtracking on
+    /// dead ends and repeat-cap violations. Returns `None` if the retry
+    /// budget is exhausted before reaching `word_length`.
+    fn walk(
+        &self,
+        seed: char,
+        word_length: usize,
+        targets: &HashSet<(char, char)>,
+        boost_factor: f32,
+        rng: &mut impl Rng,
+    ) -> Option<(String, Vec<(char, char)>)> {
+        let max_backtracks = word_length * MAX_BACKTRACKS_FACTOR;
+        let mut chars = vec![seed];
+        let mut exercised = Vec::new();
+        let mut backtracks = 0;
+
+        while chars.len() < word_length {
+            let current = *chars.last().unwrap();
+            let candidates = self.next_step_candidates(current, &chars);
+
+            let Some((next, is_target)) = self.choose_next(current, &candidates, targets, boost_factor, rng) else {
+                if chars.len() <= 1 || backtracks >= max_backtracks {
+                    return None;
+                }
+                chars.pop();
+                backtracks += 1;
+                continue;
+            };
+
+            if is_target {
+                exercised.push((current, next));
+            }
+            chars.push(next);
+        }
+
+        Some((chars.into_iter().collect(), exercised))
+    }
+
+    /// Outgoing edges from `current` that wouldn't push `chars` over the
+    /// consecutive-repeat cap if taken
+    fn next_step_candidates<'a>(&'a self, current: char, chars: &[char]) -> Vec<&'a (char, f32)> {
+        let Some(edges) = self.edges.get(&current) else {
+            return Vec::new();
+        };
+
+        edges
+            .iter()
+            .filter(|(candidate, _)| !would_exceed_repeat_cap(chars, *candidate))
+            .collect()
+    }
+
+    /// Weighted-random-pick the next character from `candidates`, boosting
+    /// any edge that matches a target bigram. Returns the chosen character
+    /// and whether it was a target-boosted edge.
+    fn choose_next(
+        &self,
+        current: char,
+        candidates: &[&(char, f32)],
+        targets: &HashSet<(char, char)>,
+        boost_factor: f32,
+        rng: &mut impl Rng,
+    ) -> Option<(char, bool)> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let weights: Vec<f32> = candidates
+            .iter()
+            .map(|(next, weight)| {
+                if targets.contains(&(current, *next)) {
+                    weight * boost_factor
+                } else {
+                    *weight
+                }
+            })
+            .collect();
+
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            // A zero or negative `boost_factor` can zero out every candidate
+            // (or a corrupt table could have a non-positive frequency); treat
+            // that the same as no viable edge rather than panicking on an
+            // empty `gen_range`.
+            return None;
+        }
+        let mut pick = rng.gen_range(0.0..total);
+
+        for (i, weight) in weights.iter().enumerate() {
+            if pick < *weight {
+                let next = candidates[i].0;
+                return Some((next, targets.contains(&(current, next))));
+            }
+            pick -= weight;
+        }
+
+        // Floating-point rounding can leave a sliver of `pick` unconsumed;
+        // fall back to the last candidate rather than panicking.
+        let next = candidates[candidates.len() - 1].0;
+        Some((next, targets.contains(&(current, next))))
+    }
+}
+
+/// Whether appending `candidate` to `chars` would create a run of more than
+/// `MAX_CONSECUTIVE_REPEATS` of the same character
+fn would_exceed_repeat_cap(chars: &[char], candidate: char) -> bool {
+    if chars.len() < MAX_CONSECUTIVE_REPEATS {
+        return false;
+    }
+    chars[chars.len() - MAX_CONSECUTIVE_REPEATS..]
+        .iter()
+        .all(|&c| c == candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear_bigrams() -> Vec<Bigram> {
+        vec![
+            Bigram::new("th", 1.00, &["the"]),
+            Bigram::new("he", 0.90, &["the"]),
+            Bigram::new("er", 0.80, &["her"]),
+        ]
+    }
+
+    #[test]
+    fn test_from_bigrams_builds_edges_from_two_char_patterns() {
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        assert_eq!(graph.edges[&'t'], vec![('h', 1.00)]);
+    }
+
+    #[test]
+    fn test_generate_words_returns_requested_length() {
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        let words = graph.generate_words(5, 3, &[], 1.0);
+
+        assert_eq!(words.len(), 5);
+        for generated in &words {
+            assert_eq!(generated.word.chars().count(), 3);
+        }
+    }
+
+    #[test]
+    fn test_no_edges_produces_no_words() {
+        let graph = TransitionGraph::from_bigrams(&[]);
+        assert!(graph.generate_words(5, 3, &[], 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_zero_length_produces_no_words() {
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        assert!(graph.generate_words(5, 0, &[], 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_dead_end_graph_with_single_vertex_yields_no_words() {
+        // 'x' has no outgoing edges at all, so any walk longer than 1
+        // character can never be grown and must give up rather than hang
+        let graph = TransitionGraph::from_bigrams(&[Bigram::new("xx", 1.00, &["example"])]);
+        let words = graph.generate_words(5, 3, &[], 1.0);
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn test_repeat_cap_prevents_long_runs() {
+        // A graph that only ever self-loops 'a' -> 'a' would produce "aaaa"
+        // without the repeat cap; with it, such a walk can never reach
+        // length 3 and is correctly dropped instead of violating the cap.
+        let graph = TransitionGraph::from_bigrams(&[Bigram::new("aa", 1.00, &["aardvark"])]);
+        let words = graph.generate_words(10, 3, &[], 1.0);
+        for generated in &words {
+            assert!(!generated.word.contains("aaa"));
+        }
+    }
+
+    #[test]
+    fn test_target_bigram_with_high_boost_is_exercised() {
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        let words = graph.generate_words(20, 3, &[('h', 'e')], 1000.0);
+
+        assert!(words
+            .iter()
+            .any(|g| g.target_bigrams_exercised.contains(&('h', 'e'))));
+    }
+
+    #[test]
+    fn test_zero_boost_factor_does_not_panic() {
+        // boost_factor = 0.0 zeroes every edge out of 't' (its only outgoing
+        // edge is the target bigram), which must back off gracefully instead
+        // of handing `gen_range` an empty range.
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        let words = graph.generate_words(5, 3, &[('t', 'h')], 0.0);
+        assert!(words.is_empty());
+    }
+
+    #[test]
+    fn test_unexercised_targets_are_not_reported() {
+        let graph = TransitionGraph::from_bigrams(&linear_bigrams());
+        let words = graph.generate_words(5, 3, &[('z', 'z')], 1.0);
+
+        for generated in &words {
+            assert!(generated.target_bigrams_exercised.is_empty());
+        }
+    }
+}
@@ -1,10 +1,42 @@
+use std::collections::{BTreeMap, HashMap};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::bigram::{english_bigrams, french_bigrams, Bigram, Language};
+
+/// Exact relative-frequency weight expressed as numerator/denominator,
+/// so cumulative-distribution sampling never accumulates floating point drift
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Fraction {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl Fraction {
+    pub fn new(numerator: u32, denominator: u32) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn as_f32(&self) -> f32 {
+        if self.denominator == 0 {
+            0.0
+        } else {
+            self.numerator as f32 / self.denominator as f32
+        }
+    }
+}
+
 /// A single trigram with frequency and example words
 #[derive(Debug, Clone)]
 pub struct Trigram {
     pub pattern: String,
-    /// Natural language frequency weighting for future spaced repetition algorithms
-    #[allow(dead_code)]
-    pub frequency: f32, // 0.0 to 1.0, higher = more common (for future use)
+    /// Natural language frequency weighting, also used for weighted sampling
+    pub frequency: f32, // 0.0 to 1.0, higher = more common
     pub examples: Vec<String>,
 }
 
@@ -16,6 +48,332 @@ impl Trigram {
             examples: examples.iter().map(|s| s.to_string()).collect(),
         }
     }
+
+    /// Relative-frequency weight as an exact fraction, derived from `frequency`
+    /// (occurrence_count / total_count, à la a corpus-trained frequency model)
+    pub fn weight(&self) -> Fraction {
+        Fraction::new((self.frequency * 1_000_000.0).round() as u32, 1_000_000)
+    }
+}
+
+/// How many top trigrams a trained model keeps, matching the hardcoded
+/// English/French lists below
+const TRAINED_TRIGRAM_COUNT: usize = 25;
+
+/// How many example words a trained model keeps per trigram
+const MAX_EXAMPLES_PER_TRIGRAM: usize = 5;
+
+/// A trigram frequency table learned from a text corpus, as an alternative
+/// to the hardcoded `english_trigrams()`/`french_trigrams()` lists.
+///
+/// Ngrams are keyed by their exact-fraction weight in a `BTreeMap`, which
+/// both deduplicates by frequency and keeps the table ready to serialize
+/// without a separate sort step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrigramModel {
+    pub language_tag: String,
+    pub ngrams: BTreeMap<Fraction, String>,
+}
+
+impl TrigramModel {
+    /// Train a trigram frequency table from raw corpus text, returning the
+    /// model alongside up to `MAX_EXAMPLES_PER_TRIGRAM` example words seen
+    /// for each kept trigram (for use with [`TrigramModel::to_trigrams`]).
+    ///
+    /// The corpus is lowercased and split on non-alphabetic characters so
+    /// trigrams never span a word boundary. The top `TRAINED_TRIGRAM_COUNT`
+    /// trigrams by occurrence count are kept.
+    pub fn train_from_corpus(corpus: &str, language_tag: &str) -> (Self, HashMap<String, Vec<String>>) {
+        let lowercase = corpus.to_lowercase();
+        let words: Vec<&str> = lowercase
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| w.len() >= 3)
+            .collect();
+
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        let mut examples: HashMap<String, Vec<String>> = HashMap::new();
+
+        for word in &words {
+            let chars: Vec<char> = word.chars().collect();
+            for window in chars.windows(3) {
+                let pattern: String = window.iter().collect();
+                *counts.entry(pattern.clone()).or_insert(0) += 1;
+
+                let word_examples = examples.entry(pattern).or_default();
+                if word_examples.len() < MAX_EXAMPLES_PER_TRIGRAM
+                    && !word_examples.contains(&word.to_string())
+                {
+                    word_examples.push(word.to_string());
+                }
+            }
+        }
+
+        let total: u32 = counts.values().sum();
+        let mut by_count: Vec<(String, u32)> = counts.into_iter().collect();
+        by_count.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        by_count.truncate(TRAINED_TRIGRAM_COUNT);
+
+        let mut ngrams = BTreeMap::new();
+        let mut kept_examples = HashMap::new();
+        for (pattern, count) in by_count {
+            if total == 0 {
+                continue;
+            }
+            if let Some(words) = examples.remove(&pattern) {
+                kept_examples.insert(pattern.clone(), words);
+            }
+            ngrams.insert(Fraction::new(count, total), pattern);
+        }
+
+        let model = Self {
+            language_tag: language_tag.to_string(),
+            ngrams,
+        };
+
+        (model, kept_examples)
+    }
+
+    /// Convert the trained table back into `Trigram`s, ordered from most to
+    /// least frequent, carrying along the example words collected during
+    /// training (falling back to the pattern itself if none were found).
+    pub fn to_trigrams(&self, examples: &HashMap<String, Vec<String>>) -> Vec<Trigram> {
+        self.ngrams
+            .iter()
+            .rev()
+            .map(|(fraction, pattern)| {
+                let words = examples
+                    .get(pattern)
+                    .filter(|words| !words.is_empty())
+                    .cloned()
+                    .unwrap_or_else(|| vec![pattern.clone()]);
+                let example_refs: Vec<&str> = words.iter().map(String::as_str).collect();
+
+                Trigram::new(pattern, fraction.as_f32(), &example_refs)
+            })
+            .collect()
+    }
+}
+
+/// Floor log-probability assigned to a trigram absent from a language model,
+/// roughly ln(1e-5), so an unseen trigram penalizes a candidate without
+/// sending its score to negative infinity
+const FLOOR_LOG_PROBABILITY: f32 = -11.5;
+
+/// Build a relative-frequency probability table from a hardcoded trigram list
+fn language_model(trigrams: &[Trigram]) -> HashMap<&str, f32> {
+    let total: f32 = trigrams.iter().map(|t| t.frequency).sum();
+    trigrams
+        .iter()
+        .map(|t| (t.pattern.as_str(), t.frequency / total))
+        .collect()
+}
+
+/// Extract the lowercase, word-bounded n-grams of size `n` found in `sample`
+fn extract_sample_ngrams(sample: &str, n: usize) -> Vec<String> {
+    let lowercase = sample.to_lowercase();
+    lowercase
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| word.chars().count() >= n)
+        .flat_map(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            chars
+                .windows(n)
+                .map(|window| window.iter().collect::<String>())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Extract the lowercase, word-bounded trigrams found in `sample`
+fn extract_sample_trigrams(sample: &str) -> Vec<String> {
+    extract_sample_ngrams(sample, 3)
+}
+
+/// Extract the lowercase, word-bounded bigrams found in `sample`, used as a
+/// fallback when `sample` is too short to yield 3 trigrams
+fn extract_sample_bigrams(sample: &str) -> Vec<String> {
+    extract_sample_ngrams(sample, 2)
+}
+
+/// Lowercase letters found in `sample`, used as a last-resort fallback when
+/// even a 2-char window doesn't fit (e.g. a single-character sample)
+fn extract_sample_letters(sample: &str) -> Vec<char> {
+    sample.to_lowercase().chars().filter(|c| c.is_alphabetic()).collect()
+}
+
+/// Build a relative-frequency probability table from a hardcoded bigram list,
+/// mirroring `language_model` one n-gram size down
+fn bigram_language_model(bigrams: &[Bigram]) -> HashMap<&str, f32> {
+    let total: f32 = bigrams.iter().map(|b| b.frequency).sum();
+    bigrams
+        .iter()
+        .map(|b| (b.pattern.as_str(), b.frequency / total))
+        .collect()
+}
+
+/// Build a per-letter frequency table from a hardcoded bigram list's own
+/// example words, rather than introducing a separate hardcoded letter-
+/// frequency table
+fn letter_language_model(bigrams: &[Bigram]) -> HashMap<char, f32> {
+    let mut counts: HashMap<char, u32> = HashMap::new();
+    for bigram in bigrams {
+        for word in &bigram.examples {
+            for letter in word.chars().filter(|c| c.is_alphabetic()) {
+                *counts.entry(letter).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let total: u32 = counts.values().sum::<u32>().max(1);
+    counts
+        .into_iter()
+        .map(|(letter, count)| (letter, count as f32 / total as f32))
+        .collect()
+}
+
+/// Sum log-likelihood of `sample_letters` against a letter frequency model,
+/// falling back to `FLOOR_LOG_PROBABILITY` for letters the model never saw
+fn log_score_letters(sample_letters: &[char], model: &HashMap<char, f32>) -> f32 {
+    sample_letters
+        .iter()
+        .map(|letter| {
+            model
+                .get(letter)
+                .map(|probability| probability.ln())
+                .unwrap_or(FLOOR_LOG_PROBABILITY)
+        })
+        .sum()
+}
+
+/// Sum log-likelihood of `sample_trigrams` against a language model,
+/// falling back to `FLOOR_LOG_PROBABILITY` for trigrams the model never saw
+fn log_score(sample_trigrams: &[String], model: &HashMap<&str, f32>) -> f32 {
+    sample_trigrams
+        .iter()
+        .map(|trigram| {
+            model
+                .get(trigram.as_str())
+                .map(|probability| probability.ln())
+                .unwrap_or(FLOOR_LOG_PROBABILITY)
+        })
+        .sum()
+}
+
+/// Language with the higher score, defaulting to French on a tie (mirrors a
+/// 0.0-vs-0.0 tie on a sample with no signal at all)
+fn higher_scoring_language(french_score: f32, english_score: f32) -> Language {
+    if french_score >= english_score {
+        Language::French
+    } else {
+        Language::English
+    }
+}
+
+/// Detect the most likely language of `sample` by scoring its trigrams
+/// against each language's trigram frequency model and picking the best fit.
+/// Samples too short to yield 3 trigrams fall back to bigram lookups, and
+/// samples too short even for that fall back to per-letter frequency.
+pub(crate) fn detect_language(sample: &str) -> Language {
+    let sample_trigrams = extract_sample_trigrams(sample);
+    if sample_trigrams.len() >= 3 {
+        let french_trigrams = french_trigrams();
+        let english_trigrams = english_trigrams();
+        let french_model = language_model(&french_trigrams);
+        let english_model = language_model(&english_trigrams);
+
+        return higher_scoring_language(
+            log_score(&sample_trigrams, &french_model),
+            log_score(&sample_trigrams, &english_model),
+        );
+    }
+
+    let sample_bigrams = extract_sample_bigrams(sample);
+    if !sample_bigrams.is_empty() {
+        let french_bigrams = french_bigrams();
+        let english_bigrams = english_bigrams();
+        let french_model = bigram_language_model(&french_bigrams);
+        let english_model = bigram_language_model(&english_bigrams);
+
+        return higher_scoring_language(
+            log_score(&sample_bigrams, &french_model),
+            log_score(&sample_bigrams, &english_model),
+        );
+    }
+
+    let sample_letters = extract_sample_letters(sample);
+    let french_bigrams = french_bigrams();
+    let english_bigrams = english_bigrams();
+    let french_model = letter_language_model(&french_bigrams);
+    let english_model = letter_language_model(&english_bigrams);
+
+    higher_scoring_language(
+        log_score_letters(&sample_letters, &french_model),
+        log_score_letters(&sample_letters, &english_model),
+    )
+}
+
+/// Smallest weight a trigram can carry under `SelectionWeighting::Inverse`,
+/// so a trigram at `frequency == 1.0` still has a (tiny) chance of being
+/// drawn rather than a weight of exactly zero
+const INVERSE_SELECTION_WEIGHT_EPSILON: f64 = 0.01;
+
+/// How `select_trigrams` weights its random draws
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionWeighting {
+    /// Weight by `frequency`: common trigrams are drawn more often, same
+    /// bias as `trigram_generator`'s drill/word/mixed modes
+    ByFrequency,
+    /// Weight by `1.0 - frequency`: emphasizes the trigrams a struggling
+    /// user is least exposed to, pairs naturally with a spaced-repetition
+    /// scheduler (see `trigram_scheduler`) biasing toward overdue patterns
+    Inverse,
+}
+
+/// Selection weight for one trigram under `weighting`
+fn selection_weight(trigram: &Trigram, weighting: SelectionWeighting) -> f64 {
+    match weighting {
+        SelectionWeighting::ByFrequency => trigram.frequency as f64,
+        SelectionWeighting::Inverse => {
+            (1.0 - trigram.frequency as f64).max(INVERSE_SELECTION_WEIGHT_EPSILON)
+        }
+    }
+}
+
+/// Draw up to `n` trigrams from `set` without replacement, weighted by
+/// `weighting` rather than always returning the same fixed, descending-
+/// frequency prefix of `set`.
+///
+/// Builds a cumulative-weight prefix-sum table over the remaining
+/// candidates, draws a uniform value in `[0, total)`, and binary-searches
+/// the prefix sums to pick an index; the chosen trigram is swap-removed from
+/// the working set and the table recomputed before the next draw. Returns
+/// fewer than `n` trigrams if `set` has fewer than `n` entries.
+pub fn select_trigrams(set: &[Trigram], n: usize, weighting: SelectionWeighting) -> Vec<Trigram> {
+    let mut remaining: Vec<Trigram> = set.to_vec();
+    let mut selected = Vec::with_capacity(n.min(remaining.len()));
+    let mut rng = rand::thread_rng();
+
+    while selected.len() < n && !remaining.is_empty() {
+        let mut cumulative = Vec::with_capacity(remaining.len());
+        let mut total = 0.0;
+        for trigram in &remaining {
+            total += selection_weight(trigram, weighting);
+            cumulative.push(total);
+        }
+
+        if total <= 0.0 {
+            break;
+        }
+
+        let target = rng.gen_range(0.0..total);
+        let idx = cumulative
+            .partition_point(|&c| c < target)
+            .min(remaining.len() - 1);
+
+        selected.push(remaining.swap_remove(idx));
+    }
+
+    selected
 }
 
 /// English language trigrams (frequency-ordered)
@@ -666,12 +1024,19 @@ mod tests {
 
     #[test]
     fn test_examples_contain_trigrams() {
+        use crate::content::bigram_match::{bigram_matches, MatchingPolicy};
+
         let all = vec![french_trigrams(), english_trigrams()];
         for set in all {
             for trigram in set {
                 for example in &trigram.examples {
+                    // NFC-normalized comparison (see `bigram_match`), so a
+                    // composed ("é") vs. decomposed ("e" + combining acute)
+                    // accent in either side can't make a genuinely-matching
+                    // example fail, the same fix applied to the bigram
+                    // tables' equivalent test
                     assert!(
-                        example.to_lowercase().contains(&trigram.pattern),
+                        bigram_matches(&trigram.pattern, example, MatchingPolicy::Strict),
                         "Example '{}' should contain trigram '{}'",
                         example,
                         trigram.pattern
@@ -680,4 +1045,172 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_train_from_corpus_finds_common_trigram() {
+        let corpus = "the the the and and the cat sat on the mat";
+        let (model, _) = TrigramModel::train_from_corpus(corpus, "en");
+
+        assert_eq!(model.language_tag, "en");
+        assert!(model.ngrams.values().any(|pattern| pattern == "the"));
+    }
+
+    #[test]
+    fn test_train_from_corpus_ignores_short_words_and_punctuation() {
+        let corpus = "a an, to. THE-the THE";
+        let (model, _) = TrigramModel::train_from_corpus(corpus, "en");
+
+        // "a", "an", "to" are all shorter than a trigram window
+        assert!(!model.ngrams.values().any(|pattern| pattern.len() < 3));
+        // Case is folded so "THE" and "the" count toward the same trigram
+        assert!(model.ngrams.values().any(|pattern| pattern == "the"));
+    }
+
+    #[test]
+    fn test_train_from_corpus_caps_trigram_count() {
+        let corpus = "abcdefghijklmnopqrstuvwxyz abcdefghijklmnopqrstuvwxyz0123456789";
+        let (model, _) = TrigramModel::train_from_corpus(corpus, "en");
+
+        assert!(model.ngrams.len() <= TRAINED_TRIGRAM_COUNT);
+    }
+
+    #[test]
+    fn test_to_trigrams_descending_order() {
+        let corpus = "the the the the and and cat";
+        let (model, examples) = TrigramModel::train_from_corpus(corpus, "en");
+
+        let trigrams = model.to_trigrams(&examples);
+
+        // Frequencies should be non-increasing
+        for pair in trigrams.windows(2) {
+            assert!(pair[0].frequency >= pair[1].frequency);
+        }
+        assert_eq!(trigrams[0].pattern, "the");
+    }
+
+    #[test]
+    fn test_to_trigrams_keeps_example_words() {
+        let corpus = "keyboard keyboard keyboard";
+        let (model, examples) = TrigramModel::train_from_corpus(corpus, "en");
+
+        let trigrams = model.to_trigrams(&examples);
+
+        let key_trigram = trigrams
+            .iter()
+            .find(|t| t.pattern == "key")
+            .expect("'key' trigram should be present");
+        assert!(key_trigram.examples.contains(&"keyboard".to_string()));
+    }
+
+    #[test]
+    fn test_detect_language_english() {
+        let sample = "the quick brown fox jumps over the lazy dog and the cat";
+        assert_eq!(detect_language(sample), Language::English);
+    }
+
+    #[test]
+    fn test_detect_language_french() {
+        let sample = "les chiens et les chats sont dans les rues avec leurs amis";
+        assert_eq!(detect_language(sample), Language::French);
+    }
+
+    #[test]
+    fn test_language_detect_delegates() {
+        let sample = "the quick brown fox jumps over the lazy dog and the cat";
+        assert_eq!(Language::detect(sample), Language::English);
+    }
+
+    #[test]
+    fn test_detect_language_falls_back_to_bigrams_for_short_english_sample() {
+        // "to" yields zero trigrams (word shorter than 3 chars) but one bigram
+        assert!(extract_sample_trigrams("to").is_empty());
+        assert_eq!(detect_language("to"), Language::English);
+    }
+
+    #[test]
+    fn test_detect_language_falls_back_to_bigrams_for_short_french_sample() {
+        assert!(extract_sample_trigrams("le").is_empty());
+        assert_eq!(detect_language("le"), Language::French);
+    }
+
+    #[test]
+    fn test_detect_language_falls_back_to_letters_for_single_char_sample() {
+        // A lone letter yields zero trigrams and zero bigrams
+        assert!(extract_sample_trigrams("w").is_empty());
+        assert!(extract_sample_bigrams("w").is_empty());
+        assert_eq!(detect_language("w"), Language::English);
+    }
+
+    #[test]
+    fn test_detect_language_falls_back_to_letters_for_single_accented_char_sample() {
+        assert!(extract_sample_bigrams("é").is_empty());
+        assert_eq!(detect_language("é"), Language::French);
+    }
+
+    #[test]
+    fn test_select_trigrams_returns_requested_count() {
+        let set = english_trigrams();
+        let selected = select_trigrams(&set, 5, SelectionWeighting::ByFrequency);
+        assert_eq!(selected.len(), 5);
+    }
+
+    #[test]
+    fn test_select_trigrams_has_no_duplicates() {
+        let set = english_trigrams();
+        let selected = select_trigrams(&set, set.len(), SelectionWeighting::ByFrequency);
+
+        let mut patterns: Vec<&str> = selected.iter().map(|t| t.pattern.as_str()).collect();
+        patterns.sort_unstable();
+        patterns.dedup();
+        assert_eq!(patterns.len(), selected.len());
+    }
+
+    #[test]
+    fn test_select_trigrams_more_than_available_returns_all() {
+        let set = english_trigrams();
+        let selected = select_trigrams(&set, set.len() + 10, SelectionWeighting::ByFrequency);
+        assert_eq!(selected.len(), set.len());
+    }
+
+    #[test]
+    fn test_select_trigrams_empty_set_is_empty() {
+        let selected = select_trigrams(&[], 5, SelectionWeighting::ByFrequency);
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn test_select_trigrams_by_frequency_favors_common_trigram() {
+        let set = vec![
+            Trigram::new("aaa", 1.0, &["aaa"]),
+            Trigram::new("zzz", 0.1, &["zzz"]),
+        ];
+
+        let mut common_picks = 0;
+        for _ in 0..200 {
+            let picked = select_trigrams(&set, 1, SelectionWeighting::ByFrequency);
+            if picked[0].pattern == "aaa" {
+                common_picks += 1;
+            }
+        }
+
+        assert!(common_picks > 150);
+    }
+
+    #[test]
+    fn test_select_trigrams_inverse_favors_rare_trigram() {
+        let set = vec![
+            Trigram::new("aaa", 1.0, &["aaa"]),
+            Trigram::new("zzz", 0.1, &["zzz"]),
+        ];
+
+        let mut rare_picks = 0;
+        for _ in 0..200 {
+            let picked = select_trigrams(&set, 1, SelectionWeighting::Inverse);
+            if picked[0].pattern == "zzz" {
+                rare_picks += 1;
+            }
+        }
+
+        assert!(rare_picks > 150);
+    }
 }
@@ -1,9 +1,47 @@
 /// Content generator for trigram training lessons
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use std::io;
+use std::path::Path;
+
 use super::bigram::Language;
 use super::trigram::{english_trigrams, french_trigrams, Trigram};
+use super::wordlist::{Wordlist, WordlistIndex};
 
 pub struct TrigramGenerator {
     trigrams: Vec<Trigram>,
+    wordlist_index: Option<WordlistIndex>,
+}
+
+/// Derive a deterministic RNG seed from level+length so repeated calls with
+/// the same arguments reproduce the same content
+fn seed_for(level: usize, length: usize) -> u64 {
+    ((level as u64) << 32) | (length as u64)
+}
+
+/// Build a cumulative weight distribution over the selected trigrams
+fn cumulative_weights(trigrams: &[&Trigram]) -> (Vec<u64>, u64) {
+    let mut cumulative = Vec::with_capacity(trigrams.len());
+    let mut total = 0u64;
+
+    for trigram in trigrams {
+        total += trigram.weight().numerator as u64;
+        cumulative.push(total);
+    }
+
+    (cumulative, total)
+}
+
+/// Draw a trigram index proportionally to its weight via binary search
+/// over the cumulative distribution
+fn sample_weighted_index(cumulative: &[u64], total_weight: u64, rng: &mut StdRng) -> usize {
+    if total_weight == 0 {
+        return 0;
+    }
+
+    let target = rng.gen_range(0..total_weight);
+    cumulative.partition_point(|&c| c <= target)
 }
 
 impl TrigramGenerator {
@@ -13,7 +51,53 @@ impl TrigramGenerator {
             Language::English => english_trigrams(),
         };
 
-        Self { trigrams }
+        Self {
+            trigrams,
+            wordlist_index: None,
+        }
+    }
+
+    /// Build a generator for the language auto-detected from `sample`,
+    /// so the tool can configure itself from pasted practice text
+    pub fn from_sample(sample: &str) -> Self {
+        Self::new(Language::detect(sample))
+    }
+
+    /// Draw word-mode and mixed-mode vocabulary from a wordlist file instead
+    /// of the handful of hardcoded `examples` per trigram, so long lessons
+    /// don't repeat quickly. The file is indexed by which trigram patterns
+    /// (from this generator's full trigram set) each word contains.
+    pub fn with_wordlist(mut self, path: &Path) -> io::Result<Self> {
+        let wordlist = Wordlist::from_path(path)?;
+        self.wordlist_index = Some(self.build_wordlist_index(&wordlist));
+        Ok(self)
+    }
+
+    /// Use the compiled-in wordlist for this generator's language, if its
+    /// cargo feature was enabled at build time
+    pub fn with_bundled_wordlist(mut self, language: Language) -> Self {
+        if let Some(wordlist) = Wordlist::bundled(language) {
+            self.wordlist_index = Some(self.build_wordlist_index(&wordlist));
+        }
+        self
+    }
+
+    fn build_wordlist_index(&self, wordlist: &Wordlist) -> WordlistIndex {
+        let patterns: Vec<&str> = self.trigrams.iter().map(|t| t.pattern.as_str()).collect();
+        WordlistIndex::build(wordlist, &patterns)
+    }
+
+    /// Pick an example word for `trigram`, preferring the wordlist (cycling
+    /// through its matches via `counter`) and falling back to the trigram's
+    /// own hardcoded examples when no wordlist is loaded or it has no match
+    fn select_example(&self, trigram: &Trigram, counter: usize) -> String {
+        if let Some(index) = &self.wordlist_index {
+            if let Some(words) = index.words_for(&trigram.pattern) {
+                return words[counter % words.len()].clone();
+            }
+        }
+
+        trigram.examples[counter % trigram.examples.len()].clone()
     }
 
     /// Generate content for a given level
@@ -25,9 +109,9 @@ impl TrigramGenerator {
         let selected_trigrams = self.select_trigrams_for_level(level);
 
         match level {
-            1 => self.generate_drill_mode(&selected_trigrams, length),
-            2 => self.generate_word_mode(&selected_trigrams, length),
-            3 | 4 => self.generate_mixed_mode(&selected_trigrams, length),
+            1 => self.generate_drill_mode(&selected_trigrams, level, length),
+            2 => self.generate_word_mode(&selected_trigrams, level, length),
+            3 | 4 => self.generate_mixed_mode(&selected_trigrams, level, length),
             _ => String::new(),
         }
     }
@@ -45,58 +129,64 @@ impl TrigramGenerator {
         self.trigrams.iter().take(count).collect()
     }
 
-    /// Level 1: Pure trigram repetition
+    /// Level 1: Pure trigram repetition, frequency-weighted so common
+    /// trigrams ("the") are drilled more often than rare ones
     /// Example: "the the the and and and"
-    fn generate_drill_mode(&self, trigrams: &[&Trigram], length: usize) -> String {
+    fn generate_drill_mode(&self, trigrams: &[&Trigram], level: usize, length: usize) -> String {
         let mut result = String::new();
-        let mut idx = 0;
+        let (cumulative, total_weight) = cumulative_weights(trigrams);
+        let mut rng = StdRng::seed_from_u64(seed_for(level, length));
 
         while result.len() < length {
             if !result.is_empty() {
                 result.push(' ');
             }
 
-            let trigram = trigrams[idx % trigrams.len()];
+            let idx = sample_weighted_index(&cumulative, total_weight, &mut rng);
+            let trigram = trigrams[idx];
             // Repeat the trigram 3 times
             result.push_str(&format!(
                 "{} {} {}",
                 trigram.pattern, trigram.pattern, trigram.pattern
             ));
-
-            idx += 1;
         }
 
         result.chars().take(length).collect()
     }
 
-    /// Level 2: Trigrams in word context
+    /// Level 2: Trigrams in word context, trigram choice weighted by frequency
     /// Example: "the them then and hand stand"
-    fn generate_word_mode(&self, trigrams: &[&Trigram], length: usize) -> String {
+    fn generate_word_mode(&self, trigrams: &[&Trigram], level: usize, length: usize) -> String {
         let mut result = String::new();
-        let mut trigram_idx = 0;
+        let (cumulative, total_weight) = cumulative_weights(trigrams);
+        let mut rng = StdRng::seed_from_u64(seed_for(level, length));
+        let mut example_counts = vec![0usize; trigrams.len()];
 
         while result.len() < length {
             if !result.is_empty() {
                 result.push(' ');
             }
 
-            let trigram = trigrams[trigram_idx % trigrams.len()];
+            let idx = sample_weighted_index(&cumulative, total_weight, &mut rng);
+            let trigram = trigrams[idx];
 
-            // Cycle through examples for this trigram
-            let example_idx = (trigram_idx / trigrams.len()) % trigram.examples.len();
-            let word = &trigram.examples[example_idx];
+            let word = self.select_example(trigram, example_counts[idx]);
+            example_counts[idx] += 1;
 
-            result.push_str(word);
-            trigram_idx += 1;
+            result.push_str(&word);
         }
 
         result.chars().take(length).collect()
     }
 
-    /// Level 3-4: Realistic sentences with target trigrams
+    /// Level 3-4: Realistic sentences with target trigrams, weighted toward
+    /// the higher-frequency trigrams in the selected set
     /// Combines examples into natural-looking phrases
-    fn generate_mixed_mode(&self, trigrams: &[&Trigram], length: usize) -> String {
+    fn generate_mixed_mode(&self, trigrams: &[&Trigram], level: usize, length: usize) -> String {
         let mut result = String::new();
+        let (cumulative, total_weight) = cumulative_weights(trigrams);
+        let mut rng = StdRng::seed_from_u64(seed_for(level, length));
+        let mut example_counts = vec![0usize; trigrams.len()];
         let mut word_count = 0;
 
         while result.len() < length {
@@ -104,14 +194,14 @@ impl TrigramGenerator {
                 result.push(' ');
             }
 
-            // Pick a trigram
-            let trigram = trigrams[word_count % trigrams.len()];
+            // Pick a trigram, weighted by frequency
+            let idx = sample_weighted_index(&cumulative, total_weight, &mut rng);
+            let trigram = trigrams[idx];
 
-            // Pick an example
-            let example_idx = (word_count / trigrams.len()) % trigram.examples.len();
-            let word = &trigram.examples[example_idx];
+            let word = self.select_example(trigram, example_counts[idx]);
+            example_counts[idx] += 1;
 
-            result.push_str(word);
+            result.push_str(&word);
             word_count += 1;
         }
 
@@ -219,4 +309,59 @@ mod tests {
         assert_eq!(level3_trigrams.len(), 20); // CHANGED from 15
         assert_eq!(level4_trigrams.len(), 20); // English has 20 trigrams total
     }
+
+    #[test]
+    fn test_from_sample_detects_english() {
+        let sample = "the quick brown fox jumps over the lazy dog and the cat";
+        let gen = TrigramGenerator::from_sample(sample);
+
+        let content = gen.generate(1, 30);
+        assert!(content.contains("the the the") || content.contains("and and and"));
+    }
+
+    #[test]
+    fn test_from_sample_detects_french() {
+        let sample = "les chiens et les chats sont dans les rues avec leurs amis";
+        let gen = TrigramGenerator::from_sample(sample);
+
+        let content = gen.generate(1, 30);
+        assert!(content.contains("les les les") || content.contains("des des des"));
+    }
+
+    #[test]
+    fn test_with_wordlist_draws_from_file() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "themselves\ntheory\ntheme\nanderson\nhandshake").unwrap();
+
+        let gen = TrigramGenerator::new(Language::English)
+            .with_wordlist(file.path())
+            .unwrap();
+
+        let content = gen.generate(2, 200);
+        assert!(
+            content.contains("themselves")
+                || content.contains("theory")
+                || content.contains("theme")
+        );
+    }
+
+    #[test]
+    fn test_with_wordlist_missing_file_errors() {
+        let result = TrigramGenerator::new(Language::English)
+            .with_wordlist(Path::new("/nonexistent/wordlist.txt"));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_bundled_wordlist_without_feature_falls_back() {
+        // With no wordlist feature enabled, the generator should still
+        // fall back to the hardcoded examples rather than producing nothing
+        let gen = TrigramGenerator::new(Language::English).with_bundled_wordlist(Language::English);
+
+        let content = gen.generate(2, 50);
+        assert!(!content.is_empty());
+    }
 }
@@ -0,0 +1,310 @@
+/// Registry of named trigram tables, so adding a language to practice means
+/// dropping a `LanguagePack` JSON file into a config directory rather than
+/// editing Rust. `trigram::french_trigrams()`/`english_trigrams()` stay as
+/// the crate's embedded defaults; a `LanguagePack` file discovered by
+/// `load_packs` can extend the registry with a new language or replace an
+/// existing one's table, without recompiling.
+///
+/// Mirrors `bigram_registry::BigramRegistry`'s shape, but the on-disk format
+/// carries its own `language` name (so the registry key comes from the pack's
+/// content rather than the file's name), and only JSON is supported since
+/// `LanguagePack` has no TOML-specific use case yet.
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::trigram::{english_trigrams, french_trigrams, Trigram};
+
+/// On-disk shape of one language pack file: `{ language, trigrams: [...] }`
+#[derive(Debug, Deserialize)]
+struct LanguagePack {
+    language: String,
+    trigrams: Vec<TrigramEntryConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TrigramEntryConfig {
+    pattern: String,
+    frequency: f32,
+    #[serde(default)]
+    examples: Vec<String>,
+}
+
+/// Trigram tables keyed by language name, seeded from the crate's embedded
+/// defaults and extendable at runtime with `LanguagePack` files from a config
+/// directory (see `load_packs`).
+#[derive(Debug, Clone, Default)]
+pub struct TrigramRegistry {
+    tables: HashMap<String, Vec<Trigram>>,
+}
+
+impl TrigramRegistry {
+    /// A registry containing just the crate's embedded French/English tables
+    pub fn with_defaults() -> Self {
+        let mut tables = HashMap::new();
+        tables.insert("french".to_string(), french_trigrams());
+        tables.insert("english".to_string(), english_trigrams());
+        Self { tables }
+    }
+
+    /// Discover and load every `.json` language pack file in `dir`, keyed by
+    /// the pack's own `language` field (not the file name). A `language` that
+    /// matches an existing entry (e.g. a user's own `french.json`) replaces
+    /// that language's table entirely. A missing `dir` isn't an error, just
+    /// nothing to load. A file that fails to parse or fails `validate_pack`
+    /// is skipped with a diagnostic on stderr and the registry keeps whatever
+    /// it already had for that name, the same degrade-rather-than-fail
+    /// approach `BigramRegistry::load_overrides` uses for bigram tables.
+    pub fn load_packs(&mut self, dir: &Path) {
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return;
+        };
+
+        // Sorted so that if a directory somehow yields two packs claiming the
+        // same `language`, which one wins is deterministic rather than
+        // filesystem-order-dependent.
+        let mut paths: Vec<_> = read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let is_json = path.extension().is_some_and(|ext| ext == "json");
+            if !is_json {
+                continue;
+            }
+
+            match load_pack_file(&path) {
+                Ok((language, trigrams)) => {
+                    self.tables.insert(language, trigrams);
+                }
+                Err(message) => {
+                    eprintln!(
+                        "warning: failed to load language pack from {}: {message}; keeping existing table",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+
+    /// The trigram table registered under `name`, if any
+    pub fn get(&self, name: &str) -> Option<&[Trigram]> {
+        self.tables.get(name).map(Vec::as_slice)
+    }
+
+    /// Every registered language name, sorted for stable display
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tables.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Parse one `LanguagePack` JSON file and validate it before accepting it
+/// into the registry, returning its declared language name alongside the
+/// trigrams it carries.
+fn load_pack_file(path: &Path) -> Result<(String, Vec<Trigram>), String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let pack: LanguagePack = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+
+    validate_pack(&pack)?;
+
+    let trigrams: Vec<Trigram> = pack
+        .trigrams
+        .into_iter()
+        .map(|entry| {
+            let examples: Vec<&str> = entry.examples.iter().map(String::as_str).collect();
+            Trigram::new(&entry.pattern, entry.frequency, &examples)
+        })
+        .collect();
+
+    Ok((pack.language, trigrams))
+}
+
+/// Enforce the pack invariants the request calls for: a non-empty `language`
+/// name, and every trigram carrying a non-empty pattern and at least one
+/// example.
+fn validate_pack(pack: &LanguagePack) -> Result<(), String> {
+    if pack.language.trim().is_empty() {
+        return Err("language pack is missing a non-empty 'language' name".to_string());
+    }
+
+    for entry in &pack.trigrams {
+        if entry.pattern.is_empty() {
+            return Err("trigram entry has an empty pattern".to_string());
+        }
+        if entry.examples.is_empty() {
+            return Err(format!(
+                "trigram '{}' has no examples; at least one is required",
+                entry.pattern
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_defaults_contains_french_and_english() {
+        let registry = TrigramRegistry::with_defaults();
+        assert!(registry.get("french").is_some());
+        assert!(registry.get("english").is_some());
+        assert!(registry.get("spanish").is_none());
+    }
+
+    #[test]
+    fn test_load_packs_missing_dir_is_not_an_error() {
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(Path::new("/no/such/directory"));
+        assert!(registry.get("french").is_some());
+    }
+
+    #[test]
+    fn test_load_packs_adds_a_new_language() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("spanish.json"),
+            r#"{
+                "language": "spanish",
+                "trigrams": [
+                    { "pattern": "que", "frequency": 1.0, "examples": ["porque"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        let spanish = registry.get("spanish").unwrap();
+        assert_eq!(spanish[0].pattern, "que");
+    }
+
+    #[test]
+    fn test_load_packs_keys_by_declared_language_not_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("my_custom_pack.json"),
+            r#"{
+                "language": "german",
+                "trigrams": [
+                    { "pattern": "sch", "frequency": 1.0, "examples": ["schon"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        assert!(registry.get("my_custom_pack").is_none());
+        assert_eq!(registry.get("german").unwrap()[0].pattern, "sch");
+    }
+
+    #[test]
+    fn test_load_packs_replaces_an_existing_language() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("french.json"),
+            r#"{
+                "language": "french",
+                "trigrams": [
+                    { "pattern": "xyz", "frequency": 1.0, "examples": ["xyzemple"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        let french = registry.get("french").unwrap();
+        assert_eq!(french.len(), 1);
+        assert_eq!(french[0].pattern, "xyz");
+    }
+
+    #[test]
+    fn test_load_packs_ignores_unsupported_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a language pack").unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        assert_eq!(registry.names(), vec!["english", "french"]);
+    }
+
+    #[test]
+    fn test_load_packs_rejects_empty_pattern() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("bad.json"),
+            r#"{
+                "language": "bad",
+                "trigrams": [
+                    { "pattern": "", "frequency": 1.0, "examples": ["something"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        assert!(registry.get("bad").is_none());
+    }
+
+    #[test]
+    fn test_load_packs_rejects_trigram_with_no_examples() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("bad.json"),
+            r#"{
+                "language": "bad",
+                "trigrams": [
+                    { "pattern": "abc", "frequency": 1.0, "examples": [] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        assert!(registry.get("bad").is_none());
+    }
+
+    #[test]
+    fn test_load_packs_rejects_missing_language_name() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("bad.json"),
+            r#"{
+                "language": "",
+                "trigrams": [
+                    { "pattern": "abc", "frequency": 1.0, "examples": ["abcess"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = TrigramRegistry::with_defaults();
+        registry.load_packs(dir.path());
+
+        assert_eq!(registry.names(), vec!["english", "french"]);
+    }
+
+    #[test]
+    fn test_names_are_sorted() {
+        let registry = TrigramRegistry::with_defaults();
+        assert_eq!(registry.names(), vec!["english", "french"]);
+    }
+}
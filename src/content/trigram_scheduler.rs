@@ -0,0 +1,339 @@
+/// SM-2-style spaced repetition over a fixed set of trigrams, so drills keep
+/// resurfacing weak/rare patterns instead of cycling through them uniformly.
+/// Gives `Trigram::frequency` a second consumer beyond weighted sampling: it
+/// now also nudges which trigram comes up next.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::trigram::Trigram;
+
+/// Ease factor assigned to a trigram that has never been reviewed
+const INITIAL_EASE: f32 = 2.5;
+/// Ease factor never drops below this floor, however many weak reps accumulate
+const MIN_EASE: f32 = 1.3;
+/// How many sessions of priority a fully common (frequency = 1.0) trigram
+/// is nudged ahead by, so a new user starts on common patterns even when
+/// nothing is strictly overdue yet
+const FREQUENCY_PRIORITY_BOOST: f64 = 0.5;
+
+/// Per-trigram SM-2 review state: ease factor, repetition count, and the
+/// current interval (in sessions), plus the session number it's next due at.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrigramReviewState {
+    pub ef: f32,
+    pub n: u32,
+    pub i: u32,
+    /// Session count this trigram is next due at; lower means more urgent
+    pub due_at: u64,
+    /// Copy of the trigram's static corpus frequency, carried along so
+    /// `TrigramScheduler::next_trigram` can blend it into due-date ordering
+    /// without needing the original `&[Trigram]` slice at pick time
+    frequency: f32,
+}
+
+impl TrigramReviewState {
+    /// Seed state from a trigram's corpus frequency: commoner trigrams start
+    /// due sooner, so early sessions bias toward the patterns worth
+    /// practicing most.
+    fn seeded(frequency: f32) -> Self {
+        Self {
+            ef: INITIAL_EASE,
+            n: 0,
+            i: 1,
+            due_at: ((1.0 - frequency) * 10.0).round() as u64,
+            frequency,
+        }
+    }
+
+    /// Grade one drill rep against a quality score `q` in `0..=5` (see
+    /// `quality_from_performance`), following the standard SM-2 update: a
+    /// weak rep (`q < 3`) resets the repetition count and interval back to
+    /// the start; a good rep grows the interval (`1` then `6` then
+    /// `round(i * ef)`) and nudges the ease factor by the book's formula,
+    /// floored at `MIN_EASE`.
+    fn grade(&mut self, current_session: u64, q: u8) {
+        let q = q.min(5) as f32;
+
+        if q < 3.0 {
+            self.n = 0;
+            self.i = 1;
+        } else {
+            self.n += 1;
+            self.i = match self.n {
+                1 => 1,
+                2 => 6,
+                _ => (self.i as f32 * self.ef).round() as u32,
+            };
+        }
+
+        self.ef = (self.ef + (0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02))).max(MIN_EASE);
+        self.due_at = current_session + self.i as u64;
+    }
+}
+
+/// Map a drill rep's accuracy and pace on one trigram to an SM-2 quality
+/// score `q` in `0..=5`: any error caps the score at "hard but correct"
+/// territory, and a clean rep is graded on how its pace compared to the
+/// user's own target (`speed_ratio` = achieved speed / target speed, so
+/// `1.0` means "right on pace").
+pub fn quality_from_performance(error_rate: f64, speed_ratio: f64) -> u8 {
+    if error_rate > 0.5 {
+        0
+    } else if error_rate > 0.0 {
+        2
+    } else if speed_ratio < 0.7 {
+        3
+    } else if speed_ratio < 1.0 {
+        4
+    } else {
+        5
+    }
+}
+
+/// Spaced-repetition scheduler over a fixed set of trigram patterns. Wraps
+/// per-trigram `TrigramReviewState`, persisted to disk (see `load`/`save`)
+/// so long-term weaknesses are tracked across sessions rather than reset
+/// every time the drill starts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrigramScheduler {
+    states: HashMap<String, TrigramReviewState>,
+    session_count: u64,
+}
+
+impl TrigramScheduler {
+    /// Build a scheduler seeded from `trigrams`, each pattern's initial
+    /// priority derived from its `frequency`. Patterns already tracked by
+    /// `existing` (e.g. loaded from disk) keep their saved state instead of
+    /// being reseeded, so switching corpora doesn't erase prior progress on
+    /// patterns that still appear. Entries for patterns no longer in
+    /// `trigrams` are dropped, so a stale pattern from a previous corpus can
+    /// never be handed back out by `next_trigram`.
+    pub fn new(trigrams: &[Trigram], existing: TrigramScheduler) -> Self {
+        let mut old_states = existing.states;
+        let mut states = HashMap::with_capacity(trigrams.len());
+
+        for trigram in trigrams {
+            // `.entry` rather than a plain insert: if `trigrams` repeats a
+            // pattern, only the first occurrence should claim the restored
+            // state, so a later duplicate can't clobber it with a fresh seed.
+            states
+                .entry(trigram.pattern.clone())
+                .or_insert_with(|| {
+                    old_states
+                        .remove(&trigram.pattern)
+                        .unwrap_or_else(|| TrigramReviewState::seeded(trigram.frequency))
+                });
+        }
+
+        Self {
+            states,
+            session_count: existing.session_count,
+        }
+    }
+
+    /// Record one drill session's rep for `pattern`, grading it by `q` (see
+    /// `quality_from_performance`). Unknown patterns are ignored.
+    pub fn record_rep(&mut self, pattern: &str, q: u8) {
+        self.session_count += 1;
+        let session_count = self.session_count;
+        if let Some(state) = self.states.get_mut(pattern) {
+            state.grade(session_count, q);
+        }
+    }
+
+    /// Pick the next trigram to drill: the lowest-priority-score pattern,
+    /// where priority blends the literal `due_at` session number with a
+    /// small boost proportional to the trigram's static frequency, so a
+    /// common pattern can edge out a rare one that isn't overdue by much
+    /// yet. Ties are broken alphabetically for determinism.
+    pub fn next_trigram(&self) -> Option<&str> {
+        self.states
+            .iter()
+            .min_by(|(pattern_a, a), (pattern_b, b)| {
+                let score_a = a.due_at as f64 - a.frequency as f64 * FREQUENCY_PRIORITY_BOOST;
+                let score_b = b.due_at as f64 - b.frequency as f64 * FREQUENCY_PRIORITY_BOOST;
+                score_a
+                    .partial_cmp(&score_b)
+                    .unwrap()
+                    .then_with(|| pattern_a.cmp(pattern_b))
+            })
+            .map(|(pattern, _)| pattern.as_str())
+    }
+
+    /// Load scheduler state from `path`, or start fresh if it's missing or
+    /// unreadable (a corrupt/absent file just means "no history yet", not a
+    /// fatal error).
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist scheduler state to `path`, writing to a sibling temp file and
+    /// renaming it into place so a crash mid-write can't truncate it.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize trigram scheduler state: {}", e),
+            )
+        })?;
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_trigrams() -> Vec<Trigram> {
+        vec![
+            Trigram::new("the", 1.00, &["the"]),
+            Trigram::new("qui", 0.70, &["quick"]),
+        ]
+    }
+
+    #[test]
+    fn test_higher_frequency_trigram_is_due_sooner() {
+        let scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        assert!(scheduler.states["the"].due_at < scheduler.states["qui"].due_at);
+    }
+
+    #[test]
+    fn test_quality_from_performance_perfect_and_fast_is_five() {
+        assert_eq!(quality_from_performance(0.0, 1.2), 5);
+    }
+
+    #[test]
+    fn test_quality_from_performance_correct_but_slow_is_lower() {
+        assert_eq!(quality_from_performance(0.0, 0.5), 3);
+    }
+
+    #[test]
+    fn test_quality_from_performance_any_error_caps_below_three() {
+        assert_eq!(quality_from_performance(0.2, 1.5), 2);
+        assert_eq!(quality_from_performance(0.8, 1.5), 0);
+    }
+
+    #[test]
+    fn test_good_rep_grows_interval_one_then_six_then_ease_scaled() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+
+        scheduler.record_rep("the", 5);
+        assert_eq!(scheduler.states["the"].n, 1);
+        assert_eq!(scheduler.states["the"].i, 1);
+
+        scheduler.record_rep("the", 5);
+        assert_eq!(scheduler.states["the"].n, 2);
+        assert_eq!(scheduler.states["the"].i, 6);
+
+        scheduler.record_rep("the", 5);
+        assert_eq!(scheduler.states["the"].n, 3);
+        let expected_ease = INITIAL_EASE + 3.0 * (0.1 - 0.0 * 0.08);
+        let expected_i = (6.0 * (INITIAL_EASE + 2.0 * (0.1 - 0.0 * 0.08))).round() as u32;
+        assert_eq!(scheduler.states["the"].i, expected_i);
+        assert!((scheduler.states["the"].ef - expected_ease).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_weak_rep_resets_repetition_count_and_interval() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        scheduler.record_rep("the", 5);
+        scheduler.record_rep("the", 5);
+        scheduler.record_rep("the", 1); // quality below 3: reset
+
+        let state = &scheduler.states["the"];
+        assert_eq!(state.n, 0);
+        assert_eq!(state.i, 1);
+    }
+
+    #[test]
+    fn test_ease_never_drops_below_floor() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        for _ in 0..50 {
+            scheduler.record_rep("the", 0);
+        }
+        assert_eq!(scheduler.states["the"].ef, MIN_EASE);
+    }
+
+    #[test]
+    fn test_next_trigram_is_none_when_empty() {
+        let scheduler = TrigramScheduler::new(&[], TrigramScheduler::default());
+        assert_eq!(scheduler.next_trigram(), None);
+    }
+
+    #[test]
+    fn test_next_trigram_returns_a_known_pattern() {
+        let scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        let next = scheduler.next_trigram().unwrap();
+        assert!(next == "the" || next == "qui");
+    }
+
+    #[test]
+    fn test_loading_existing_state_preserves_progress() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        scheduler.record_rep("the", 5);
+        scheduler.record_rep("the", 5);
+
+        let reseeded = TrigramScheduler::new(&sample_trigrams(), scheduler.clone());
+        assert_eq!(reseeded.states["the"].n, 2);
+    }
+
+    #[test]
+    fn test_reseeding_drops_patterns_absent_from_new_corpus() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        scheduler.record_rep("qui", 5);
+
+        let english_only = vec![Trigram::new("the", 1.00, &["the"])];
+        let reseeded = TrigramScheduler::new(&english_only, scheduler);
+
+        assert!(!reseeded.states.contains_key("qui"));
+        assert!(reseeded.states.contains_key("the"));
+    }
+
+    #[test]
+    fn test_duplicate_pattern_in_new_corpus_does_not_clobber_restored_state() {
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        scheduler.record_rep("the", 5);
+
+        let with_duplicate = vec![
+            Trigram::new("the", 1.00, &["the"]),
+            Trigram::new("the", 1.00, &["theme"]),
+        ];
+        let reseeded = TrigramScheduler::new(&with_duplicate, scheduler);
+
+        assert_eq!(reseeded.states["the"].n, 1);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trips() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("trigram_scheduler.json");
+
+        let mut scheduler = TrigramScheduler::new(&sample_trigrams(), TrigramScheduler::default());
+        scheduler.record_rep("the", 5);
+        scheduler.save(&path).unwrap();
+
+        let loaded = TrigramScheduler::load(&path);
+        assert_eq!(loaded.states["the"].n, 1);
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_fresh() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("does_not_exist.json");
+
+        let loaded = TrigramScheduler::load(&path);
+        assert!(loaded.states.is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+/// Character-level Markov word generator, restricted to a caller-supplied
+/// set of unlocked keys. Replaces a small hardcoded word list with
+/// phonotactically plausible pseudo-words so home-row lessons stay varied
+/// as the learner's key set grows.
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::bigram::Language;
+
+/// Weighted choices for what follows a given context (the previous one or
+/// two characters). Higher weight = more likely to be sampled.
+type WeightedChars = &'static [(char, u32)];
+
+/// A per-language table of conditional character transitions plus a
+/// word-length distribution, small enough to hand-author and tune by eye
+/// rather than corpus-trained.
+struct MarkovTable {
+    /// Weighted first letters of a word
+    starts: WeightedChars,
+    /// P(next | previous two chars), keyed by the two-char context
+    trigram_transitions: &'static [(&'static str, WeightedChars)],
+    /// P(next | previous one char), used for a word's second character and
+    /// as a fallback when a two-char context has no entry
+    bigram_transitions: &'static [(char, WeightedChars)],
+    /// Weighted word lengths
+    lengths: &'static [(usize, u32)],
+}
+
+const ENGLISH_TABLE: MarkovTable = MarkovTable {
+    starts: &[
+        ('t', 16),
+        ('a', 12),
+        ('s', 10),
+        ('c', 9),
+        ('p', 8),
+        ('b', 7),
+        ('m', 6),
+        ('d', 6),
+        ('w', 5),
+        ('f', 5),
+        ('r', 5),
+        ('l', 4),
+        ('h', 4),
+        ('g', 3),
+        ('n', 3),
+    ],
+    trigram_transitions: &[
+        ("th", &[('e', 20), ('a', 6), ('i', 5), ('o', 4)]),
+        ("he", &[('r', 8), ('n', 6), ('a', 5), ('l', 3)]),
+        ("in", &[('g', 10), ('t', 6), ('e', 4), ('d', 3)]),
+        ("er", &[('s', 8), ('e', 5), ('a', 4), ('y', 3)]),
+        ("an", &[('d', 9), ('t', 5), ('c', 3), ('s', 3)]),
+        ("re", &[('s', 6), ('a', 5), ('d', 4), ('n', 3)]),
+        ("on", &[('s', 7), ('e', 5), ('g', 3), ('d', 3)]),
+        ("at", &[('e', 7), ('i', 5), ('o', 3)]),
+        ("en", &[('t', 7), ('d', 5), ('c', 3)]),
+        ("ti", &[('o', 9), ('n', 5), ('c', 2)]),
+    ],
+    bigram_transitions: &[
+        ('t', &[('h', 14), ('o', 8), ('i', 7), ('e', 6), ('a', 5)]),
+        ('a', &[('n', 10), ('t', 8), ('r', 7), ('l', 6), ('s', 5)]),
+        ('s', &[('t', 9), ('e', 7), ('i', 5), ('o', 4), ('h', 3)]),
+        ('c', &[('o', 8), ('a', 6), ('e', 5), ('h', 4)]),
+        ('p', &[('r', 7), ('e', 6), ('a', 5), ('o', 4)]),
+        ('b', &[('e', 7), ('a', 6), ('o', 4), ('l', 3)]),
+        ('m', &[('e', 7), ('a', 6), ('o', 4), ('i', 3)]),
+        ('d', &[('e', 7), ('a', 5), ('i', 4), ('o', 3)]),
+        ('w', &[('h', 8), ('a', 6), ('i', 4), ('o', 3)]),
+        ('f', &[('o', 6), ('i', 5), ('a', 4), ('e', 4)]),
+        ('r', &[('e', 8), ('a', 6), ('i', 5), ('o', 4)]),
+        ('l', &[('e', 7), ('a', 5), ('i', 4), ('o', 3)]),
+        ('h', &[('e', 9), ('a', 6), ('i', 5)]),
+        ('g', &[('e', 6), ('a', 5), ('o', 4), ('r', 3)]),
+        ('n', &[('e', 6), ('d', 5), ('g', 4), ('t', 3)]),
+        ('e', &[('r', 8), ('n', 6), ('s', 5), ('d', 4)]),
+        ('i', &[('n', 9), ('t', 6), ('s', 4), ('o', 3)]),
+        ('o', &[('n', 8), ('r', 6), ('u', 5), ('m', 4)]),
+        ('u', &[('r', 6), ('t', 5), ('s', 4), ('n', 3)]),
+    ],
+    lengths: &[(3, 4), (4, 8), (5, 10), (6, 8), (7, 5), (8, 3)],
+};
+
+const FRENCH_TABLE: MarkovTable = MarkovTable {
+    starts: &[
+        ('d', 12),
+        ('l', 11),
+        ('c', 9),
+        ('p', 8),
+        ('s', 8),
+        ('e', 7),
+        ('a', 6),
+        ('m', 6),
+        ('r', 5),
+        ('t', 5),
+        ('v', 4),
+        ('f', 4),
+        ('b', 3),
+        ('n', 3),
+    ],
+    trigram_transitions: &[
+        ("es", &[('t', 8), ('s', 4), ('p', 3)]),
+        ("le", &[('s', 7), ('r', 5), ('c', 3), ('m', 3)]),
+        ("de", &[('s', 8), ('p', 5), ('v', 3), ('m', 3)]),
+        ("re", &[('s', 6), ('n', 5), ('m', 4), ('v', 3)]),
+        ("en", &[('t', 9), ('c', 4), ('s', 3)]),
+        ("on", &[('s', 7), ('t', 5), ('n', 3)]),
+        ("an", &[('t', 7), ('s', 5), ('c', 3)]),
+        ("ou", &[('r', 7), ('s', 5), ('v', 3)]),
+        ("ai", &[('s', 7), ('t', 5), ('n', 3)]),
+        ("qu", &[('e', 12), ('i', 6)]),
+    ],
+    bigram_transitions: &[
+        ('d', &[('e', 12), ('a', 6), ('i', 5), ('o', 4)]),
+        ('l', &[('e', 10), ('a', 7), ('o', 5), ('u', 4)]),
+        ('c', &[('o', 7), ('a', 6), ('e', 5), ('h', 3)]),
+        ('p', &[('a', 7), ('e', 6), ('o', 5), ('r', 4)]),
+        ('s', &[('e', 8), ('o', 5), ('a', 4), ('u', 3)]),
+        ('e', &[('s', 7), ('n', 6), ('r', 5), ('t', 4)]),
+        ('a', &[('n', 7), ('i', 6), ('u', 5), ('v', 3)]),
+        ('m', &[('e', 7), ('a', 6), ('o', 4), ('i', 3)]),
+        ('r', &[('e', 8), ('a', 5), ('o', 4), ('i', 3)]),
+        ('t', &[('e', 7), ('i', 5), ('a', 4), ('o', 3)]),
+        ('v', &[('e', 7), ('i', 5), ('a', 4), ('o', 3)]),
+        ('f', &[('a', 6), ('e', 5), ('o', 4), ('i', 3)]),
+        ('b', &[('l', 6), ('e', 5), ('a', 4), ('o', 3)]),
+        ('n', &[('e', 6), ('t', 5), ('o', 4), ('s', 3)]),
+        ('o', &[('n', 8), ('u', 6), ('i', 4), ('r', 3)]),
+        ('u', &[('r', 7), ('n', 5), ('s', 4), ('e', 3)]),
+        ('i', &[('s', 6), ('e', 5), ('o', 4), ('n', 3)]),
+    ],
+    lengths: &[(3, 4), (4, 9), (5, 10), (6, 7), (7, 4), (8, 2)],
+};
+
+fn table_for(language: Language) -> &'static MarkovTable {
+    match language {
+        Language::English => &ENGLISH_TABLE,
+        Language::French => &FRENCH_TABLE,
+    }
+}
+
+/// Sample one weighted choice from `choices`, restricted to characters in
+/// `allowed`. Returns `None` if no candidate is allowed.
+fn sample_allowed(rng: &mut StdRng, choices: WeightedChars, allowed: &[char]) -> Option<char> {
+    let filtered: Vec<(char, u32)> = choices
+        .iter()
+        .filter(|(c, _)| allowed.contains(c))
+        .copied()
+        .collect();
+
+    if filtered.is_empty() {
+        return None;
+    }
+
+    let total: u32 = filtered.iter().map(|(_, w)| w).sum();
+    let mut target = rng.gen_range(0..total);
+
+    for (c, weight) in filtered {
+        if target < weight {
+            return Some(c);
+        }
+        target -= weight;
+    }
+
+    None
+}
+
+/// Sample a word length from `table`'s distribution
+fn sample_length(rng: &mut StdRng, table: &MarkovTable) -> usize {
+    let total: u32 = table.lengths.iter().map(|(_, w)| w).sum();
+    let mut target = rng.gen_range(0..total);
+
+    for &(length, weight) in table.lengths {
+        if target < weight {
+            return length;
+        }
+        target -= weight;
+    }
+
+    table.lengths.last().map(|(length, _)| *length).unwrap_or(4)
+}
+
+/// Generate one pseudo-word using `language`'s Markov table, sampling only
+/// characters present in `allowed_keys`. Returns `None` if `allowed_keys`
+/// can't even supply a starting letter, or if generation stalls before
+/// reaching the two-character minimum (callers should fall back to a
+/// repetition drill in that case).
+pub fn generate_word(
+    rng: &mut StdRng,
+    language: Language,
+    allowed_keys: &[char],
+) -> Option<String> {
+    let table = table_for(language);
+    let target_length = sample_length(rng, table);
+
+    let first = sample_allowed(rng, table.starts, allowed_keys)?;
+    let mut word = String::new();
+    word.push(first);
+
+    while word.chars().count() < target_length {
+        let chars: Vec<char> = word.chars().collect();
+        let context_len = chars.len();
+
+        let next = if context_len >= 2 {
+            let context: String = chars[context_len - 2..].iter().collect();
+            table
+                .trigram_transitions
+                .iter()
+                .find(|(pattern, _)| *pattern == context)
+                .and_then(|(_, choices)| sample_allowed(rng, choices, allowed_keys))
+                .or_else(|| {
+                    let prev = chars[context_len - 1];
+                    table
+                        .bigram_transitions
+                        .iter()
+                        .find(|(c, _)| *c == prev)
+                        .and_then(|(_, choices)| sample_allowed(rng, choices, allowed_keys))
+                })
+        } else {
+            let prev = chars[context_len - 1];
+            table
+                .bigram_transitions
+                .iter()
+                .find(|(c, _)| *c == prev)
+                .and_then(|(_, choices)| sample_allowed(rng, choices, allowed_keys))
+        };
+
+        match next {
+            Some(c) => word.push(c),
+            None => break,
+        }
+    }
+
+    if word.chars().count() < 2 {
+        None
+    } else {
+        Some(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate_word_only_uses_allowed_keys() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let allowed = ['t', 'h', 'e', 'a', 'n', 'd', 's', 'o'];
+
+        for _ in 0..50 {
+            if let Some(word) = generate_word(&mut rng, Language::English, &allowed) {
+                assert!(
+                    word.chars().all(|c| allowed.contains(&c)),
+                    "word {word} used a locked key"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_word_none_when_start_letters_unavailable() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let allowed = ['x', 'z', 'q'];
+        assert_eq!(generate_word(&mut rng, Language::English, &allowed), None);
+    }
+
+    #[test]
+    fn test_generate_word_is_deterministic_for_a_given_seed() {
+        let allowed = ['t', 'h', 'e', 'a', 'n', 'd', 's', 'o', 'r', 'i', 'n', 'g'];
+
+        let mut rng_a = StdRng::seed_from_u64(42);
+        let mut rng_b = StdRng::seed_from_u64(42);
+
+        assert_eq!(
+            generate_word(&mut rng_a, Language::English, &allowed),
+            generate_word(&mut rng_b, Language::English, &allowed)
+        );
+    }
+
+    #[test]
+    fn test_generate_word_french_only_uses_allowed_keys() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let allowed = ['d', 'e', 's', 'l', 'a', 'n', 't', 'o', 'u', 'r'];
+
+        for _ in 0..50 {
+            if let Some(word) = generate_word(&mut rng, Language::French, &allowed) {
+                assert!(
+                    word.chars().all(|c| allowed.contains(&c)),
+                    "word {word} used a locked key"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+/// External/bundled word lists for trigram word and mixed modes
+///
+/// `Trigram::examples` only carries a handful of words per pattern, so long
+/// lessons repeat quickly. A `WordlistIndex` draws from a much larger pool —
+/// bundled per-language behind cargo features (the way `tiny-bip39` gates its
+/// language wordlists), or supplied by the user as a plain text file, one
+/// word per line — indexed by which target trigram(s) each word contains.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::bigram::Language;
+
+/// Maximum number of matching words kept per trigram pattern, so a common
+/// pattern like "the" doesn't pull in the entire wordlist
+const MAX_WORDS_PER_TRIGRAM: usize = 25;
+
+#[cfg(feature = "wordlist-en")]
+const BUNDLED_ENGLISH_WORDS: &[&str] = &[
+    "the",
+    "and",
+    "that",
+    "have",
+    "for",
+    "not",
+    "with",
+    "you",
+    "this",
+    "but",
+    "his",
+    "from",
+    "they",
+    "she",
+    "which",
+    "their",
+    "them",
+    "there",
+    "then",
+    "other",
+    "into",
+    "more",
+    "these",
+    "could",
+    "would",
+    "should",
+    "about",
+    "after",
+    "great",
+    "little",
+    "through",
+    "something",
+    "another",
+    "because",
+    "between",
+    "without",
+    "together",
+    "important",
+    "different",
+];
+
+#[cfg(feature = "wordlist-fr")]
+const BUNDLED_FRENCH_WORDS: &[&str] = &[
+    "les", "des", "une", "dans", "pour", "qui", "que", "sur", "avec", "mais", "plus", "tout",
+    "même", "bien", "faire", "comme", "alors", "encore", "toujours", "pendant", "pourquoi",
+    "chaque", "quelque", "comment", "ensemble", "entre", "années", "journée", "nouvelle",
+];
+
+/// A pool of real words, either bundled for a language or loaded from a file
+pub struct Wordlist {
+    words: Vec<String>,
+}
+
+impl Wordlist {
+    /// Load a plain text wordlist, one word per line, blank lines ignored
+    pub fn from_path(path: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let words = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_lowercase())
+            .collect();
+
+        Ok(Self { words })
+    }
+
+    /// The compiled-in wordlist for a language, if its cargo feature is enabled
+    pub fn bundled(language: Language) -> Option<Self> {
+        #[cfg(feature = "wordlist-en")]
+        if language == Language::English {
+            return Some(Self {
+                words: BUNDLED_ENGLISH_WORDS
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect(),
+            });
+        }
+
+        #[cfg(feature = "wordlist-fr")]
+        if language == Language::French {
+            return Some(Self {
+                words: BUNDLED_FRENCH_WORDS.iter().map(|w| w.to_string()).collect(),
+            });
+        }
+
+        #[allow(unreachable_code)]
+        {
+            let _ = language;
+            None
+        }
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Enumerate the word-list packs available in `dir`: every `.txt` file's
+    /// stem, sorted. A user drops e.g. `programming.txt` or `en-1k.txt` in
+    /// there and it shows up as pack name `"programming"`/`"en-1k"`, loadable
+    /// via `Wordlist::from_path`. Returns an empty list (not an error) if
+    /// `dir` doesn't exist yet.
+    pub fn discover_packs(dir: &Path) -> io::Result<Vec<String>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut packs: Vec<String> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter_map(|path| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .collect();
+
+        packs.sort();
+        Ok(packs)
+    }
+}
+
+/// Words indexed by which trigram pattern they contain, for fast lookup
+/// during drill generation
+pub struct WordlistIndex {
+    by_trigram: HashMap<String, Vec<String>>,
+}
+
+impl WordlistIndex {
+    /// Build an index restricted to `trigram_patterns`, keeping at most
+    /// `MAX_WORDS_PER_TRIGRAM` matching words per pattern
+    pub fn build(wordlist: &Wordlist, trigram_patterns: &[&str]) -> Self {
+        let mut by_trigram: HashMap<String, Vec<String>> = HashMap::new();
+
+        for word in wordlist.words() {
+            for &pattern in trigram_patterns {
+                if word.contains(pattern) {
+                    let matches = by_trigram.entry(pattern.to_string()).or_default();
+                    if matches.len() < MAX_WORDS_PER_TRIGRAM {
+                        matches.push(word.clone());
+                    }
+                }
+            }
+        }
+
+        Self { by_trigram }
+    }
+
+    /// Words containing `pattern`, in insertion order, or `None` if the
+    /// wordlist had no matches (callers should fall back to hardcoded examples)
+    pub fn words_for(&self, pattern: &str) -> Option<&[String]> {
+        self.by_trigram.get(pattern).map(|words| words.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_from_path_loads_words() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "hello\nworld\n\n  spaced  \nTHE").unwrap();
+
+        let wordlist = Wordlist::from_path(file.path()).unwrap();
+
+        assert_eq!(wordlist.words().len(), 4);
+        assert!(wordlist.words().contains(&"hello".to_string()));
+        assert!(wordlist.words().contains(&"the".to_string()));
+    }
+
+    #[test]
+    fn test_index_groups_by_trigram() {
+        let wordlist = Wordlist {
+            words: vec![
+                "keyboard".to_string(),
+                "monkey".to_string(),
+                "tree".to_string(),
+            ],
+        };
+        let index = WordlistIndex::build(&wordlist, &["key", "tre"]);
+
+        let key_matches = index.words_for("key").unwrap();
+        assert!(key_matches.contains(&"keyboard".to_string()));
+        assert!(key_matches.contains(&"monkey".to_string()));
+
+        let tre_matches = index.words_for("tre").unwrap();
+        assert!(tre_matches.contains(&"tree".to_string()));
+    }
+
+    #[test]
+    fn test_index_missing_pattern_returns_none() {
+        let wordlist = Wordlist {
+            words: vec!["hello".to_string()],
+        };
+        let index = WordlistIndex::build(&wordlist, &["key"]);
+
+        assert!(index.words_for("key").is_none());
+    }
+
+    #[test]
+    fn test_index_caps_matches_per_trigram() {
+        let words: Vec<String> = (0..50).map(|i| format!("key{}", i)).collect();
+        let wordlist = Wordlist { words };
+        let index = WordlistIndex::build(&wordlist, &["key"]);
+
+        assert_eq!(index.words_for("key").unwrap().len(), MAX_WORDS_PER_TRIGRAM);
+    }
+
+    #[test]
+    fn test_discover_packs_lists_txt_file_stems_sorted() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("programming.txt"), "fn\nlet\n").unwrap();
+        fs::write(dir.path().join("en-1k.txt"), "the\nand\n").unwrap();
+        fs::write(dir.path().join("notes.md"), "not a wordlist").unwrap();
+
+        let packs = Wordlist::discover_packs(dir.path()).unwrap();
+
+        assert_eq!(packs, vec!["en-1k".to_string(), "programming".to_string()]);
+    }
+
+    #[test]
+    fn test_discover_packs_missing_dir_is_empty_not_an_error() {
+        let packs = Wordlist::discover_packs(Path::new("/no/such/directory")).unwrap();
+        assert!(packs.is_empty());
+    }
+}
@@ -0,0 +1,196 @@
+/// User-configurable keybindings, loaded from `keys.toml` in the config directory
+/// (see `Storage::keybindings_path`). Maps crokey-style key specs ("ctrl-h",
+/// "alt-up") to named actions, so `App::handle_key_event` dispatches through a
+/// lookup table instead of literal `KeyCode`/`KeyModifiers` matches.
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Named actions the keybinding table can dispatch to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Back,
+    Confirm,
+    MenuUp,
+    MenuDown,
+    ToggleKeyboard,
+    ToggleFingerColors,
+    ToggleHeatmap,
+    Restart,
+}
+
+/// On-disk keybinding config: action name -> one or more human-readable key specs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeybindingConfig {
+    #[serde(flatten)]
+    pub bindings: HashMap<Action, Vec<String>>,
+}
+
+impl KeybindingConfig {
+    /// The bindings shipped with the crate, used when no user override exists
+    /// (or as a base that a partial user override is layered on top of)
+    pub fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(Action::Quit, vec!["q".to_string()]);
+        bindings.insert(Action::Back, vec!["esc".to_string()]);
+        bindings.insert(Action::Confirm, vec!["enter".to_string(), "space".to_string()]);
+        bindings.insert(Action::MenuUp, vec!["up".to_string(), "k".to_string()]);
+        bindings.insert(Action::MenuDown, vec!["down".to_string(), "j".to_string()]);
+        bindings.insert(Action::ToggleKeyboard, vec!["tab".to_string()]);
+        bindings.insert(Action::ToggleFingerColors, vec!["ctrl-f".to_string()]);
+        bindings.insert(Action::ToggleHeatmap, vec!["ctrl-h".to_string()]);
+        bindings.insert(Action::Restart, vec!["r".to_string()]);
+        Self { bindings }
+    }
+}
+
+/// Parsed, normalized keybinding table: `(KeyCode, KeyModifiers)` -> `Action`
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl Keymap {
+    /// Build the keymap from the shipped defaults, with any action the user
+    /// overrides in `keys.toml` replacing the default spec(s) for that action.
+    /// Gracefully falls back to the defaults when the file is missing or invalid.
+    pub fn load(user_config_path: &Path) -> Self {
+        let mut config = KeybindingConfig::defaults();
+
+        if let Ok(content) = fs::read_to_string(user_config_path) {
+            if let Ok(overrides) = toml::from_str::<KeybindingConfig>(&content) {
+                config.bindings.extend(overrides.bindings);
+            }
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &KeybindingConfig) -> Self {
+        let mut bindings = HashMap::new();
+        for (action, specs) in &config.bindings {
+            for spec in specs {
+                if let Some(key) = parse_key_spec(spec) {
+                    bindings.insert(key, *action);
+                }
+            }
+        }
+        Self { bindings }
+    }
+
+    /// Look up the action bound to a key event, if any
+    pub fn action_for(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&KeybindingConfig::defaults())
+    }
+}
+
+/// Parse a crokey-style key spec like `"ctrl-h"`, `"alt-up"`, `"q"`, `"enter"`
+/// into a normalized `(KeyCode, KeyModifiers)` pair
+fn parse_key_spec(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut parts: Vec<&str> = spec.split('-').collect();
+    let key_part = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            _ => return None,
+        }
+    }
+
+    let code = match key_part.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        single if single.chars().count() == 1 => {
+            let c = single.chars().next()?;
+            if modifiers.contains(KeyModifiers::SHIFT) {
+                KeyCode::Char(c.to_ascii_uppercase())
+            } else {
+                KeyCode::Char(c)
+            }
+        }
+        _ => return None,
+    };
+
+    Some((code, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_spec_plain_char() {
+        assert_eq!(parse_key_spec("q"), Some((KeyCode::Char('q'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_parse_key_spec_with_modifier() {
+        assert_eq!(
+            parse_key_spec("ctrl-h"),
+            Some((KeyCode::Char('h'), KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn test_parse_key_spec_named_key() {
+        assert_eq!(parse_key_spec("alt-up"), Some((KeyCode::Up, KeyModifiers::ALT)));
+        assert_eq!(parse_key_spec("esc"), Some((KeyCode::Esc, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_parse_key_spec_unknown_modifier_is_none() {
+        assert_eq!(parse_key_spec("meta-h"), None);
+    }
+
+    #[test]
+    fn test_default_keymap_resolves_known_actions() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action_for(KeyCode::Char('q'), KeyModifiers::NONE),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            keymap.action_for(KeyCode::Char('h'), KeyModifiers::CONTROL),
+            Some(Action::ToggleHeatmap)
+        );
+        assert_eq!(keymap.action_for(KeyCode::Char('z'), KeyModifiers::NONE), None);
+    }
+
+    #[test]
+    fn test_user_override_replaces_default_binding() {
+        let mut config = KeybindingConfig::defaults();
+        config
+            .bindings
+            .insert(Action::ToggleHeatmap, vec!["alt-h".to_string()]);
+        let keymap = Keymap::from_config(&config);
+
+        assert_eq!(
+            keymap.action_for(KeyCode::Char('h'), KeyModifiers::ALT),
+            Some(Action::ToggleHeatmap)
+        );
+        assert_eq!(
+            keymap.action_for(KeyCode::Char('h'), KeyModifiers::CONTROL),
+            None
+        );
+    }
+}
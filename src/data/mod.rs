@@ -0,0 +1,6 @@
+pub mod keybindings;
+pub mod stats;
+pub mod storage;
+
+pub use stats::{SessionRecord, Stats};
+pub use storage::{AppConfig, StatsDisplayConfig, StatsLayoutConfig, StatsPanel, Storage};
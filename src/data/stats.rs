@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime};
 
-use crate::engine::analytics::{AdaptiveAnalytics, KeyStats, SessionAnalysis};
-use crate::engine::TypingSession;
+use crate::engine::analytics::{AdaptiveAnalytics, KeyStats, SessionAnalysis, SessionAnalytics};
+use crate::engine::{SessionResult, TypingSession};
+use crate::keyboard::{CursorStyle, KeyboardLayoutKind};
 
 /// Enregistrement d'une session sauvegardée
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,26 +14,76 @@ pub struct SessionRecord {
     pub accuracy: f64,
     #[serde(with = "duration_serde")]
     pub duration: Duration,
+    /// Elapsed time (ms since the session started) of each keystroke, in order.
+    /// Drives `GhostReplay` so a later attempt at the same lesson can race this one.
+    #[serde(default)]
+    pub keystrokes: Vec<u64>,
 }
 
 impl SessionRecord {
-    pub fn new(lesson_type: String, wpm: f64, accuracy: f64, duration: Duration) -> Self {
+    pub fn new(
+        lesson_type: String,
+        wpm: f64,
+        accuracy: f64,
+        duration: Duration,
+        keystrokes: Vec<u64>,
+    ) -> Self {
         Self {
-            timestamp: chrono::Utc::now().to_rfc3339(),
+            timestamp: format_rfc3339(SystemTime::now()),
             lesson_type,
             wpm,
             accuracy,
             duration,
+            keystrokes,
         }
     }
 }
 
+/// Format a `SystemTime` as a UTC RFC 3339 timestamp (e.g.
+/// `2024-01-01T12:30:00Z`). Hand-rolled rather than pulling in a date/time
+/// crate, since this is the only place in the app that needs one.
+fn format_rfc3339(time: SystemTime) -> String {
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let days = secs / 86_400;
+    let time_of_day = secs % 86_400;
+    let (year, month, day) = civil_from_days(days as i64);
+    let hour = time_of_day / 3_600;
+    let minute = (time_of_day % 3_600) / 60;
+    let second = time_of_day % 60;
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
+
+/// Convert a day count since the Unix epoch (1970-01-01) to a proleptic
+/// Gregorian (year, month, day), via Howard Hinnant's `civil_from_days`.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
+    let year = if m <= 2 { y + 1 } else { y };
+    (year, m, d)
+}
+
 /// Stats globales de l'utilisateur
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stats {
     pub sessions: Vec<SessionRecord>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub adaptive_analytics: Option<AdaptiveAnalytics>,
+    /// Last keyboard layout the user selected, so it's restored on the next run
+    #[serde(default)]
+    pub keyboard_layout: KeyboardLayoutKind,
+    /// Last cursor style the user selected, so it's restored on the next run
+    #[serde(default)]
+    pub cursor_style: CursorStyle,
 }
 
 impl Stats {
@@ -40,6 +91,8 @@ impl Stats {
         Self {
             sessions: Vec::new(),
             adaptive_analytics: None,
+            keyboard_layout: KeyboardLayoutKind::default(),
+            cursor_style: CursorStyle::default(),
         }
     }
 
@@ -71,12 +124,23 @@ impl Stats {
     }
 
     /// Update adaptive analytics with session data
-    pub fn update_analytics(&mut self, session: &TypingSession, analysis: SessionAnalysis) {
+    pub fn update_analytics(
+        &mut self,
+        session: &TypingSession,
+        analysis: SessionAnalysis,
+        result: &SessionResult,
+        lesson_type: String,
+    ) {
         // Get or create adaptive analytics
         let analytics = self
             .adaptive_analytics
             .get_or_insert_with(AdaptiveAnalytics::default);
 
+        // Keys mistyped in this session, and keys that came out of it mastered
+        // or proficient, for the session's `SessionAnalytics` entry below.
+        let mut weak_keys = Vec::new();
+        let mut improved_keys = Vec::new();
+
         // Update per-key statistics
         for (key, perf) in analysis.key_performance {
             let key_stats = analytics
@@ -88,9 +152,25 @@ impl Stats {
             key_stats.correct_attempts += perf.correct_attempts;
             key_stats.error_count += perf.errors.len();
 
-            // Update timing (sum all timings)
+            if !perf.errors.is_empty() {
+                weak_keys.push(key);
+            }
+
+            // Update timing (sum all timings, and keep the raw samples for KDE)
             let total_time_ms: u64 = perf.timings.iter().map(|d| d.as_millis() as u64).sum();
             key_stats.total_time_ms += total_time_ms;
+            key_stats
+                .timing_samples_ms
+                .extend(perf.timings.iter().map(|d| d.as_millis() as u64));
+
+            // Update dwell time (kitty protocol only, empty otherwise)
+            let total_dwell_ms: u64 = perf
+                .dwell_timings
+                .iter()
+                .map(|d| d.as_millis() as u64)
+                .sum();
+            key_stats.total_dwell_ms += total_dwell_ms;
+            key_stats.dwell_samples += perf.dwell_timings.len();
 
             // Update mistype map
             for error_char in perf.errors {
@@ -99,6 +179,14 @@ impl Stats {
 
             key_stats.last_practiced = Some(SystemTime::now());
             key_stats.update_mastery_level();
+
+            if matches!(
+                key_stats.mastery_level,
+                crate::engine::analytics::MasteryLevel::Proficient
+                    | crate::engine::analytics::MasteryLevel::Mastered
+            ) {
+                improved_keys.push(key);
+            }
         }
 
         // Update per-bigram statistics
@@ -120,6 +208,17 @@ impl Stats {
         // Update global counters
         analytics.total_sessions += 1;
         analytics.total_keystrokes += session.inputs.len();
+
+        // Record this session's headline numbers for the performance trend chart
+        analytics.session_history.push(SessionAnalytics {
+            timestamp: SystemTime::now(),
+            lesson_type,
+            wpm: result.wpm,
+            accuracy: result.accuracy,
+            duration_secs: result.duration.as_secs(),
+            weak_keys,
+            improved_keys,
+        });
     }
 }
 
@@ -165,8 +264,13 @@ mod tests {
     #[test]
     fn test_stats_add_session() {
         let mut stats = Stats::new();
-        let record =
-            SessionRecord::new("HomeRow-1".to_string(), 45.0, 95.0, Duration::from_secs(60));
+        let record = SessionRecord::new(
+            "HomeRow-1".to_string(),
+            45.0,
+            95.0,
+            Duration::from_secs(60),
+            Vec::new(),
+        );
         stats.add_session(record);
         assert_eq!(stats.session_count(), 1);
     }
@@ -179,12 +283,14 @@ mod tests {
             40.0,
             90.0,
             Duration::from_secs(60),
+            Vec::new(),
         ));
         stats.add_session(SessionRecord::new(
             "HomeRow-2".to_string(),
             60.0,
             100.0,
             Duration::from_secs(60),
+            Vec::new(),
         ));
 
         assert_eq!(stats.average_wpm(), 50.0);
@@ -198,6 +304,7 @@ mod tests {
             45.5,
             97.3,
             Duration::from_secs(120),
+            vec![120, 340, 560],
         );
 
         let json = serde_json::to_string(&record).unwrap();
@@ -206,5 +313,33 @@ mod tests {
         assert_eq!(deserialized.lesson_type, "HomeRow-1");
         assert!((deserialized.wpm - 45.5).abs() < 0.01);
         assert_eq!(deserialized.duration, Duration::from_secs(120));
+        assert_eq!(deserialized.keystrokes, vec![120, 340, 560]);
+    }
+
+    #[test]
+    fn test_session_record_keystrokes_default_on_missing_field() {
+        // Records saved before this field existed have no `keystrokes` key
+        let json = r#"{"timestamp":"2024-01-01T00:00:00Z","lesson_type":"HomeRow-1","wpm":40.0,"accuracy":90.0,"duration":60000}"#;
+        let record: SessionRecord = serde_json::from_str(json).unwrap();
+        assert!(record.keystrokes.is_empty());
+    }
+
+    #[test]
+    fn test_format_rfc3339_known_epoch_offsets() {
+        assert_eq!(
+            format_rfc3339(SystemTime::UNIX_EPOCH),
+            "1970-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            format_rfc3339(SystemTime::UNIX_EPOCH + Duration::from_secs(86_400 + 3_661)),
+            "1970-01-02T01:01:01Z"
+        );
+    }
+
+    #[test]
+    fn test_civil_from_days_handles_leap_years() {
+        // 2024-02-29 is a leap day; days since epoch computed independently.
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
     }
 }
@@ -1,7 +1,52 @@
 use super::stats::Stats;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Current on-disk schema version. Bump this and add a migration arm in
+/// `StatsDocument::into_stats` whenever `Stats`'s shape changes.
+const CURRENT_STATS_VERSION: u32 = 1;
+
+/// Versioned wrapper around `Stats` so `load` can tell an unversioned legacy
+/// file (the bare `Stats` this crate used to write directly) apart from the
+/// current schema and migrate it instead of failing.
+#[derive(Debug, Serialize, Deserialize)]
+struct StatsDocument {
+    version: u32,
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+impl StatsDocument {
+    fn current(stats: Stats) -> Self {
+        Self {
+            version: CURRENT_STATS_VERSION,
+            stats,
+        }
+    }
+
+    /// Migrate into the in-memory `Stats` shape. There is only one version
+    /// today, so this is a no-op, but it's the seam future migrations hang off.
+    fn into_stats(self) -> Stats {
+        match self.version {
+            CURRENT_STATS_VERSION => self.stats,
+            _ => self.stats,
+        }
+    }
+}
+
+/// Output format for `Storage::export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Header row followed by one line per session: lesson, wpm, accuracy,
+    /// duration in seconds, timestamp
+    Csv,
+    /// One `SessionRecord` JSON object per line
+    JsonLines,
+}
 
 /// Gestionnaire de stockage des stats
 pub struct Storage {
@@ -37,32 +82,53 @@ impl Storage {
     }
 
     /// Charger les stats depuis le fichier
+    ///
+    /// Tries the current versioned schema first, falls back to migrating a
+    /// bare unversioned `Stats` (what this crate used to write), and if the
+    /// file is corrupt beyond that, backs it up to `stats.json.bak` and
+    /// returns fresh `Stats` rather than erroring out.
     pub fn load(&self) -> io::Result<Stats> {
         if !self.file_path.exists() {
             return Ok(Stats::new());
         }
 
         let content = fs::read_to_string(&self.file_path)?;
-        let stats: Stats = serde_json::from_str(&content).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("Failed to parse stats: {}", e),
-            )
-        })?;
 
-        Ok(stats)
+        if let Ok(document) = serde_json::from_str::<StatsDocument>(&content) {
+            return Ok(document.into_stats());
+        }
+
+        if let Ok(stats) = serde_json::from_str::<Stats>(&content) {
+            return Ok(stats);
+        }
+
+        self.backup_corrupt_file()?;
+        Ok(Stats::new())
+    }
+
+    /// Copy the unparseable stats file aside so it isn't silently lost
+    fn backup_corrupt_file(&self) -> io::Result<()> {
+        let backup_path = self.file_path.with_extension("json.bak");
+        fs::rename(&self.file_path, &backup_path)
     }
 
     /// Sauvegarder les stats dans le fichier
+    ///
+    /// Writes atomically: serializes to a sibling temp file, then renames it
+    /// onto `stats.json`, so a crash mid-write can never leave a truncated file.
     pub fn save(&self, stats: &Stats) -> io::Result<()> {
-        let content = serde_json::to_string_pretty(stats).map_err(|e| {
+        let document = StatsDocument::current(stats.clone());
+        let content = serde_json::to_string_pretty(&document).map_err(|e| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
                 format!("Failed to serialize stats: {}", e),
             )
         })?;
 
-        fs::write(&self.file_path, content)?;
+        let temp_path = self.file_path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, &self.file_path)?;
+
         Ok(())
     }
 
@@ -72,6 +138,250 @@ impl Storage {
     pub fn get_path(&self) -> &PathBuf {
         &self.file_path
     }
+
+    /// Path to the user's keybinding override (`keys.toml`), if they've created one.
+    /// Lives alongside `stats.json` in the same config directory.
+    pub fn keybindings_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("keys.toml"))
+            .unwrap_or_else(|| PathBuf::from("keys.toml"))
+    }
+
+    /// Path to the user's general app config (`config.toml`), if they've
+    /// created one. Lives alongside `stats.json` in the same config directory.
+    pub fn app_config_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("config.toml"))
+            .unwrap_or_else(|| PathBuf::from("config.toml"))
+    }
+
+    /// Path to the user's custom AZERTY layout override (`azerty_layout.toml`),
+    /// if they've created one (see `AzertyLayout::from_config_file`). Lives
+    /// alongside `stats.json` in the same config directory.
+    pub fn azerty_layout_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("azerty_layout.toml"))
+            .unwrap_or_else(|| PathBuf::from("azerty_layout.toml"))
+    }
+
+    /// Directory the user drops custom word-list packs (`.txt` files) into,
+    /// enumerated by `content::wordlist::Wordlist::discover_packs`. Lives
+    /// alongside `stats.json` in the same config directory.
+    pub fn wordlists_dir(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("wordlists"))
+            .unwrap_or_else(|| PathBuf::from("wordlists"))
+    }
+
+    /// Path to the persisted `content::bigram_scheduler::BigramScheduler`
+    /// state, read/written by that module's own `load`/`save`. Lives
+    /// alongside `stats.json` in the same config directory.
+    pub fn bigram_scheduler_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("bigram_scheduler.json"))
+            .unwrap_or_else(|| PathBuf::from("bigram_scheduler.json"))
+    }
+
+    /// Path to the persisted `content::trigram_scheduler::TrigramScheduler`
+    /// state, read/written by that module's own `load`/`save`. Lives
+    /// alongside `stats.json` in the same config directory.
+    pub fn trigram_scheduler_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("trigram_scheduler.json"))
+            .unwrap_or_else(|| PathBuf::from("trigram_scheduler.json"))
+    }
+
+    /// Directory the user drops custom bigram table files (`.toml`/`.json`)
+    /// into, discovered by `content::bigram_registry::BigramRegistry::load_overrides`.
+    /// Lives alongside `stats.json` in the same config directory.
+    pub fn bigram_tables_dir(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("bigram_tables"))
+            .unwrap_or_else(|| PathBuf::from("bigram_tables"))
+    }
+
+    /// Directory the user drops custom `LanguagePack` JSON files into,
+    /// discovered by `content::trigram_registry::TrigramRegistry::load_packs`.
+    /// Lives alongside `stats.json` in the same config directory.
+    pub fn trigram_language_packs_dir(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("langs"))
+            .unwrap_or_else(|| PathBuf::from("langs"))
+    }
+
+    /// Path to the persisted `content::bigram_mastery::BigramMasteryStore`
+    /// state, read/written by that module's own `load`/`save`. Lives
+    /// alongside `stats.json` in the same config directory.
+    pub fn bigram_mastery_path(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|dir| dir.join("bigram_mastery.json"))
+            .unwrap_or_else(|| PathBuf::from("bigram_mastery.json"))
+    }
+
+    /// Export saved stats as `format`, streaming each `SessionRecord` to
+    /// `writer` rather than building one giant in-memory value first, so
+    /// export stays cheap however many sessions have accumulated
+    pub fn export<W: Write>(&self, format: ExportFormat, writer: W) -> io::Result<()> {
+        let stats = self.load()?;
+
+        match format {
+            ExportFormat::Csv => Self::export_csv(&stats, writer),
+            ExportFormat::JsonLines => Self::export_jsonl(&stats, writer),
+        }
+    }
+
+    fn export_csv<W: Write>(stats: &Stats, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "lesson_type,wpm,accuracy,duration_secs,timestamp")?;
+
+        for session in &stats.sessions {
+            writeln!(
+                writer,
+                "{},{},{},{},{}",
+                csv_field(&session.lesson_type),
+                session.wpm,
+                session.accuracy,
+                session.duration.as_secs_f64(),
+                csv_field(&session.timestamp),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// One JSON object per line. Each `SessionRecord` is serialized through a
+    /// one-element `SerializeSeq` and the surrounding `[`/`]`/`,` stripped,
+    /// so no `Vec<SessionRecord>` of the whole history is ever materialized.
+    fn export_jsonl<W: Write>(stats: &Stats, mut writer: W) -> io::Result<()> {
+        for session in &stats.sessions {
+            let mut buf = Vec::new();
+            let mut serializer = serde_json::Serializer::new(&mut buf);
+            let mut seq = serializer.serialize_seq(Some(1)).map_err(json_err)?;
+            seq.serialize_element(session).map_err(json_err)?;
+            seq.end().map_err(json_err)?;
+
+            let line = &buf[1..buf.len() - 1];
+            writer.write_all(line)?;
+            writer.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_err(e: serde_json::Error) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Failed to serialize stats: {}", e),
+    )
+}
+
+/// A panel the statistics dashboard can show, as named in the `[statistics]`
+/// section of `config.toml`. `ui::render` maps each of these onto its own
+/// `StatsTab`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsPanel {
+    Overall,
+    Mastery,
+    Weaknesses,
+    Mistypes,
+    Heatmap,
+    Trend,
+}
+
+/// On-disk `[statistics]` config section: which panels the dashboard shows,
+/// and in what order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsLayoutConfig {
+    pub panels: Vec<StatsPanel>,
+}
+
+impl Default for StatsLayoutConfig {
+    fn default() -> Self {
+        Self {
+            panels: vec![
+                StatsPanel::Overall,
+                StatsPanel::Mastery,
+                StatsPanel::Weaknesses,
+                StatsPanel::Mistypes,
+                StatsPanel::Heatmap,
+                StatsPanel::Trend,
+            ],
+        }
+    }
+}
+
+/// On-disk `[stats_display]` config section: the thresholds and top-N
+/// limits the weaknesses/mistypes panels use to decide what's worth showing.
+/// Defaults match the values that were previously hardcoded in
+/// `render_weaknesses_list`/`render_common_mistypes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsDisplayConfig {
+    /// Below this accuracy (0-100), a key counts as a weakness
+    pub weakness_accuracy_threshold: f64,
+    /// A key needs at least this many attempts before it can count as a weakness
+    pub weakness_min_attempts: u32,
+    /// Max number of weak keys listed in the Weaknesses panel
+    pub max_weaknesses_shown: usize,
+    /// Minimum distinct mistypes recorded before the Mistypes panel shows data
+    /// instead of an "insufficient data" placeholder
+    pub min_mistypes_to_display: usize,
+    /// Max number of mistype pairs listed in the Mistypes panel
+    pub max_mistypes_shown: usize,
+}
+
+impl Default for StatsDisplayConfig {
+    fn default() -> Self {
+        Self {
+            weakness_accuracy_threshold: 80.0,
+            weakness_min_attempts: 5,
+            max_weaknesses_shown: 10,
+            min_mistypes_to_display: 5,
+            max_mistypes_shown: 10,
+        }
+    }
+}
+
+/// General app config, loaded from `config.toml` in the config directory
+/// (see `Storage::app_config_path`). Distinct from `keys.toml`'s keybinding
+/// overrides; new top-level sections can be added here the same way.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default)]
+    pub statistics: StatsLayoutConfig,
+    #[serde(default)]
+    pub stats_display: StatsDisplayConfig,
+}
+
+impl AppConfig {
+    /// Load the user's `config.toml`, falling back to defaults for any
+    /// section that's missing or if the file itself is missing or invalid.
+    pub fn load(user_config_path: &Path) -> Self {
+        fs::read_to_string(user_config_path)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -94,6 +404,78 @@ mod tests {
         assert!(storage.is_ok());
     }
 
+    #[test]
+    fn test_keybindings_path_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let keys_path = storage.keybindings_path();
+
+        assert_eq!(keys_path.file_name().unwrap(), "keys.toml");
+        assert_eq!(keys_path.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_azerty_layout_path_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let layout_path = storage.azerty_layout_path();
+
+        assert_eq!(layout_path.file_name().unwrap(), "azerty_layout.toml");
+        assert_eq!(layout_path.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_wordlists_dir_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let wordlists_dir = storage.wordlists_dir();
+
+        assert_eq!(wordlists_dir.file_name().unwrap(), "wordlists");
+        assert_eq!(wordlists_dir.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_bigram_scheduler_path_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let scheduler_path = storage.bigram_scheduler_path();
+
+        assert_eq!(scheduler_path.file_name().unwrap(), "bigram_scheduler.json");
+        assert_eq!(scheduler_path.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_trigram_scheduler_path_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let scheduler_path = storage.trigram_scheduler_path();
+
+        assert_eq!(scheduler_path.file_name().unwrap(), "trigram_scheduler.json");
+        assert_eq!(scheduler_path.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_bigram_tables_dir_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let tables_dir = storage.bigram_tables_dir();
+
+        assert_eq!(tables_dir.file_name().unwrap(), "bigram_tables");
+        assert_eq!(tables_dir.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_trigram_language_packs_dir_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let packs_dir = storage.trigram_language_packs_dir();
+
+        assert_eq!(packs_dir.file_name().unwrap(), "langs");
+        assert_eq!(packs_dir.parent(), storage.get_path().parent());
+    }
+
+    #[test]
+    fn test_bigram_mastery_path_lives_next_to_stats_file() {
+        let (storage, _temp_dir) = create_test_storage();
+        let mastery_path = storage.bigram_mastery_path();
+
+        assert_eq!(mastery_path.file_name().unwrap(), "bigram_mastery.json");
+        assert_eq!(mastery_path.parent(), storage.get_path().parent());
+    }
+
     #[test]
     fn test_load_empty_stats() {
         let (storage, _temp_dir) = create_test_storage();
@@ -124,4 +506,133 @@ mod tests {
         let loaded_stats = storage.load().unwrap();
         assert_eq!(loaded_stats.session_count(), 1);
     }
+
+    #[test]
+    fn test_save_writes_current_version() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        storage.save(&Stats::new()).unwrap();
+
+        let content = fs::read_to_string(storage.get_path()).unwrap();
+        let document: StatsDocument = serde_json::from_str(&content).unwrap();
+        assert_eq!(document.version, CURRENT_STATS_VERSION);
+    }
+
+    #[test]
+    fn test_load_migrates_legacy_unversioned_file() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        let mut legacy_stats = Stats::new();
+        legacy_stats.add_session(SessionRecord::new(
+            "HomeRow-1".to_string(),
+            45.0,
+            95.0,
+            Duration::from_secs(60),
+        ));
+        let legacy_json = serde_json::to_string_pretty(&legacy_stats).unwrap();
+        fs::write(storage.get_path(), legacy_json).unwrap();
+
+        let loaded_stats = storage.load().unwrap();
+        assert_eq!(loaded_stats.session_count(), 1);
+    }
+
+    #[test]
+    fn test_load_backs_up_and_recovers_from_corrupt_file() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        fs::write(storage.get_path(), "not valid json at all").unwrap();
+
+        let loaded_stats = storage.load().unwrap();
+        assert_eq!(loaded_stats.session_count(), 0);
+
+        let backup_path = storage.get_path().with_extension("json.bak");
+        assert!(backup_path.exists());
+        assert!(!storage.get_path().exists());
+    }
+
+    #[test]
+    fn test_save_does_not_leave_temp_file_behind() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        storage.save(&Stats::new()).unwrap();
+
+        let temp_path = storage.get_path().with_extension("json.tmp");
+        assert!(!temp_path.exists());
+    }
+
+    #[test]
+    fn test_export_csv_writes_header_and_rows() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        let mut stats = Stats::new();
+        stats.add_session(SessionRecord::new(
+            "HomeRow-1".to_string(),
+            45.0,
+            95.0,
+            Duration::from_secs(60),
+        ));
+        storage.save(&stats).unwrap();
+
+        let mut out = Vec::new();
+        storage.export(ExportFormat::Csv, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let mut lines = out.lines();
+
+        assert_eq!(
+            lines.next().unwrap(),
+            "lesson_type,wpm,accuracy,duration_secs,timestamp"
+        );
+        assert_eq!(lines.next().unwrap(), "HomeRow-1,45,95,60,");
+    }
+
+    #[test]
+    fn test_export_csv_quotes_fields_with_commas() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        let mut stats = Stats::new();
+        stats.add_session(SessionRecord::new(
+            "Custom, Lesson".to_string(),
+            45.0,
+            95.0,
+            Duration::from_secs(60),
+        ));
+        storage.save(&stats).unwrap();
+
+        let mut out = Vec::new();
+        storage.export(ExportFormat::Csv, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("\"Custom, Lesson\""));
+    }
+
+    #[test]
+    fn test_export_jsonl_writes_one_object_per_line() {
+        let (storage, _temp_dir) = create_test_storage();
+
+        let mut stats = Stats::new();
+        stats.add_session(SessionRecord::new(
+            "HomeRow-1".to_string(),
+            45.0,
+            95.0,
+            Duration::from_secs(60),
+        ));
+        stats.add_session(SessionRecord::new(
+            "HomeRow-2".to_string(),
+            50.0,
+            96.0,
+            Duration::from_secs(30),
+        ));
+        storage.save(&stats).unwrap();
+
+        let mut out = Vec::new();
+        storage.export(ExportFormat::JsonLines, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            let record: SessionRecord = serde_json::from_str(line).unwrap();
+            assert!(!record.lesson_type.is_empty());
+        }
+    }
 }
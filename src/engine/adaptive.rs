@@ -1,5 +1,7 @@
 /// Adaptive algorithms for personalized training
 /// Includes weakness detection for identifying problem areas
+use rand::{thread_rng, Rng};
+
 use super::analytics::AdaptiveAnalytics;
 
 /// Weakness detector for identifying problem areas
@@ -8,6 +10,43 @@ use super::analytics::AdaptiveAnalytics;
 #[allow(dead_code)]
 pub struct WeaknessDetector;
 
+/// Number of bootstrap resamples drawn per key for `identify_weak_keys_ci`.
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+
+/// 95% bootstrap confidence interval for a key's true accuracy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccuracyInterval {
+    pub point_estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Confidence-based weakness classification for a single key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaknessConfidence {
+    /// The interval's upper bound is still below threshold: confidently weak.
+    Weak,
+    /// Only the point estimate is below threshold: inconclusive, could be noise.
+    Borderline,
+}
+
+/// A key flagged by `identify_weak_keys_ci`, with the bootstrap interval that
+/// justified its classification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeakKeyCi {
+    pub key: char,
+    pub confidence: WeaknessConfidence,
+    pub interval: AccuracyInterval,
+}
+
+/// Keys flagged as timing outliers by `identify_slow_keys_tukey`, grouped by
+/// severity of the Tukey fence they cleared.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SlowKeyOutliers {
+    pub mild: Vec<char>,
+    pub severe: Vec<char>,
+}
+
 impl WeaknessDetector {
     /// Identify weak keys based on accuracy threshold
     /// Returns up to 5 weakest keys that meet minimum attempts threshold
@@ -32,6 +71,54 @@ impl WeaknessDetector {
             .collect()
     }
 
+    /// Statistically-grounded variant of `identify_weak_keys`: bootstrap
+    /// resamples each key's hit/miss sequence `BOOTSTRAP_RESAMPLES` times to
+    /// build a 95% confidence interval for its true accuracy, then flags a
+    /// key as `Weak` only when the interval's upper bound is still below
+    /// `threshold`, or `Borderline` when just the point estimate is, so a key
+    /// near the boundary isn't flagged on noisy data alone.
+    /// Phase 3: confidence-interval weakness detection for future session feedback
+    #[allow(dead_code)]
+    pub fn identify_weak_keys_ci(analytics: &AdaptiveAnalytics, threshold: f64) -> Vec<WeakKeyCi> {
+        let mut rng = thread_rng();
+
+        let mut flagged: Vec<WeakKeyCi> = analytics
+            .key_stats
+            .iter()
+            .filter(|(_, stats)| stats.total_attempts >= 10)
+            .filter_map(|(&key, stats)| {
+                let interval = bootstrap_accuracy_interval(
+                    stats.total_attempts,
+                    stats.correct_attempts,
+                    &mut rng,
+                );
+
+                let confidence = if interval.upper < threshold {
+                    WeaknessConfidence::Weak
+                } else if interval.point_estimate < threshold {
+                    WeaknessConfidence::Borderline
+                } else {
+                    return None;
+                };
+
+                Some(WeakKeyCi {
+                    key,
+                    confidence,
+                    interval,
+                })
+            })
+            .collect();
+
+        flagged.sort_by(|a, b| {
+            a.interval
+                .point_estimate
+                .partial_cmp(&b.interval.point_estimate)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        flagged
+    }
+
     /// Identify slow keys based on timing percentile
     /// Returns keys slower than the given percentile (e.g., 0.75 for top 25% slowest)
     pub fn identify_slow_keys(analytics: &AdaptiveAnalytics, percentile: f64) -> Vec<char> {
@@ -60,6 +147,110 @@ impl WeaknessDetector {
             .collect()
     }
 
+    /// Statistically-grounded variant of `identify_slow_keys`: instead of a
+    /// fixed percentile cutoff, classifies keys via Tukey/IQR outlier fences
+    /// over every key's `average_time_ms()`, so "slow" adapts to each user's
+    /// overall typing speed rather than always flagging the top quartile.
+    /// Quartiles need at least 4 data points to be meaningful, so with fewer
+    /// than 4 qualifying keys this falls back to `identify_slow_keys` at the
+    /// 75th percentile, reported as `mild` with no `severe` keys.
+    /// Phase 3: Tukey-fence timing outlier detection for future session feedback
+    #[allow(dead_code)]
+    pub fn identify_slow_keys_tukey(analytics: &AdaptiveAnalytics) -> SlowKeyOutliers {
+        let mut times: Vec<(char, f64)> = analytics
+            .key_stats
+            .iter()
+            .filter(|(_, stats)| stats.correct_attempts >= 5)
+            .map(|(&key, stats)| (key, stats.average_time_ms()))
+            .collect();
+
+        if times.len() < 4 {
+            return SlowKeyOutliers {
+                mild: Self::identify_slow_keys(analytics, 0.75),
+                severe: Vec::new(),
+            };
+        }
+
+        times.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        let sorted_times: Vec<f64> = times.iter().map(|(_, time)| *time).collect();
+
+        let q1 = percentile(&sorted_times, 0.25);
+        let q3 = percentile(&sorted_times, 0.75);
+        let iqr = q3 - q1;
+
+        let mild_fence = q3 + 1.5 * iqr;
+        let severe_fence = q3 + 3.0 * iqr;
+
+        let mut mild = Vec::new();
+        let mut severe = Vec::new();
+
+        for (key, time) in times {
+            if time > severe_fence {
+                severe.push(key);
+            } else if time > mild_fence {
+                mild.push(key);
+            }
+        }
+
+        SlowKeyOutliers { mild, severe }
+    }
+
+    /// Flag keys whose keystroke-timing KDE is multi-modal: a secondary peak
+    /// at least `SECONDARY_PEAK_FRACTION` as tall as the primary one
+    /// indicates the user is usually fast but occasionally freezes on this
+    /// key, which `average_time_ms()` alone would hide behind a "medium"
+    /// average. Requires at least `MIN_SAMPLES_FOR_KDE` timing samples for
+    /// the density estimate to be meaningful.
+    /// Phase 3: KDE hesitation detection for future session feedback
+    #[allow(dead_code)]
+    pub fn identify_hesitant_keys(analytics: &AdaptiveAnalytics) -> Vec<char> {
+        const MIN_SAMPLES_FOR_KDE: usize = 10;
+        const KDE_GRID_POINTS: usize = 100;
+        const SECONDARY_PEAK_FRACTION: f64 = 0.3;
+
+        let mut hesitant: Vec<char> = analytics
+            .key_stats
+            .iter()
+            .filter(|(_, stats)| stats.timing_samples_ms.len() >= MIN_SAMPLES_FOR_KDE)
+            .filter_map(|(&key, _)| {
+                let density = analytics.timing_density(key, KDE_GRID_POINTS);
+                let peaks = local_maxima(&density);
+
+                let mut heights: Vec<f64> = peaks.into_iter().map(|(_, y)| y).collect();
+                heights.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+
+                let is_multimodal = heights.len() >= 2
+                    && heights[1] >= heights[0] * SECONDARY_PEAK_FRACTION;
+
+                is_multimodal.then_some(key)
+            })
+            .collect();
+
+        hesitant.sort_unstable();
+        hesitant
+    }
+
+    /// Identify the most frequent expected-vs-typed confusions recorded in
+    /// `analytics.substitution_counts` (e.g. `e` typed as `r`), most frequent
+    /// first, so drills can target disambiguating specific neighboring-key
+    /// mix-ups instead of just the expected key in isolation.
+    /// Phase 3: substitution-pair weakness detection for future session feedback
+    #[allow(dead_code)]
+    pub fn identify_confused_pairs(analytics: &AdaptiveAnalytics) -> Vec<((char, char), u32)> {
+        let mut pairs: Vec<((char, char), u32)> = analytics
+            .substitution_counts
+            .iter()
+            .flat_map(|(&expected, typed_counts)| {
+                typed_counts
+                    .iter()
+                    .map(move |(&typed, &count)| ((expected, typed), count))
+            })
+            .collect();
+
+        pairs.sort_by(|a, b| b.1.cmp(&a.1));
+        pairs
+    }
+
     /// Identify weak bigrams based on accuracy
     /// Returns up to 5 weakest bigrams with minimum attempts threshold
     /// Phase 3: Bigram weakness detection for future UI session feedback
@@ -82,6 +273,148 @@ impl WeaknessDetector {
     }
 }
 
+/// Smallest possible sampling weight, so even a key/bigram with a perfect
+/// (zero) error rate still has a tiny chance of being drawn.
+const DRILL_WEIGHT_EPSILON: f64 = 0.01;
+
+/// Turns `WeaknessDetector`'s weak-key/bigram data into actual practice text,
+/// sampling characters with probability proportional to their error rate via
+/// the Efraimidis-Spirakis weighted-random scheme: for each candidate with
+/// weight `w_i`, draw `u ~ Uniform(0,1)` and compute `k_i = u.powf(1.0 / w_i)`;
+/// the candidate with the largest `k_i` wins. This is an unbiased, one-pass
+/// weighted sample, so the most-missed keys appear more often while strong
+/// keys still show up occasionally.
+pub struct DrillGenerator;
+
+impl DrillGenerator {
+    /// Generate a `len`-character drill of individual weak keys, doubled up
+    /// ("dd kk") so each pick is still a comfortable two-key drill beat.
+    pub fn generate_drill(analytics: &AdaptiveAnalytics, len: usize) -> String {
+        let candidates: Vec<(char, f64)> = analytics
+            .key_stats
+            .iter()
+            .map(|(&key, stats)| (key, stats.error_rate() + DRILL_WEIGHT_EPSILON))
+            .collect();
+
+        if candidates.is_empty() {
+            return String::new();
+        }
+
+        let mut rng = thread_rng();
+        let mut result = String::new();
+
+        while result.len() < len {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            let key = weighted_pick(&candidates, &mut rng);
+            result.push(key);
+            result.push(key);
+        }
+
+        result.chars().take(len).collect()
+    }
+
+    /// Generate a `len`-character drill that chains weak bigrams from
+    /// `bigram_stats`, each drawn via the same weighted scheme, weighted by
+    /// bigram error rate.
+    pub fn generate_bigram_drill(analytics: &AdaptiveAnalytics, len: usize) -> String {
+        let candidates: Vec<(String, f64)> = analytics
+            .bigram_stats
+            .iter()
+            .map(|(bigram, stats)| {
+                let error_rate = 100.0 - stats.accuracy();
+                (bigram.clone(), error_rate + DRILL_WEIGHT_EPSILON)
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return String::new();
+        }
+
+        let mut rng = thread_rng();
+        let mut result = String::new();
+
+        while result.len() < len {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(&weighted_pick(&candidates, &mut rng));
+        }
+
+        result.chars().take(len).collect()
+    }
+}
+
+/// Bootstrap a 95% confidence interval for a key's true accuracy: resample
+/// `BOOTSTRAP_RESAMPLES` sequences of `total_attempts` hit/miss draws with
+/// replacement (each draw a Bernoulli trial at the observed accuracy, which
+/// is equivalent to resampling the original 0/1 sequence with replacement),
+/// then take the 2.5th/97.5th percentiles of the resulting accuracies.
+fn bootstrap_accuracy_interval(
+    total_attempts: usize,
+    correct_attempts: usize,
+    rng: &mut impl Rng,
+) -> AccuracyInterval {
+    let p = correct_attempts as f64 / total_attempts as f64;
+    let point_estimate = p * 100.0;
+
+    let mut resampled_accuracies: Vec<f64> = (0..BOOTSTRAP_RESAMPLES)
+        .map(|_| {
+            let hits = (0..total_attempts).filter(|_| rng.gen_bool(p)).count();
+            (hits as f64 / total_attempts as f64) * 100.0
+        })
+        .collect();
+
+    resampled_accuracies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let lower_idx = ((resampled_accuracies.len() as f64) * 0.025) as usize;
+    let upper_idx =
+        (((resampled_accuracies.len() as f64) * 0.975) as usize).min(resampled_accuracies.len() - 1);
+
+    AccuracyInterval {
+        point_estimate,
+        lower: resampled_accuracies[lower_idx],
+        upper: resampled_accuracies[upper_idx],
+    }
+}
+
+/// Linear-interpolation-free percentile (matches `identify_slow_keys`'s
+/// nearest-rank convention): `values` must already be sorted ascending.
+fn percentile(values: &[f64], p: f64) -> f64 {
+    let idx = ((values.len() as f64) * p).min((values.len() - 1) as f64) as usize;
+    values[idx]
+}
+
+/// Strict local maxima of a `(x, y)` curve: points whose `y` is greater than
+/// both neighbors. Used to detect secondary modes in a KDE curve.
+fn local_maxima(curve: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if curve.len() < 3 {
+        return Vec::new();
+    }
+
+    curve
+        .windows(3)
+        .filter(|w| w[1].1 > w[0].1 && w[1].1 > w[2].1)
+        .map(|w| w[1])
+        .collect()
+}
+
+/// Draw a single candidate via the Efraimidis-Spirakis key
+/// `k_i = u.powf(1.0 / w_i)` for `u ~ Uniform(0,1)`, returning the candidate
+/// with the largest key. Unbiased, one-pass weighted sampling.
+fn weighted_pick<T: Clone>(candidates: &[(T, f64)], rng: &mut impl Rng) -> T {
+    candidates
+        .iter()
+        .map(|(item, weight)| {
+            let u: f64 = rng.gen_range(0.0..1.0);
+            (item, u.powf(1.0 / weight))
+        })
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(item, _)| item.clone())
+        .expect("candidates is non-empty")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +475,60 @@ mod tests {
         assert!(!weak_keys.contains(&'k'));
     }
 
+    #[test]
+    fn test_identify_confused_pairs_orders_by_frequency() {
+        let mut analytics = AdaptiveAnalytics::default();
+        analytics.record_substitution('e', 'r');
+        analytics.record_substitution('e', 'r');
+        analytics.record_substitution('e', 'r');
+        analytics.record_substitution('a', 's');
+
+        let confused = WeaknessDetector::identify_confused_pairs(&analytics);
+
+        assert_eq!(confused[0], (('e', 'r'), 3));
+        assert_eq!(confused[1], (('a', 's'), 1));
+    }
+
+    #[test]
+    fn test_identify_confused_pairs_empty_without_substitutions() {
+        let analytics = AdaptiveAnalytics::default();
+        assert!(WeaknessDetector::identify_confused_pairs(&analytics).is_empty());
+    }
+
+    #[test]
+    fn test_identify_hesitant_keys_flags_bimodal_timing() {
+        let mut analytics = AdaptiveAnalytics::default();
+
+        // 'g': mostly fast (~100ms), but a cluster of occasional freezes (~500ms).
+        let mut g_stats = KeyStats::new('g');
+        g_stats.timing_samples_ms = [
+            vec![95, 98, 100, 100, 102, 105, 99, 101, 97, 103],
+            vec![490, 500, 510, 495, 505],
+        ]
+        .concat();
+        analytics.key_stats.insert('g', g_stats);
+
+        // 'f': consistently fast, no secondary mode.
+        let mut f_stats = KeyStats::new('f');
+        f_stats.timing_samples_ms = vec![95, 98, 100, 100, 102, 105, 99, 101, 97, 103];
+        analytics.key_stats.insert('f', f_stats);
+
+        let hesitant = WeaknessDetector::identify_hesitant_keys(&analytics);
+
+        assert!(hesitant.contains(&'g'));
+        assert!(!hesitant.contains(&'f'));
+    }
+
+    #[test]
+    fn test_identify_hesitant_keys_requires_minimum_samples() {
+        let mut analytics = AdaptiveAnalytics::default();
+        let mut stats = KeyStats::new('g');
+        stats.timing_samples_ms = vec![100, 500]; // too few samples to trust
+        analytics.key_stats.insert('g', stats);
+
+        assert!(WeaknessDetector::identify_hesitant_keys(&analytics).is_empty());
+    }
+
     #[test]
     fn test_identify_slow_keys() {
         let mut analytics = AdaptiveAnalytics::default();
@@ -174,6 +561,67 @@ mod tests {
         assert!(slow_keys.contains(&'g'));
     }
 
+    fn analytics_with_times(keys_and_times: &[(char, u64)]) -> AdaptiveAnalytics {
+        let mut analytics = AdaptiveAnalytics::default();
+        for &(key, time_ms) in keys_and_times {
+            let mut stats = KeyStats::new(key);
+            stats.correct_attempts = 10;
+            stats.total_time_ms = time_ms * 10;
+            analytics.key_stats.insert(key, stats);
+        }
+        analytics
+    }
+
+    #[test]
+    fn test_identify_slow_keys_tukey_flags_severe_outlier() {
+        let analytics = analytics_with_times(&[
+            ('a', 100),
+            ('b', 110),
+            ('c', 120),
+            ('d', 130),
+            ('e', 140),
+            ('f', 150),
+            ('g', 160),
+            ('h', 1000), // far beyond Q3 + 3*IQR
+        ]);
+
+        let outliers = WeaknessDetector::identify_slow_keys_tukey(&analytics);
+
+        assert!(outliers.severe.contains(&'h'));
+        assert!(!outliers.mild.contains(&'h'));
+    }
+
+    #[test]
+    fn test_identify_slow_keys_tukey_flags_mild_outlier() {
+        let analytics = analytics_with_times(&[
+            ('a', 100),
+            ('b', 110),
+            ('c', 120),
+            ('d', 130),
+            ('e', 140),
+            ('f', 150),
+            ('g', 160),
+            ('h', 250), // beyond Q3 + 1.5*IQR but not Q3 + 3*IQR
+        ]);
+
+        let outliers = WeaknessDetector::identify_slow_keys_tukey(&analytics);
+
+        assert!(outliers.mild.contains(&'h'));
+        assert!(!outliers.severe.contains(&'h'));
+    }
+
+    #[test]
+    fn test_identify_slow_keys_tukey_falls_back_with_few_keys() {
+        let analytics = analytics_with_times(&[('a', 100), ('b', 200), ('c', 400)]);
+
+        let outliers = WeaknessDetector::identify_slow_keys_tukey(&analytics);
+
+        assert_eq!(outliers, SlowKeyOutliers {
+            mild: WeaknessDetector::identify_slow_keys(&analytics, 0.75),
+            severe: Vec::new(),
+        });
+    }
+
     #[test]
     fn test_identify_weak_bigrams() {
         let mut analytics = AdaptiveAnalytics::default();
@@ -197,4 +645,102 @@ mod tests {
         // Should not include fj (95% accuracy)
         assert!(!weak_bigrams.contains(&"fj".to_string()));
     }
+
+    #[test]
+    fn test_generate_drill_respects_length() {
+        let analytics = create_test_analytics();
+        let content = DrillGenerator::generate_drill(&analytics, 40);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 40);
+    }
+
+    #[test]
+    fn test_generate_drill_empty_analytics_is_empty() {
+        let analytics = AdaptiveAnalytics::default();
+        assert_eq!(DrillGenerator::generate_drill(&analytics, 40), String::new());
+    }
+
+    #[test]
+    fn test_generate_drill_favors_weaker_keys() {
+        let analytics = create_test_analytics();
+
+        // 's' (50% accuracy) is much weaker than 'f' (96%); over many draws
+        // it should appear noticeably more often.
+        let content = DrillGenerator::generate_drill(&analytics, 2000);
+        let s_count = content.chars().filter(|&c| c == 's').count();
+        let f_count = content.chars().filter(|&c| c == 'f').count();
+
+        assert!(s_count > f_count);
+    }
+
+    #[test]
+    fn test_generate_bigram_drill_respects_length() {
+        let mut analytics = AdaptiveAnalytics::default();
+        let mut dk_stats = BigramStats::new("dk".to_string());
+        dk_stats.total_attempts = 20;
+        dk_stats.correct_attempts = 15;
+        analytics.bigram_stats.insert("dk".to_string(), dk_stats);
+
+        let content = DrillGenerator::generate_bigram_drill(&analytics, 30);
+
+        assert!(!content.is_empty());
+        assert!(content.len() <= 30);
+    }
+
+    #[test]
+    fn test_generate_bigram_drill_empty_analytics_is_empty() {
+        let analytics = AdaptiveAnalytics::default();
+        assert_eq!(
+            DrillGenerator::generate_bigram_drill(&analytics, 30),
+            String::new()
+        );
+    }
+
+    #[test]
+    fn test_identify_weak_keys_ci_flags_clearly_weak_key() {
+        let analytics = create_test_analytics();
+
+        // 's' is 50% accuracy (25/50): with 50 attempts the CI should stay
+        // well below an 80% threshold.
+        let flagged = WeaknessDetector::identify_weak_keys_ci(&analytics, 80.0);
+
+        let s_result = flagged.iter().find(|w| w.key == 's');
+        assert!(s_result.is_some());
+        assert_eq!(s_result.unwrap().confidence, WeaknessConfidence::Weak);
+        assert!(s_result.unwrap().interval.upper < 80.0);
+    }
+
+    #[test]
+    fn test_identify_weak_keys_ci_excludes_strong_key() {
+        let analytics = create_test_analytics();
+
+        // 'f' is 96% accuracy: should never be flagged at an 80% threshold.
+        let flagged = WeaknessDetector::identify_weak_keys_ci(&analytics, 80.0);
+
+        assert!(!flagged.iter().any(|w| w.key == 'f'));
+    }
+
+    #[test]
+    fn test_identify_weak_keys_ci_respects_minimum_attempts() {
+        let mut analytics = AdaptiveAnalytics::default();
+
+        let mut k_stats = KeyStats::new('k');
+        k_stats.total_attempts = 5; // below the minimum of 10
+        k_stats.correct_attempts = 1;
+        analytics.key_stats.insert('k', k_stats);
+
+        let flagged = WeaknessDetector::identify_weak_keys_ci(&analytics, 80.0);
+
+        assert!(!flagged.iter().any(|w| w.key == 'k'));
+    }
+
+    #[test]
+    fn test_bootstrap_interval_contains_point_estimate() {
+        let mut rng = thread_rng();
+        let interval = bootstrap_accuracy_interval(50, 35, &mut rng);
+
+        assert!(interval.lower <= interval.point_estimate);
+        assert!(interval.point_estimate <= interval.upper);
+    }
 }
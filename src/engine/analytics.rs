@@ -60,9 +60,20 @@ pub struct KeyStats {
     pub correct_attempts: usize,
     pub error_count: usize,
     pub total_time_ms: u64,
+    /// Sum of dwell times (key hold duration) across kitty-protocol-enabled sessions
+    #[serde(default)]
+    pub total_dwell_ms: u64,
+    /// Number of dwell-time samples contributing to `total_dwell_ms`
+    #[serde(default)]
+    pub dwell_samples: usize,
     pub mistype_map: HashMap<char, usize>, // What was typed instead
     pub last_practiced: Option<SystemTime>,
     pub mastery_level: MasteryLevel,
+    /// Raw per-keystroke timing samples (correct attempts only), for kernel
+    /// density estimation. `total_time_ms` only keeps the sum, which hides
+    /// bimodal behavior like occasional hesitation freezes.
+    #[serde(default)]
+    pub timing_samples_ms: Vec<u64>,
 }
 
 impl KeyStats {
@@ -73,12 +84,23 @@ impl KeyStats {
             correct_attempts: 0,
             error_count: 0,
             total_time_ms: 0,
+            total_dwell_ms: 0,
+            dwell_samples: 0,
             mistype_map: HashMap::new(),
             last_practiced: None,
             mastery_level: MasteryLevel::Beginner,
+            timing_samples_ms: Vec::new(),
         }
     }
 
+    /// Calculate average dwell time (key hold duration) in milliseconds
+    pub fn average_dwell_ms(&self) -> f64 {
+        if self.dwell_samples == 0 {
+            return 0.0;
+        }
+        self.total_dwell_ms as f64 / self.dwell_samples as f64
+    }
+
     /// Calculate accuracy percentage
     pub fn accuracy(&self) -> f64 {
         if self.total_attempts == 0 {
@@ -171,6 +193,138 @@ pub struct AdaptiveAnalytics {
     pub session_history: Vec<SessionAnalytics>,
     pub total_sessions: usize,
     pub total_keystrokes: usize,
+    /// Substitution confusion counts from edit-distance-aligned input,
+    /// keyed `expected -> typed -> count`. Nested instead of a single map
+    /// keyed by `(char, char)` because `serde_json` can't serialize a tuple
+    /// as an object key.
+    #[serde(default)]
+    pub substitution_counts: HashMap<char, HashMap<char, u32>>,
+}
+
+impl AdaptiveAnalytics {
+    /// Record that `expected` was typed as `typed` instead, for the
+    /// edit-distance-aligned confusion matrix.
+    pub fn record_substitution(&mut self, expected: char, typed: char) {
+        *self
+            .substitution_counts
+            .entry(expected)
+            .or_default()
+            .entry(typed)
+            .or_insert(0) += 1;
+    }
+
+    /// Gaussian kernel density estimate of `key`'s keystroke-time samples,
+    /// evaluated at `grid_points` evenly-spaced points spanning the sample
+    /// range (padded by 3 bandwidths on each side). Uses Silverman's rule of
+    /// thumb for the bandwidth: `h = 1.06 * sigma * n^(-1/5)`. Returns an
+    /// empty vec if `key` has fewer than 2 timing samples, since a
+    /// bandwidth/variance isn't meaningful below that.
+    pub fn timing_density(&self, key: char, grid_points: usize) -> Vec<(f64, f64)> {
+        let samples: Vec<f64> = match self.key_stats.get(&key) {
+            Some(stats) => stats.timing_samples_ms.iter().map(|&t| t as f64).collect(),
+            None => return Vec::new(),
+        };
+
+        if samples.len() < 2 || grid_points == 0 {
+            return Vec::new();
+        }
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let sigma = variance.sqrt();
+
+        if sigma == 0.0 {
+            return Vec::new();
+        }
+
+        let h = 1.06 * sigma * n.powf(-1.0 / 5.0);
+
+        let min = samples.iter().cloned().fold(f64::INFINITY, f64::min) - 3.0 * h;
+        let max = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max) + 3.0 * h;
+        let step = (max - min) / (grid_points - 1).max(1) as f64;
+
+        (0..grid_points)
+            .map(|i| {
+                let x = min + step * i as f64;
+                let density = samples
+                    .iter()
+                    .map(|&sample| gaussian_kernel((x - sample) / h))
+                    .sum::<f64>()
+                    / (n * h);
+                (x, density)
+            })
+            .collect()
+    }
+
+    /// Mean WPM across the last `n` sessions in `session_history` (fewer if
+    /// there aren't `n` yet). Returns `None` if no sessions have been recorded.
+    pub fn rolling_average_wpm(&self, n: usize) -> Option<f64> {
+        let recent = self.session_history.iter().rev().take(n);
+        let (sum, count) = recent.fold((0.0, 0usize), |(sum, count), session| {
+            (sum + session.wpm, count + 1)
+        });
+
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f64)
+        }
+    }
+
+    /// The single best WPM recorded across all sessions, if any have been recorded.
+    pub fn personal_best_wpm(&self) -> Option<f64> {
+        self.session_history
+            .iter()
+            .map(|session| session.wpm)
+            .fold(None, |best, wpm| {
+                Some(best.map_or(wpm, |b: f64| b.max(wpm)))
+            })
+    }
+
+    /// Slope of a least-squares linear regression of WPM over the last `n`
+    /// sessions (x = session index, y = wpm), in WPM gained per session.
+    /// Positive means improving, negative means regressing. Returns `None`
+    /// with fewer than 2 sessions, since a trend isn't meaningful below that.
+    pub fn wpm_trend_slope(&self, n: usize) -> Option<f64> {
+        let recent: Vec<f64> = self
+            .session_history
+            .iter()
+            .rev()
+            .take(n)
+            .map(|session| session.wpm)
+            .collect();
+
+        if recent.len() < 2 {
+            return None;
+        }
+
+        // Restore chronological order (oldest first) so x increases with time
+        let ys: Vec<f64> = recent.into_iter().rev().collect();
+        let n = ys.len() as f64;
+        let xs: Vec<f64> = (0..ys.len()).map(|i| i as f64).collect();
+
+        let x_mean = xs.iter().sum::<f64>() / n;
+        let y_mean = ys.iter().sum::<f64>() / n;
+
+        let covariance: f64 = xs
+            .iter()
+            .zip(ys.iter())
+            .map(|(x, y)| (x - x_mean) * (y - y_mean))
+            .sum();
+        let variance: f64 = xs.iter().map(|x| (x - x_mean).powi(2)).sum();
+
+        if variance == 0.0 {
+            Some(0.0)
+        } else {
+            Some(covariance / variance)
+        }
+    }
+}
+
+/// Standard Gaussian kernel `K(u) = exp(-u^2 / 2) / sqrt(2*pi)`.
+fn gaussian_kernel(u: f64) -> f64 {
+    (-u * u / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
 }
 
 /// Per-key performance analysis for a single session
@@ -180,6 +334,8 @@ pub struct KeyPerformance {
     pub correct_attempts: usize,
     pub errors: Vec<char>,
     pub timings: Vec<Duration>,
+    /// Dwell times (key hold duration) collected from kitty protocol release events
+    pub dwell_timings: Vec<Duration>,
 }
 
 /// Session analysis result
@@ -187,6 +343,39 @@ pub struct KeyPerformance {
 pub struct SessionAnalysis {
     pub key_performance: HashMap<char, KeyPerformance>,
     pub bigram_performance: HashMap<String, KeyPerformance>,
+    /// Accidental n-key-rollover events observed during the session
+    pub rollover_count: usize,
+}
+
+/// A gap between two consecutive keystrokes that crossed
+/// `LONG_PAUSE_THRESHOLD_MS`, for `TimingReport::pauses`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PauseEvent {
+    /// The character whose keystroke followed the pause
+    pub before_char: char,
+    pub gap: Duration,
+}
+
+/// A pause at or above this, between two consecutive keystrokes, is
+/// reported as a hesitation in `TimingReport::pauses`
+const LONG_PAUSE_THRESHOLD_MS: u64 = 1500;
+
+/// How many entries `TimingReport::slowest_keys`/`slowest_bigrams` keep
+const TIMING_REPORT_TOP_N: usize = 5;
+
+/// Slowest-key/slowest-bigram/long-pause summary for one session, built
+/// directly from `TypingSession.inputs`' per-keystroke timestamps — distinct
+/// from `SessionAnalysis`, which aggregates totals rather than ranking them.
+#[derive(Debug, Clone, Default)]
+pub struct TimingReport {
+    /// Up to the slowest `TIMING_REPORT_TOP_N` characters by average
+    /// inter-keystroke interval, slowest first
+    pub slowest_keys: Vec<(char, Duration)>,
+    /// Up to the slowest `TIMING_REPORT_TOP_N` adjacent digraphs (pairs of
+    /// expected characters) by average interval, slowest first
+    pub slowest_bigrams: Vec<(String, Duration)>,
+    /// Every gap at or above `LONG_PAUSE_THRESHOLD_MS`, in session order
+    pub pauses: Vec<PauseEvent>,
 }
 
 /// Analyzer for extracting statistics from typing sessions
@@ -243,11 +432,71 @@ impl SessionAnalyzer {
             }
         }
 
+        // Fold in per-key dwell times from the kitty protocol, if any were recorded
+        for timing in &session.key_timings {
+            let perf = key_performance.entry(timing.key).or_default();
+            perf.dwell_timings.push(timing.dwell);
+        }
+
         SessionAnalysis {
             key_performance,
             bigram_performance,
+            rollover_count: session.rollover_count,
         }
     }
+
+    /// Build a `TimingReport` from `session.inputs`' per-keystroke
+    /// timestamps: the inter-keystroke interval `inputs[i].timestamp -
+    /// inputs[i-1].timestamp`, aggregated per character and per adjacent
+    /// digraph to rank the slowest, plus every individual gap long enough to
+    /// count as a hesitation pause.
+    pub fn timing_report(&self, session: &TypingSession) -> TimingReport {
+        let mut key_intervals: HashMap<char, Vec<Duration>> = HashMap::new();
+        let mut bigram_intervals: HashMap<String, Vec<Duration>> = HashMap::new();
+        let mut pauses = Vec::new();
+
+        for pair in session.inputs.windows(2) {
+            let (prev, curr) = (&pair[0], &pair[1]);
+            let Some(gap) = curr.timestamp.checked_sub(prev.timestamp) else {
+                continue;
+            };
+
+            key_intervals.entry(curr.expected).or_default().push(gap);
+            bigram_intervals
+                .entry(format!("{}{}", prev.expected, curr.expected))
+                .or_default()
+                .push(gap);
+
+            if gap.as_millis() as u64 >= LONG_PAUSE_THRESHOLD_MS {
+                pauses.push(PauseEvent {
+                    before_char: curr.expected,
+                    gap,
+                });
+            }
+        }
+
+        TimingReport {
+            slowest_keys: top_slowest(key_intervals),
+            slowest_bigrams: top_slowest(bigram_intervals),
+            pauses,
+        }
+    }
+}
+
+/// Average each key's collected intervals and return the slowest
+/// `TIMING_REPORT_TOP_N`, slowest first (ties broken by key for determinism)
+fn top_slowest<K: Ord>(intervals: HashMap<K, Vec<Duration>>) -> Vec<(K, Duration)> {
+    let mut averaged: Vec<(K, Duration)> = intervals
+        .into_iter()
+        .map(|(key, samples)| {
+            let total: Duration = samples.iter().sum();
+            (key, total / samples.len() as u32)
+        })
+        .collect();
+
+    averaged.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    averaged.truncate(TIMING_REPORT_TOP_N);
+    averaged
 }
 
 #[cfg(test)]
@@ -313,6 +562,46 @@ mod tests {
         assert_eq!(stats.average_time_ms(), 200.0); // 200ms per key
     }
 
+    #[test]
+    fn test_timing_density_empty_below_min_samples() {
+        let mut analytics = AdaptiveAnalytics::default();
+        let mut stats = KeyStats::new('f');
+        stats.timing_samples_ms = vec![100];
+        analytics.key_stats.insert('f', stats);
+
+        assert!(analytics.timing_density('f', 50).is_empty());
+    }
+
+    #[test]
+    fn test_timing_density_missing_key_is_empty() {
+        let analytics = AdaptiveAnalytics::default();
+        assert!(analytics.timing_density('z', 50).is_empty());
+    }
+
+    #[test]
+    fn test_timing_density_peaks_near_samples() {
+        let mut analytics = AdaptiveAnalytics::default();
+        let mut stats = KeyStats::new('f');
+        // Tight cluster around 100ms, so the KDE should peak near there.
+        stats.timing_samples_ms = vec![95, 98, 100, 100, 102, 105, 99, 101];
+        analytics.key_stats.insert('f', stats);
+
+        let density = analytics.timing_density('f', 200);
+        assert!(!density.is_empty());
+
+        let (peak_x, _) = density
+            .iter()
+            .cloned()
+            .fold((0.0, f64::NEG_INFINITY), |best, cur| {
+                if cur.1 > best.1 {
+                    cur
+                } else {
+                    best
+                }
+            });
+        assert!((peak_x - 100.0).abs() < 10.0);
+    }
+
     #[test]
     fn test_bigram_stats_accuracy() {
         let mut stats = BigramStats::new("fj".to_string());
@@ -321,6 +610,63 @@ mod tests {
         assert_eq!(stats.accuracy(), 90.0);
     }
 
+    fn session_with_wpm(wpm: f64) -> SessionAnalytics {
+        SessionAnalytics {
+            timestamp: std::time::SystemTime::UNIX_EPOCH,
+            lesson_type: "HomeRow-1".to_string(),
+            wpm,
+            accuracy: 95.0,
+            duration_secs: 60,
+            weak_keys: Vec::new(),
+            improved_keys: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_rolling_average_wpm_no_sessions() {
+        let analytics = AdaptiveAnalytics::default();
+        assert_eq!(analytics.rolling_average_wpm(5), None);
+    }
+
+    #[test]
+    fn test_rolling_average_wpm_uses_only_last_n() {
+        let mut analytics = AdaptiveAnalytics::default();
+        for wpm in [20.0, 30.0, 40.0, 50.0] {
+            analytics.session_history.push(session_with_wpm(wpm));
+        }
+
+        // Last 2 sessions are 40 and 50
+        assert_eq!(analytics.rolling_average_wpm(2), Some(45.0));
+    }
+
+    #[test]
+    fn test_personal_best_wpm() {
+        let mut analytics = AdaptiveAnalytics::default();
+        for wpm in [30.0, 55.0, 40.0] {
+            analytics.session_history.push(session_with_wpm(wpm));
+        }
+
+        assert_eq!(analytics.personal_best_wpm(), Some(55.0));
+    }
+
+    #[test]
+    fn test_wpm_trend_slope_none_below_two_sessions() {
+        let mut analytics = AdaptiveAnalytics::default();
+        analytics.session_history.push(session_with_wpm(40.0));
+        assert_eq!(analytics.wpm_trend_slope(5), None);
+    }
+
+    #[test]
+    fn test_wpm_trend_slope_improving() {
+        let mut analytics = AdaptiveAnalytics::default();
+        for wpm in [30.0, 40.0, 50.0, 60.0] {
+            analytics.session_history.push(session_with_wpm(wpm));
+        }
+
+        // Perfectly linear +10 wpm per session
+        assert!((analytics.wpm_trend_slope(10).unwrap() - 10.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_practice_weights() {
         assert_eq!(MasteryLevel::Beginner.practice_weight(), 0.6);
@@ -366,6 +712,10 @@ mod tests {
             ],
             start_time: Some(Instant::now()),
             end_time: Some(Instant::now()),
+            key_timings: Vec::new(),
+            rollover_count: 0,
+            held_keys: HashMap::new(),
+            last_key_up_time: None,
         };
 
         let analyzer = SessionAnalyzer::new();
@@ -378,4 +728,77 @@ mod tests {
         assert_eq!(analysis.key_performance[&'e'].total_attempts, 1);
         assert_eq!(analysis.key_performance[&'s'].total_attempts, 1);
     }
+
+    fn session_with_inputs(inputs: Vec<CharInput>) -> TypingSession {
+        use std::time::Instant;
+
+        TypingSession {
+            content: inputs.iter().map(|i| i.expected).collect(),
+            current_index: inputs.len(),
+            duration_limit: Duration::from_secs(300),
+            content_buffer_size: inputs.len(),
+            inputs,
+            start_time: Some(Instant::now()),
+            end_time: Some(Instant::now()),
+            key_timings: Vec::new(),
+            rollover_count: 0,
+            held_keys: HashMap::new(),
+            last_key_up_time: None,
+        }
+    }
+
+    #[test]
+    fn test_timing_report_ranks_slowest_key_first() {
+        let session = session_with_inputs(vec![
+            CharInput::new('a', 'a', Duration::from_millis(0)),
+            CharInput::new('b', 'b', Duration::from_millis(50)), // 50ms after 'a'
+            CharInput::new('c', 'c', Duration::from_millis(450)), // 400ms after 'b'
+        ]);
+
+        let report = SessionAnalyzer::new().timing_report(&session);
+
+        assert_eq!(report.slowest_keys[0].0, 'c');
+        assert_eq!(report.slowest_keys[0].1, Duration::from_millis(400));
+        assert_eq!(report.slowest_keys[1].0, 'b');
+        assert_eq!(report.slowest_keys[1].1, Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_timing_report_ranks_slowest_bigram_first() {
+        let session = session_with_inputs(vec![
+            CharInput::new('t', 't', Duration::from_millis(0)),
+            CharInput::new('h', 'h', Duration::from_millis(300)), // "th" = 300ms
+            CharInput::new('e', 'e', Duration::from_millis(320)), // "he" = 20ms
+        ]);
+
+        let report = SessionAnalyzer::new().timing_report(&session);
+
+        assert_eq!(report.slowest_bigrams[0].0, "th");
+        assert_eq!(report.slowest_bigrams[0].1, Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_timing_report_flags_long_pauses() {
+        let session = session_with_inputs(vec![
+            CharInput::new('a', 'a', Duration::from_millis(0)),
+            CharInput::new('b', 'b', Duration::from_millis(100)), // short gap
+            CharInput::new('c', 'c', Duration::from_millis(2000)), // 1900ms pause
+        ]);
+
+        let report = SessionAnalyzer::new().timing_report(&session);
+
+        assert_eq!(report.pauses.len(), 1);
+        assert_eq!(report.pauses[0].before_char, 'c');
+        assert_eq!(report.pauses[0].gap, Duration::from_millis(1900));
+    }
+
+    #[test]
+    fn test_timing_report_empty_session_has_no_pauses_or_rankings() {
+        let session = session_with_inputs(vec![]);
+        let report = SessionAnalyzer::new().timing_report(&session);
+
+        assert!(report.slowest_keys.is_empty());
+        assert!(report.slowest_bigrams.is_empty());
+        assert!(report.pauses.is_empty());
+    }
 }
@@ -0,0 +1,181 @@
+/// Naive-Bayes-style digraph confusion model: predicts which (preceding,
+/// following) key transitions a user is most likely to mistype, so adaptive
+/// content can target specific error-prone transitions rather than only
+/// weighting individual keys in isolation.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::analytics::AdaptiveAnalytics;
+
+/// Contexts with fewer combined attempts than this fall back to the uniform
+/// prior instead of trusting a noisy per-key error breakdown.
+const MIN_CONTEXT_SAMPLES: usize = 5;
+
+pub struct DigraphConfusionModel;
+
+impl DigraphConfusionModel {
+    /// Predict the `top_n` (preceding, following) key pairs most likely to be
+    /// mistyped, ranked by posterior error probability (highest risk first).
+    pub fn predict_error_prone_digraphs(
+        analytics: &AdaptiveAnalytics,
+        top_n: usize,
+    ) -> Vec<(char, char)> {
+        let contexts = Self::group_by_context(analytics);
+        let mut scored: Vec<((char, char), f64)> = Vec::new();
+
+        for (&context, followers) in &contexts {
+            let num_keys = followers.len();
+            if num_keys == 0 {
+                continue;
+            }
+            let prior = 1.0 / num_keys as f64;
+
+            let total_attempts: usize = followers.values().map(|(total, _)| *total).sum();
+            if total_attempts < MIN_CONTEXT_SAMPLES {
+                // Too little data in this context to trust a per-key
+                // breakdown: every follower here stays at the uniform prior.
+                for &key in followers.keys() {
+                    scored.push(((context, key), prior));
+                }
+                continue;
+            }
+
+            for (&key, &(key_total, key_errors)) in followers {
+                let other_total: usize = followers
+                    .iter()
+                    .filter(|(&other_key, _)| other_key != key)
+                    .map(|(_, &(total, _))| total)
+                    .sum();
+                let other_errors: usize = followers
+                    .iter()
+                    .filter(|(&other_key, _)| other_key != key)
+                    .map(|(_, &(_, errors))| errors)
+                    .sum();
+
+                let p_err_given_key = rate(key_errors, key_total);
+                let p_err_given_other = rate(other_errors, other_total);
+
+                let posterior = bayesian_update(prior, p_err_given_key, p_err_given_other);
+                scored.push(((context, key), posterior));
+            }
+        }
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        scored
+            .into_iter()
+            .take(top_n)
+            .map(|(pair, _)| pair)
+            .collect()
+    }
+
+    /// Group bigram stats by preceding ("context") character, mapping each
+    /// following key to its `(total_attempts, error_count)`.
+    fn group_by_context(
+        analytics: &AdaptiveAnalytics,
+    ) -> HashMap<char, HashMap<char, (usize, usize)>> {
+        let mut contexts: HashMap<char, HashMap<char, (usize, usize)>> = HashMap::new();
+
+        for stats in analytics.bigram_stats.values() {
+            let mut chars = stats.bigram.chars();
+            let context = match chars.next() {
+                Some(c) => c,
+                None => continue,
+            };
+            let key = match chars.next() {
+                Some(c) => c,
+                None => continue,
+            };
+
+            let errors = stats.total_attempts.saturating_sub(stats.correct_attempts);
+            contexts
+                .entry(context)
+                .or_default()
+                .insert(key, (stats.total_attempts, errors));
+        }
+
+        contexts
+    }
+}
+
+/// Error rate for `errors` out of `total`, or `0.0` with no attempts.
+fn rate(errors: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        errors as f64 / total as f64
+    }
+}
+
+/// Single naive-Bayes posterior update:
+/// `p' = (p * p_err_given_key) / (p * p_err_given_key + (1 - p) * p_err_given_other)`
+fn bayesian_update(prior: f64, p_err_given_key: f64, p_err_given_other: f64) -> f64 {
+    let numerator = prior * p_err_given_key;
+    let denominator = numerator + (1.0 - prior) * p_err_given_other;
+
+    if denominator == 0.0 {
+        prior
+    } else {
+        numerator / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::analytics::BigramStats;
+
+    fn with_bigram(
+        analytics: &mut AdaptiveAnalytics,
+        bigram: &str,
+        total_attempts: usize,
+        correct_attempts: usize,
+    ) {
+        let mut stats = BigramStats::new(bigram.to_string());
+        stats.total_attempts = total_attempts;
+        stats.correct_attempts = correct_attempts;
+        analytics.bigram_stats.insert(bigram.to_string(), stats);
+    }
+
+    #[test]
+    fn test_predicts_the_riskier_digraph_first() {
+        let mut analytics = AdaptiveAnalytics::default();
+        // 'th': mostly correct
+        with_bigram(&mut analytics, "th", 20, 19);
+        // 'tr': frequently mistyped
+        with_bigram(&mut analytics, "tr", 20, 5);
+
+        let predicted = DigraphConfusionModel::predict_error_prone_digraphs(&analytics, 2);
+
+        assert_eq!(predicted[0], ('t', 'r'));
+    }
+
+    #[test]
+    fn test_sparse_context_falls_back_to_prior() {
+        let mut analytics = AdaptiveAnalytics::default();
+        // Only 2 total attempts in the 'q' context: too sparse to trust
+        with_bigram(&mut analytics, "qu", 2, 0);
+
+        let predicted = DigraphConfusionModel::predict_error_prone_digraphs(&analytics, 10);
+
+        assert!(predicted.contains(&('q', 'u')));
+    }
+
+    #[test]
+    fn test_top_n_limits_result_count() {
+        let mut analytics = AdaptiveAnalytics::default();
+        with_bigram(&mut analytics, "th", 20, 10);
+        with_bigram(&mut analytics, "tr", 20, 5);
+        with_bigram(&mut analytics, "te", 20, 15);
+
+        let predicted = DigraphConfusionModel::predict_error_prone_digraphs(&analytics, 1);
+
+        assert_eq!(predicted.len(), 1);
+    }
+
+    #[test]
+    fn test_empty_analytics_predicts_nothing() {
+        let analytics = AdaptiveAnalytics::default();
+        let predicted = DigraphConfusionModel::predict_error_prone_digraphs(&analytics, 5);
+        assert!(predicted.is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+/// Edit-distance alignment between expected and typed text
+/// A straight positional comparison of expected vs. typed characters breaks
+/// down once a user drops or adds a character, since every character after
+/// the slip looks "wrong" even though it was typed correctly. Aligning the
+/// two strings with a Needleman-Wunsch edit-distance DP recovers the actual
+/// substitutions, insertions, and deletions, so `AdaptiveAnalytics` can track
+/// *which* key a user actually hits instead of the one they meant to.
+use super::analytics::AdaptiveAnalytics;
+
+/// A single classified difference between an expected and typed segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The typed character matched the expected character.
+    Match(char),
+    /// `expected` was typed as `typed` instead.
+    Substitution { expected: char, typed: char },
+    /// `expected` was never typed (the user skipped over it).
+    Deletion(char),
+    /// `typed` was entered with no corresponding expected character.
+    Insertion(char),
+}
+
+/// Align `expected` against `typed` with the Needleman-Wunsch edit-distance
+/// DP (unit cost for each insertion, deletion, or substitution) and
+/// backtrack the cheapest path into a sequence of classified operations.
+pub fn align(expected: &str, typed: &str) -> Vec<EditOp> {
+    let a: Vec<char> = expected.chars().collect();
+    let b: Vec<char> = typed.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=n {
+        d[0][j] = j;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+        }
+    }
+
+    let mut ops = Vec::with_capacity(m.max(n));
+    let (mut i, mut j) = (m, n);
+
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            if d[i][j] == d[i - 1][j - 1] + cost {
+                ops.push(if cost == 0 {
+                    EditOp::Match(a[i - 1])
+                } else {
+                    EditOp::Substitution {
+                        expected: a[i - 1],
+                        typed: b[j - 1],
+                    }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+
+        if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            ops.push(EditOp::Deletion(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insertion(b[j - 1]));
+            j -= 1;
+        }
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Align `expected` against `typed` and fold any substitutions into
+/// `analytics.substitution_counts`, so repeated neighboring-key confusions
+/// (e.g. `e` typed as `r`) accumulate across sessions.
+pub fn align_and_record(analytics: &mut AdaptiveAnalytics, expected: &str, typed: &str) {
+    for op in align(expected, typed) {
+        if let EditOp::Substitution { expected, typed } = op {
+            analytics.record_substitution(expected, typed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_identical_strings_is_all_matches() {
+        let ops = align("test", "test");
+        assert_eq!(ops.len(), 4);
+        assert!(ops.iter().all(|op| matches!(op, EditOp::Match(_))));
+    }
+
+    #[test]
+    fn test_align_classifies_substitution() {
+        let ops = align("cat", "car");
+        assert!(ops.contains(&EditOp::Substitution {
+            expected: 't',
+            typed: 'r'
+        }));
+    }
+
+    #[test]
+    fn test_align_classifies_deletion() {
+        // User skipped the 'e' in "test"
+        let ops = align("test", "tst");
+        assert!(ops.contains(&EditOp::Deletion('e')));
+    }
+
+    #[test]
+    fn test_align_classifies_insertion() {
+        // User typed an extra 'x' not present in the expected text
+        let ops = align("test", "txest");
+        assert!(ops.contains(&EditOp::Insertion('x')));
+    }
+
+    #[test]
+    fn test_align_and_record_accumulates_substitution_counts() {
+        let mut analytics = AdaptiveAnalytics::default();
+
+        align_and_record(&mut analytics, "cat", "car");
+        align_and_record(&mut analytics, "cat", "car");
+
+        assert_eq!(
+            analytics.substitution_counts.get(&'t').and_then(|m| m.get(&'r')),
+            Some(&2)
+        );
+    }
+}
@@ -1,8 +1,13 @@
 pub mod adaptive;
 pub mod analytics;
+pub mod digraph_model;
+pub mod edit_alignment;
 pub mod scoring;
 pub mod types;
 
 pub use analytics::SessionAnalyzer;
 pub use scoring::calculate_results;
-pub use types::{SessionDuration, TypingSession};
+pub use types::{
+    load_keystroke_timeline, GhostReplay, SessionDuration, SessionExportFormat, SessionResult,
+    TypingSession,
+};
@@ -1,6 +1,11 @@
-use super::types::{SessionResult, TypingSession};
+use super::types::{CharInput, KeyTiming, SessionResult, TypingSession};
+use std::collections::HashMap;
 use std::time::Duration;
 
+/// A key whose average dwell time is at or above this threshold is
+/// reported as a "slow key" in the results screen
+const SLOW_KEY_THRESHOLD_MS: u64 = 180;
+
 /// Calculer les résultats d'une session
 pub fn calculate_results(session: &TypingSession) -> SessionResult {
     let char_count = session.inputs.len();
@@ -12,9 +17,83 @@ pub fn calculate_results(session: &TypingSession) -> SessionResult {
     };
 
     let duration = session.duration();
-    let wpm = calculate_wpm(char_count, duration);
+    let raw_wpm = calculate_wpm(char_count, duration);
+    let net_wpm = calculate_net_wpm(raw_wpm, error_count, duration);
+    let consistency = calculate_consistency(&session.inputs);
+    let slow_keys = find_slow_keys(&session.key_timings);
+
+    SessionResult::new(
+        net_wpm,
+        raw_wpm,
+        accuracy,
+        duration,
+        char_count,
+        error_count,
+        slow_keys,
+        session.rollover_count,
+        consistency,
+    )
+}
+
+/// Net WPM: raw WPM minus an error penalty of one "word" (5 chars) per
+/// uncorrected error, spread over the session's elapsed minutes
+fn calculate_net_wpm(raw_wpm: f64, error_count: usize, duration: Duration) -> f64 {
+    let minutes = duration.as_secs_f64() / 60.0;
+    if minutes <= 0.0 {
+        return 0.0;
+    }
+
+    let penalty = error_count as f64 / minutes;
+    (raw_wpm - penalty).max(0.0)
+}
+
+/// Typing consistency, 0-100: the coefficient of variation (std dev / mean)
+/// of the gaps between consecutive correct keystrokes, inverted so a steadier
+/// pace scores higher. Needs at least two correct keystrokes to be meaningful.
+fn calculate_consistency(inputs: &[CharInput]) -> f64 {
+    let timestamps: Vec<Duration> = inputs
+        .iter()
+        .filter(|i| i.is_correct)
+        .map(|i| i.timestamp)
+        .collect();
+
+    if timestamps.len() < 2 {
+        return 0.0;
+    }
+
+    let intervals: Vec<f64> = timestamps
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).as_secs_f64())
+        .collect();
+
+    let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+
+    let variance =
+        intervals.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / intervals.len() as f64;
+    let coefficient_of_variation = variance.sqrt() / mean;
 
-    SessionResult::new(wpm, accuracy, duration, char_count, error_count)
+    (100.0 - coefficient_of_variation * 100.0).clamp(0.0, 100.0)
+}
+
+/// Find keys whose average dwell time crosses `SLOW_KEY_THRESHOLD_MS`
+fn find_slow_keys(timings: &[KeyTiming]) -> Vec<char> {
+    let mut totals: HashMap<char, (u64, usize)> = HashMap::new();
+    for timing in timings {
+        let entry = totals.entry(timing.key).or_insert((0, 0));
+        entry.0 += timing.dwell.as_millis() as u64;
+        entry.1 += 1;
+    }
+
+    let mut slow_keys: Vec<char> = totals
+        .into_iter()
+        .filter(|(_, (total_ms, count))| total_ms / *count as u64 >= SLOW_KEY_THRESHOLD_MS)
+        .map(|(key, _)| key)
+        .collect();
+    slow_keys.sort_unstable();
+    slow_keys
 }
 
 /// Calculer WPM (Words Per Minute)
@@ -40,6 +119,52 @@ pub fn calculate_accuracy(correct: usize, total: usize) -> f64 {
     (correct as f64 / total as f64) * 100.0
 }
 
+/// Buckets correct keystrokes into 1-second windows and returns each
+/// window's instantaneous WPM: `(chars_in_window / 5) * 60`. Drives the
+/// results screen's WPM-over-time sparkline (`ui::render::render_wpm_sparkline`).
+pub fn wpm_per_second_buckets(inputs: &[CharInput]) -> Vec<f64> {
+    let correct_seconds: Vec<u64> = inputs
+        .iter()
+        .filter(|i| i.is_correct)
+        .map(|i| i.timestamp.as_secs())
+        .collect();
+
+    let Some(&max_second) = correct_seconds.iter().max() else {
+        return Vec::new();
+    };
+
+    let mut buckets = vec![0usize; max_second as usize + 1];
+    for second in correct_seconds {
+        buckets[second as usize] += 1;
+    }
+
+    buckets
+        .into_iter()
+        .map(|chars_in_window| (chars_in_window as f64 / 5.0) * 60.0)
+        .collect()
+}
+
+/// Steadiness of a per-second WPM series, 0-100: `100 * (1 - stddev/mean)`,
+/// clamped. Distinct from `calculate_consistency` (which measures the gaps
+/// between individual keystrokes) — this measures steadiness of pace across
+/// whole seconds, to match what the sparkline shows. Needs at least two
+/// buckets to be meaningful.
+pub fn wpm_series_consistency(series: &[f64]) -> f64 {
+    if series.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = series.iter().sum::<f64>() / series.len() as f64;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+
+    let variance = series.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / series.len() as f64;
+    let stddev = variance.sqrt();
+
+    (100.0 * (1.0 - stddev / mean)).clamp(0.0, 100.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,9 +227,111 @@ mod tests {
         assert_eq!(result.char_count, 5);
         assert_eq!(result.error_count, 1);
         assert_eq!(result.accuracy, 80.0); // 4/5 = 80%
-        // La durée devrait être au moins 100ms
+                                           // La durée devrait être au moins 100ms
         assert!(result.duration.as_millis() >= 100);
         // Avec au moins 100ms et 5 caractères, WPM devrait être > 0
-        assert!(result.wpm > 0.0, "WPM was {} for duration {:?}", result.wpm, result.duration);
+        assert!(
+            result.wpm > 0.0,
+            "WPM was {} for duration {:?}",
+            result.wpm,
+            result.duration
+        );
+    }
+
+    #[test]
+    fn test_calculate_net_wpm_subtracts_error_penalty() {
+        // 20 raw wpm, 2 errors over 1 minute = 2 wpm penalty
+        let net = calculate_net_wpm(20.0, 2, Duration::from_secs(60));
+        assert!((net - 18.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_calculate_net_wpm_never_negative() {
+        let net = calculate_net_wpm(5.0, 100, Duration::from_secs(60));
+        assert_eq!(net, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_net_wpm_zero_duration() {
+        let net = calculate_net_wpm(20.0, 2, Duration::from_secs(0));
+        assert_eq!(net, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_consistency_perfectly_steady() {
+        let inputs = vec![
+            CharInput::new('a', 'a', Duration::from_millis(100)),
+            CharInput::new('b', 'b', Duration::from_millis(200)),
+            CharInput::new('c', 'c', Duration::from_millis(300)),
+        ];
+
+        // Every interval is identical (100ms), so variation is zero and the
+        // score should be the maximum
+        assert_eq!(calculate_consistency(&inputs), 100.0);
+    }
+
+    #[test]
+    fn test_calculate_consistency_ignores_incorrect_keystrokes() {
+        let inputs = vec![
+            CharInput::new('a', 'a', Duration::from_millis(100)),
+            CharInput::new('b', 'x', Duration::from_millis(150)), // incorrect, skipped
+            CharInput::new('c', 'c', Duration::from_millis(200)),
+        ];
+
+        assert_eq!(calculate_consistency(&inputs), 100.0);
+    }
+
+    #[test]
+    fn test_calculate_consistency_fewer_than_two_correct_is_zero() {
+        let inputs = vec![CharInput::new('a', 'a', Duration::from_millis(100))];
+        assert_eq!(calculate_consistency(&inputs), 0.0);
+    }
+
+    #[test]
+    fn test_find_slow_keys_flags_high_dwell() {
+        let timings = vec![
+            KeyTiming {
+                key: 'a',
+                dwell: Duration::from_millis(250),
+                flight: None,
+            },
+            KeyTiming {
+                key: 'b',
+                dwell: Duration::from_millis(50),
+                flight: Some(Duration::from_millis(80)),
+            },
+        ];
+
+        assert_eq!(find_slow_keys(&timings), vec!['a']);
+    }
+
+    #[test]
+    fn test_wpm_per_second_buckets_empty_with_no_correct_keystrokes() {
+        let inputs = vec![CharInput::new('a', 'x', Duration::from_millis(100))];
+        assert!(wpm_per_second_buckets(&inputs).is_empty());
+    }
+
+    #[test]
+    fn test_wpm_per_second_buckets_groups_by_whole_second() {
+        let inputs = vec![
+            CharInput::new('a', 'a', Duration::from_millis(100)),
+            CharInput::new('b', 'b', Duration::from_millis(900)),
+            CharInput::new('c', 'c', Duration::from_millis(1500)),
+        ];
+
+        let buckets = wpm_per_second_buckets(&inputs);
+        assert_eq!(buckets.len(), 2);
+        assert!((buckets[0] - 24.0).abs() < 0.01); // 2 chars in second 0: (2/5)*60
+        assert!((buckets[1] - 12.0).abs() < 0.01); // 1 char in second 1: (1/5)*60
+    }
+
+    #[test]
+    fn test_wpm_series_consistency_perfectly_steady() {
+        assert_eq!(wpm_series_consistency(&[40.0, 40.0, 40.0]), 100.0);
+    }
+
+    #[test]
+    fn test_wpm_series_consistency_fewer_than_two_buckets_is_zero() {
+        assert_eq!(wpm_series_consistency(&[40.0]), 0.0);
     }
 }
@@ -1,6 +1,12 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
 use std::time::{Duration, Instant};
 
-/// Session duration presets
+/// Session duration presets, plus an arbitrary user-supplied limit parsed by
+/// `FromStr` (e.g. from a `--time` CLI flag). A `Custom` duration of zero
+/// means "unlimited": `TypingSession::is_complete` falls back to content
+/// exhaustion only in that case.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum SessionDuration {
@@ -9,6 +15,7 @@ pub enum SessionDuration {
     FiveMinutes,
     TenMinutes,
     FifteenMinutes,
+    Custom(Duration),
 }
 
 impl SessionDuration {
@@ -19,6 +26,7 @@ impl SessionDuration {
             Self::FiveMinutes => Duration::from_secs(5 * 60),
             Self::TenMinutes => Duration::from_secs(10 * 60),
             Self::FifteenMinutes => Duration::from_secs(15 * 60),
+            Self::Custom(duration) => *duration,
         }
     }
 
@@ -32,24 +40,177 @@ impl SessionDuration {
         ]
     }
 
-    pub fn label(&self) -> &str {
+    pub fn label(&self) -> String {
         match self {
-            Self::TwoMinutes => "2 minutes",
-            Self::ThreeMinutes => "3 minutes",
-            Self::FiveMinutes => "5 minutes",
-            Self::TenMinutes => "10 minutes",
-            Self::FifteenMinutes => "15 minutes",
+            Self::TwoMinutes => "2 minutes".to_string(),
+            Self::ThreeMinutes => "3 minutes".to_string(),
+            Self::FiveMinutes => "5 minutes".to_string(),
+            Self::TenMinutes => "10 minutes".to_string(),
+            Self::FifteenMinutes => "15 minutes".to_string(),
+            Self::Custom(duration) => custom_label(*duration),
         }
     }
 }
 
+impl std::str::FromStr for SessionDuration {
+    type Err = String;
+
+    /// Parse a user-supplied time string into `Self::Custom`. Accepts
+    /// colon-delimited `HH:MM:SS`, `MM:SS`, or `:SS` (seconds always
+    /// present, a decimal part allowed with `.` or `,`), or suffix forms
+    /// like `90s`, `3m`, `1m30s`. Surrounding whitespace is trimmed; empty
+    /// or unparseable input is a clear `Err`. A parsed value of exactly zero
+    /// seconds is valid and means "unlimited".
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return Err("duration string is empty".to_string());
+        }
+
+        let seconds = if trimmed.contains(':') {
+            parse_colon_form(trimmed)?
+        } else {
+            parse_suffix_form(trimmed)?
+        };
+
+        Duration::try_from_secs_f64(seconds)
+            .map(Self::Custom)
+            .map_err(|_| format!("invalid duration '{trimmed}': value out of range"))
+    }
+}
+
+/// Parse `HH:MM:SS`, `MM:SS`, or `:SS` into a total seconds count. The
+/// seconds component is required (and may carry a `.`/`,` decimal part);
+/// minutes/hours components, if present, may be left empty (as in `:SS`) to
+/// mean zero.
+fn parse_colon_form(trimmed: &str) -> Result<f64, String> {
+    let parts: Vec<&str> = trimmed.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return Err(format!(
+            "invalid duration '{trimmed}': expected HH:MM:SS, MM:SS, or :SS"
+        ));
+    }
+
+    let seconds_part = parts[parts.len() - 1];
+    if seconds_part.is_empty() {
+        return Err(format!("invalid duration '{trimmed}': missing seconds"));
+    }
+    let seconds: f64 = seconds_part
+        .replace(',', ".")
+        .parse()
+        .map_err(|_| format!("invalid seconds '{seconds_part}' in duration '{trimmed}'"))?;
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(format!(
+            "invalid duration '{trimmed}': seconds must be a finite, non-negative number"
+        ));
+    }
+
+    // Whatever's left of the seconds component is hours/minutes, smallest
+    // (minutes) unit last: one part -> minutes only (`MM:SS`/`:SS`), two
+    // parts -> hours then minutes (`HH:MM:SS`).
+    let whole_parts = &parts[..parts.len() - 1];
+    const UNIT_SECONDS: [u64; 2] = [3600, 60];
+    let offset = UNIT_SECONDS.len() - whole_parts.len();
+
+    let mut total = seconds;
+    for (i, part) in whole_parts.iter().enumerate() {
+        let value: u64 = if part.is_empty() {
+            0
+        } else {
+            part.parse()
+                .map_err(|_| format!("invalid duration component '{part}' in '{trimmed}'"))?
+        };
+        let unit_secs = value
+            .checked_mul(UNIT_SECONDS[offset + i])
+            .ok_or_else(|| format!("invalid duration '{trimmed}': value out of range"))?;
+        total += unit_secs as f64;
+    }
+
+    Ok(total)
+}
+
+/// Parse a sequence of `<number><unit>` suffix pairs (`h`/`m`/`s`) like
+/// `90s`, `3m`, or `1m30s` into a total seconds count.
+fn parse_suffix_form(trimmed: &str) -> Result<f64, String> {
+    let bytes = trimmed.as_bytes();
+    let mut total = 0.0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.' || bytes[i] == b',') {
+            i += 1;
+        }
+        if i == start {
+            return Err(format!("invalid duration '{trimmed}'"));
+        }
+        let number_str = &trimmed[start..i];
+
+        if i >= bytes.len() {
+            return Err(format!(
+                "invalid duration '{trimmed}': missing unit after '{number_str}'"
+            ));
+        }
+        let unit = bytes[i] as char;
+        i += 1;
+
+        let value: f64 = number_str
+            .replace(',', ".")
+            .parse()
+            .map_err(|_| format!("invalid number '{number_str}' in duration '{trimmed}'"))?;
+        if !value.is_finite() || value < 0.0 {
+            return Err(format!(
+                "invalid duration '{trimmed}': '{number_str}' must be a finite, non-negative number"
+            ));
+        }
+
+        let multiplier = match unit {
+            'h' => 3600.0,
+            'm' => 60.0,
+            's' => 1.0,
+            other => return Err(format!("invalid duration unit '{other}' in '{trimmed}'")),
+        };
+
+        total += value * multiplier;
+    }
+
+    Ok(total)
+}
+
+/// Display label for a `SessionDuration::Custom`, `"Unlimited"` for zero
+fn custom_label(duration: Duration) -> String {
+    if duration.is_zero() {
+        return "Unlimited".to_string();
+    }
+
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let whole_seconds = total_secs % 60;
+
+    // Keep any fractional part (e.g. a ":1.5" custom duration) instead of
+    // silently truncating it away, so the label matches `as_duration()`;
+    // whole-second durations keep the plain "M:SS" form.
+    let seconds_label = if duration.subsec_nanos() == 0 {
+        format!("{whole_seconds:02}")
+    } else {
+        let fractional_seconds = whole_seconds as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0;
+        format!("{fractional_seconds:04.1}")
+    };
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds_label}")
+    } else {
+        format!("{minutes}:{seconds_label}")
+    }
+}
+
 /// Représente une frappe de caractère individuelle
 #[derive(Debug, Clone)]
 pub struct CharInput {
     #[allow(dead_code)]
     pub expected: char,
     pub typed: char,
-    #[allow(dead_code)]
     pub timestamp: Duration,
     pub is_correct: bool,
 }
@@ -65,6 +226,17 @@ impl CharInput {
     }
 }
 
+/// Dwell/flight timing for a single keystroke, recorded from kitty keyboard
+/// protocol press/release events (`TypingSession::record_key_down`/`record_key_up`)
+#[derive(Debug, Clone)]
+pub struct KeyTiming {
+    pub key: char,
+    /// How long the key was held down (press to release)
+    pub dwell: Duration,
+    /// Gap since the previous key was released, if any
+    pub flight: Option<Duration>,
+}
+
 /// Session de typing en cours
 #[derive(Debug)]
 pub struct TypingSession {
@@ -75,6 +247,13 @@ pub struct TypingSession {
     pub end_time: Option<Instant>,
     pub duration_limit: Duration,
     pub content_buffer_size: usize,
+    /// Per-keystroke dwell/flight timings, populated when the terminal
+    /// advertises kitty keyboard protocol support (release events available)
+    pub key_timings: Vec<KeyTiming>,
+    /// Number of times a key press arrived while another key was still held
+    pub rollover_count: usize,
+    pub(crate) held_keys: HashMap<char, Instant>,
+    pub(crate) last_key_up_time: Option<Instant>,
 }
 
 impl TypingSession {
@@ -88,6 +267,39 @@ impl TypingSession {
             end_time: None,
             duration_limit: duration,
             content_buffer_size: buffer_size,
+            key_timings: Vec::new(),
+            rollover_count: 0,
+            held_keys: HashMap::new(),
+            last_key_up_time: None,
+        }
+    }
+
+    /// Record a key press (or repeat) for dwell-time tracking. Only called
+    /// when the terminal reports kitty keyboard protocol release events.
+    pub fn record_key_down(&mut self, key: char) {
+        if !self.held_keys.contains_key(&key) {
+            if !self.held_keys.is_empty() {
+                // Another key was still held when this one was pressed
+                self.rollover_count += 1;
+            }
+            self.held_keys.insert(key, Instant::now());
+        }
+    }
+
+    /// Record a key release, completing its dwell-time measurement
+    pub fn record_key_up(&mut self, key: char) {
+        if let Some(pressed_at) = self.held_keys.remove(&key) {
+            let now = Instant::now();
+            let flight = self
+                .last_key_up_time
+                .map(|prev| pressed_at.saturating_duration_since(prev));
+
+            self.key_timings.push(KeyTiming {
+                key,
+                dwell: now.saturating_duration_since(pressed_at),
+                flight,
+            });
+            self.last_key_up_time = Some(now);
         }
     }
 
@@ -127,6 +339,54 @@ impl TypingSession {
         is_correct
     }
 
+    /// Score a fully-buffered chord (for `LessonType::Chord` lessons) against the
+    /// next whitespace-delimited token in `content`. A chord is correct only if the
+    /// exact key set was pressed; mistimed/partial chords are recorded as errors.
+    /// The caller (`App`) owns the chord-timeout buffering and calls this once a
+    /// chord is committed, whether by completion or by timeout.
+    pub fn add_chord_input(&mut self, pressed: &[char]) -> bool {
+        if self.start_time.is_none() {
+            self.start_time = Some(Instant::now());
+        }
+
+        if self.is_complete() {
+            return false;
+        }
+
+        let chars: Vec<char> = self.content.chars().collect();
+        let mut end = self.current_index;
+        while end < chars.len() && chars[end] != ' ' {
+            end += 1;
+        }
+        let expected = &chars[self.current_index..end];
+
+        let mut expected_sorted = expected.to_vec();
+        expected_sorted.sort_unstable();
+        let mut pressed_sorted = pressed.to_vec();
+        pressed_sorted.sort_unstable();
+        let is_correct = expected_sorted == pressed_sorted;
+
+        let elapsed = self
+            .start_time
+            .map(|start| start.elapsed())
+            .unwrap_or_default();
+
+        for &expected_key in expected {
+            let typed_key = if is_correct { expected_key } else { '\0' };
+            self.inputs
+                .push(CharInput::new(expected_key, typed_key, elapsed));
+        }
+
+        // Advance past the chord and its trailing separator space
+        self.current_index = (end + 1).min(chars.len());
+
+        if self.is_complete() {
+            self.end_time = Some(Instant::now());
+        }
+
+        is_correct
+    }
+
     pub fn remove_last_input(&mut self) -> bool {
         if self.current_index > 0 && !self.inputs.is_empty() {
             self.inputs.pop();
@@ -139,9 +399,11 @@ impl TypingSession {
     }
 
     pub fn is_complete(&self) -> bool {
-        // Complete when time expires OR content exhausted
+        // Complete when time expires OR content exhausted. A zero
+        // `duration_limit` (a custom "unlimited" session) never expires on
+        // time, so it falls straight through to content exhaustion.
         if let Some(start) = self.start_time {
-            if start.elapsed() >= self.duration_limit {
+            if !self.duration_limit.is_zero() && start.elapsed() >= self.duration_limit {
                 return true;
             }
         }
@@ -158,7 +420,20 @@ impl TypingSession {
         }
     }
 
+    /// Whether this session has no time limit (a `SessionDuration::Custom`
+    /// of zero). Callers displaying `remaining_time()` as a countdown should
+    /// check this first, since `remaining_time()` returns `Duration::ZERO`
+    /// for an unlimited session too and the two aren't the same thing.
+    pub fn is_unlimited(&self) -> bool {
+        self.duration_limit.is_zero()
+    }
+
     pub fn remaining_time(&self) -> Duration {
+        if self.duration_limit.is_zero() {
+            // Unlimited custom session: there's no countdown to report
+            return Duration::ZERO;
+        }
+
         match self.start_time {
             Some(start) => {
                 let elapsed = start.elapsed();
@@ -185,38 +460,545 @@ impl TypingSession {
     }
 }
 
+/// Replays a prior attempt's keystroke timing against the current lesson,
+/// driving a dimmed "ghost" cursor so a live session has a concrete pace
+/// target drawn from the player's own history (`Stats`/`SessionRecord`).
+#[derive(Debug, Clone)]
+pub struct GhostReplay {
+    /// Elapsed time (ms since the attempt started) of each keystroke, in order
+    keystrokes: Vec<u64>,
+}
+
+impl GhostReplay {
+    pub fn new(keystrokes: Vec<u64>) -> Self {
+        Self { keystrokes }
+    }
+
+    /// How many characters the ghost has typed by `elapsed`
+    pub fn index_at(&self, elapsed: Duration) -> usize {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        self.keystrokes
+            .iter()
+            .take_while(|&&t| t <= elapsed_ms)
+            .count()
+    }
+
+    /// The ghost's WPM at `elapsed`, using the standard (chars / 5) / minutes formula
+    pub fn wpm_at(&self, elapsed: Duration) -> f64 {
+        let elapsed_secs = elapsed.as_secs_f64();
+        if elapsed_secs <= 0.0 {
+            return 0.0;
+        }
+        (self.index_at(elapsed) as f64 / 5.0) / (elapsed_secs / 60.0)
+    }
+}
+
 /// Résultats d'une session complétée
 #[derive(Debug, Clone)]
 pub struct SessionResult {
+    /// Net WPM: raw WPM minus an error penalty. The headline figure.
     pub wpm: f64,
+    /// Raw WPM: (chars / 5) / minutes, with no error penalty applied
+    pub raw_wpm: f64,
     pub accuracy: f64,
     pub duration: Duration,
     #[allow(dead_code)]
     pub char_count: usize,
     pub error_count: usize,
+    /// Keys whose average dwell time crossed the slow-key threshold
+    pub slow_keys: Vec<char>,
+    /// Accidental n-key-rollover events (a key still held when the next was pressed)
+    pub rollover_count: usize,
+    /// Steadiness of pace, 0-100, derived from the coefficient of variation of
+    /// the gaps between consecutive correct keystrokes. Higher is steadier.
+    pub consistency: f64,
 }
 
 impl SessionResult {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         wpm: f64,
+        raw_wpm: f64,
         accuracy: f64,
         duration: Duration,
         char_count: usize,
         error_count: usize,
+        slow_keys: Vec<char>,
+        rollover_count: usize,
+        consistency: f64,
     ) -> Self {
         Self {
             wpm,
+            raw_wpm,
             accuracy,
             duration,
             char_count,
             error_count,
+            slow_keys,
+            rollover_count,
+            consistency,
+        }
+    }
+}
+
+/// One word's timing/accuracy breakdown, built from the `CharInput`s typed
+/// for it. See `TypingSession::word_breakdown`.
+#[derive(Debug, Clone)]
+pub struct WordBreakdown {
+    pub word: String,
+    /// Timestamp (since the session started) of the word's first keystroke
+    pub started_at: Duration,
+    /// Timestamp (since the session started) of the word's last keystroke
+    pub finished_at: Duration,
+    pub error_count: usize,
+}
+
+/// Output format for a single completed session's machine-readable export
+/// (`TypingSession::export_result`). Distinct from
+/// `data::storage::ExportFormat`, which exports the *saved session
+/// history* rather than one live session's full keystroke-level detail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionExportFormat {
+    Json,
+    Csv,
+    /// One line per keystroke, `MM:SS.mmm <expected> <typed> <ok|err>`,
+    /// round-trippable back into a `Vec<CharInput>` via
+    /// `load_keystroke_timeline` for ghost replay.
+    Timeline,
+}
+
+impl TypingSession {
+    /// Split this session's typed keystrokes into per-word timing/accuracy
+    /// breakdowns, for the machine-readable export below. Words are
+    /// delimited by literal spaces in `content`; a session ended mid-word
+    /// still reports that partial final word.
+    pub fn word_breakdown(&self) -> Vec<WordBreakdown> {
+        let mut words = Vec::new();
+        let mut current_word = String::new();
+        let mut current_errors = 0;
+        let mut word_start: Option<Duration> = None;
+        let mut word_end = Duration::default();
+
+        for input in &self.inputs {
+            if input.expected == ' ' {
+                if !current_word.is_empty() {
+                    words.push(WordBreakdown {
+                        word: std::mem::take(&mut current_word),
+                        started_at: word_start.take().unwrap_or_default(),
+                        finished_at: word_end,
+                        error_count: current_errors,
+                    });
+                    current_errors = 0;
+                }
+                continue;
+            }
+
+            word_start.get_or_insert(input.timestamp);
+            word_end = input.timestamp;
+            current_word.push(input.expected);
+            if !input.is_correct {
+                current_errors += 1;
+            }
+        }
+
+        if !current_word.is_empty() {
+            words.push(WordBreakdown {
+                word: current_word,
+                started_at: word_start.unwrap_or_default(),
+                finished_at: word_end,
+                error_count: current_errors,
+            });
+        }
+
+        words
+    }
+
+    /// Export this completed session's full per-keystroke and per-word
+    /// breakdown in machine-readable form, for piping into external
+    /// dashboards or CI instead of only the TUI results screen.
+    pub fn export_result(
+        &self,
+        result: &SessionResult,
+        format: SessionExportFormat,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        match format {
+            SessionExportFormat::Json => self.export_result_json(result, writer),
+            SessionExportFormat::Csv => self.export_result_csv(writer),
+            SessionExportFormat::Timeline => self.export_result_timeline(writer),
+        }
+    }
+
+    fn export_result_json(&self, result: &SessionResult, writer: impl Write) -> io::Result<()> {
+        #[derive(Serialize)]
+        struct CharInputExport {
+            expected: char,
+            typed: char,
+            timestamp_ms: u64,
+            is_correct: bool,
+        }
+
+        #[derive(Serialize)]
+        struct WordBreakdownExport {
+            word: String,
+            started_at_ms: u64,
+            finished_at_ms: u64,
+            error_count: usize,
+        }
+
+        #[derive(Serialize)]
+        struct SessionResultExport {
+            wpm: f64,
+            raw_wpm: f64,
+            accuracy: f64,
+            duration_ms: u64,
+            char_count: usize,
+            error_count: usize,
+            slow_keys: Vec<char>,
+            rollover_count: usize,
+            consistency: f64,
+        }
+
+        #[derive(Serialize)]
+        struct SessionExport {
+            result: SessionResultExport,
+            keystrokes: Vec<CharInputExport>,
+            words: Vec<WordBreakdownExport>,
+        }
+
+        let export = SessionExport {
+            result: SessionResultExport {
+                wpm: result.wpm,
+                raw_wpm: result.raw_wpm,
+                accuracy: result.accuracy,
+                duration_ms: result.duration.as_millis() as u64,
+                char_count: result.char_count,
+                error_count: result.error_count,
+                slow_keys: result.slow_keys.clone(),
+                rollover_count: result.rollover_count,
+                consistency: result.consistency,
+            },
+            keystrokes: self
+                .inputs
+                .iter()
+                .map(|input| CharInputExport {
+                    expected: input.expected,
+                    typed: input.typed,
+                    timestamp_ms: input.timestamp.as_millis() as u64,
+                    is_correct: input.is_correct,
+                })
+                .collect(),
+            words: self
+                .word_breakdown()
+                .into_iter()
+                .map(|word| WordBreakdownExport {
+                    word: word.word,
+                    started_at_ms: word.started_at.as_millis() as u64,
+                    finished_at_ms: word.finished_at.as_millis() as u64,
+                    error_count: word.error_count,
+                })
+                .collect(),
+        };
+
+        serde_json::to_writer_pretty(writer, &export).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize session: {}", e),
+            )
+        })
+    }
+
+    /// One row per word: `word,started_at_ms,finished_at_ms,error_count`
+    fn export_result_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "word,started_at_ms,finished_at_ms,error_count")?;
+
+        for word in self.word_breakdown() {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                csv_field(&word.word),
+                word.started_at.as_millis(),
+                word.finished_at.as_millis(),
+                word.error_count
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// One line per keystroke: `MM:SS.mmm <expected> <typed> <ok|err>`,
+    /// loadable back via `load_keystroke_timeline` for a "ghost replay".
+    fn export_result_timeline(&self, mut writer: impl Write) -> io::Result<()> {
+        for input in &self.inputs {
+            writeln!(
+                writer,
+                "{} {} {} {}",
+                format_timeline_timestamp(input.timestamp),
+                timeline_char_token(input.expected),
+                timeline_char_token(input.typed),
+                if input.is_correct { "ok" } else { "err" }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// `elapsed` as `MM:SS.mmm`, minutes uncapped (a session can run past 99:59)
+fn format_timeline_timestamp(elapsed: Duration) -> String {
+    let total_millis = elapsed.as_millis();
+    let minutes = total_millis / 60_000;
+    let seconds = (total_millis / 1_000) % 60;
+    let millis = total_millis % 1_000;
+    format!("{minutes:02}:{seconds:02}.{millis:03}")
+}
+
+/// Parse a `MM:SS.mmm`-style timestamp, tolerating a missing or rounded
+/// (fewer than 3 digits) fractional part, e.g. `MM:SS` or `MM:SS.5`
+fn parse_timeline_timestamp(raw: &str) -> Option<Duration> {
+    let (minutes_secs, fraction) = raw.split_once('.').unwrap_or((raw, ""));
+    let (minutes, seconds) = minutes_secs.split_once(':')?;
+
+    let minutes: u64 = minutes.parse().ok()?;
+    let seconds: u64 = seconds.parse().ok()?;
+    if seconds >= 60 {
+        return None;
+    }
+
+    // Take at most the first 3 *characters*, not bytes: a corrupted line
+    // could carry a multi-byte char here and a byte-count slice would panic.
+    let fraction: String = fraction.chars().take(3).collect();
+    let millis: u64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<3}").parse().ok()?
+    };
+
+    Some(Duration::from_millis(minutes * 60_000 + seconds * 1_000 + millis))
+}
+
+/// Encode a single keystroke character as a whitespace-free token, since the
+/// timeline format is itself whitespace-delimited: any `char::is_whitespace`
+/// character (space, tab, newline, a non-breaking space from pasted prose,
+/// ...) would otherwise collapse into `str::split_whitespace`'s field
+/// separators and desync the line. Common cases get a readable escape; any
+/// other whitespace falls back to a `\u{XXXX}` hex escape.
+fn timeline_char_token(c: char) -> String {
+    match c {
+        ' ' => "\\s".to_string(),
+        '\t' => "\\t".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        other if other.is_whitespace() => format!("\\u{{{:x}}}", other as u32),
+        other => other.to_string(),
+    }
+}
+
+/// Inverse of `timeline_char_token`
+fn parse_timeline_char_token(token: &str) -> Option<char> {
+    match token {
+        "\\s" => Some(' '),
+        "\\t" => Some('\t'),
+        "\\n" => Some('\n'),
+        "\\r" => Some('\r'),
+        other => {
+            if let Some(hex) = other.strip_prefix("\\u{").and_then(|s| s.strip_suffix('}')) {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else {
+                other.chars().next()
+            }
+        }
+    }
+}
+
+/// Parse one `MM:SS.mmm <expected> <typed> <ok|err>` line back into a
+/// `CharInput`. The trailing `ok`/`err` flag is read but not trusted:
+/// `is_correct` is recomputed from `expected == typed` (via `CharInput::new`)
+/// so a hand-edited timeline can't desync the flag from the actual replay.
+fn parse_timeline_line(line: &str) -> Option<CharInput> {
+    let mut fields = line.split_whitespace();
+    let timestamp = parse_timeline_timestamp(fields.next()?)?;
+    let expected = parse_timeline_char_token(fields.next()?)?;
+    let typed = parse_timeline_char_token(fields.next()?)?;
+    fields.next()?; // ok|err, informational only
+
+    Some(CharInput::new(expected, typed, timestamp))
+}
+
+/// Load a keystroke timeline previously written by
+/// `TypingSession::export_result` with `SessionExportFormat::Timeline` back
+/// into a `Vec<CharInput>`, for replaying a saved attempt as a ghost.
+/// Blank and unparseable lines are skipped rather than failing the whole
+/// load, so a hand-edited or partially-truncated timeline still replays.
+pub fn load_keystroke_timeline(reader: impl BufRead) -> io::Result<Vec<CharInput>> {
+    let mut inputs = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(input) = parse_timeline_line(trimmed) {
+            inputs.push(input);
         }
     }
+    Ok(inputs)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_session_duration_parses_mm_ss() {
+        assert_eq!(
+            SessionDuration::from_str("2:30").unwrap().as_duration(),
+            Duration::from_secs(150)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_parses_hh_mm_ss() {
+        assert_eq!(
+            SessionDuration::from_str("1:02:03").unwrap().as_duration(),
+            Duration::from_secs(3723)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_parses_seconds_only_colon_form() {
+        assert_eq!(
+            SessionDuration::from_str(":45").unwrap().as_duration(),
+            Duration::from_secs(45)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_parses_decimal_seconds_with_period_or_comma() {
+        assert_eq!(
+            SessionDuration::from_str(":1.5").unwrap().as_duration(),
+            Duration::from_secs_f64(1.5)
+        );
+        assert_eq!(
+            SessionDuration::from_str(":1,5").unwrap().as_duration(),
+            Duration::from_secs_f64(1.5)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_parses_suffix_forms() {
+        assert_eq!(
+            SessionDuration::from_str("90s").unwrap().as_duration(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            SessionDuration::from_str("3m").unwrap().as_duration(),
+            Duration::from_secs(180)
+        );
+        assert_eq!(
+            SessionDuration::from_str("1m30s").unwrap().as_duration(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            SessionDuration::from_str("1h").unwrap().as_duration(),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_trims_whitespace() {
+        assert_eq!(
+            SessionDuration::from_str("  90s  ").unwrap().as_duration(),
+            Duration::from_secs(90)
+        );
+    }
+
+    #[test]
+    fn test_session_duration_rejects_empty_input() {
+        assert!(SessionDuration::from_str("").is_err());
+        assert!(SessionDuration::from_str("   ").is_err());
+    }
+
+    #[test]
+    fn test_session_duration_rejects_garbage_input() {
+        assert!(SessionDuration::from_str("not a duration").is_err());
+        assert!(SessionDuration::from_str("90x").is_err());
+        assert!(SessionDuration::from_str("1:2:3:4").is_err());
+    }
+
+    #[test]
+    fn test_session_duration_rejects_non_finite_seconds() {
+        assert!(SessionDuration::from_str(":inf").is_err());
+        assert!(SessionDuration::from_str(":nan").is_err());
+        assert!(SessionDuration::from_str(":-inf").is_err());
+        assert!(SessionDuration::from_str("infs").is_err());
+        assert!(SessionDuration::from_str("nans").is_err());
+    }
+
+    #[test]
+    fn test_session_duration_rejects_negative_seconds() {
+        assert!(SessionDuration::from_str(":-5").is_err());
+    }
+
+    #[test]
+    fn test_session_duration_rejects_out_of_range_seconds_instead_of_panicking() {
+        assert!(SessionDuration::from_str(":1e300").is_err());
+    }
+
+    #[test]
+    fn test_session_duration_rejects_overflowing_component_instead_of_panicking() {
+        assert!(SessionDuration::from_str("18446744073709551615:00").is_err());
+    }
+
+    #[test]
+    fn test_typing_session_is_unlimited() {
+        let unlimited = TypingSession::new("ab".to_string(), Duration::ZERO);
+        assert!(unlimited.is_unlimited());
+
+        let limited = TypingSession::new("ab".to_string(), Duration::from_secs(60));
+        assert!(!limited.is_unlimited());
+    }
+
+    #[test]
+    fn test_session_duration_zero_is_unlimited_label() {
+        assert_eq!(SessionDuration::from_str(":0").unwrap().label(), "Unlimited");
+    }
+
+    #[test]
+    fn test_session_duration_label_keeps_fractional_seconds() {
+        assert_eq!(SessionDuration::from_str(":1.5").unwrap().label(), "0:01.5");
+    }
+
+    #[test]
+    fn test_session_duration_label_whole_seconds_has_no_decimal() {
+        assert_eq!(SessionDuration::from_str("2:30").unwrap().label(), "2:30");
+    }
+
+    #[test]
+    fn test_session_duration_all_routes_through_as_duration() {
+        for preset in SessionDuration::all() {
+            assert!(preset.as_duration() > Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_typing_session_unlimited_duration_never_expires_on_time() {
+        let mut session = TypingSession::new("ab".to_string(), Duration::ZERO);
+        session.add_input('a');
+        assert!(!session.is_complete());
+        session.add_input('b');
+        assert!(session.is_complete());
+    }
 
     #[test]
     fn test_char_input_correct() {
@@ -243,6 +1025,33 @@ mod tests {
         assert!(session.start_time.is_none());
         assert_eq!(session.duration_limit, Duration::from_secs(60));
         assert_eq!(session.content_buffer_size, 5);
+        assert!(session.key_timings.is_empty());
+        assert_eq!(session.rollover_count, 0);
+    }
+
+    #[test]
+    fn test_record_key_down_up_tracks_dwell() {
+        let mut session = TypingSession::new("hi".to_string(), Duration::from_secs(60));
+
+        session.record_key_down('h');
+        session.record_key_up('h');
+
+        assert_eq!(session.key_timings.len(), 1);
+        assert_eq!(session.key_timings[0].key, 'h');
+        assert!(session.key_timings[0].flight.is_none());
+    }
+
+    #[test]
+    fn test_record_key_down_detects_rollover() {
+        let mut session = TypingSession::new("hi".to_string(), Duration::from_secs(60));
+
+        session.record_key_down('h');
+        session.record_key_down('i'); // 'h' still held
+        assert_eq!(session.rollover_count, 1);
+
+        session.record_key_up('h');
+        session.record_key_up('i');
+        assert_eq!(session.key_timings.len(), 2);
     }
 
     #[test]
@@ -310,6 +1119,41 @@ mod tests {
         assert_eq!(session.inputs.len(), 0);
     }
 
+    #[test]
+    fn test_add_chord_input_correct() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+
+        assert!(session.add_chord_input(&['d', 'f']));
+        assert_eq!(session.current_index, 3); // past "fd "
+        assert_eq!(session.inputs.len(), 2);
+        assert!(session.inputs.iter().all(|i| i.is_correct));
+    }
+
+    #[test]
+    fn test_add_chord_input_wrong_keys_recorded_as_errors() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+
+        assert!(!session.add_chord_input(&['f', 'x']));
+        assert_eq!(session.inputs.len(), 2);
+        assert!(session.inputs.iter().all(|i| !i.is_correct));
+    }
+
+    #[test]
+    fn test_ghost_replay_index_at_tracks_elapsed_keystrokes() {
+        let ghost = GhostReplay::new(vec![100, 250, 400]);
+
+        assert_eq!(ghost.index_at(Duration::from_millis(50)), 0);
+        assert_eq!(ghost.index_at(Duration::from_millis(100)), 1);
+        assert_eq!(ghost.index_at(Duration::from_millis(300)), 2);
+        assert_eq!(ghost.index_at(Duration::from_millis(500)), 3);
+    }
+
+    #[test]
+    fn test_ghost_replay_wpm_at_zero_elapsed_is_zero() {
+        let ghost = GhostReplay::new(vec![100, 250]);
+        assert_eq!(ghost.wpm_at(Duration::ZERO), 0.0);
+    }
+
     #[test]
     fn test_typing_session_backspace_after_completion() {
         let mut session = TypingSession::new("ab".to_string(), Duration::from_secs(60));
@@ -324,4 +1168,259 @@ mod tests {
         assert!(!session.is_complete());
         assert!(session.end_time.is_none());
     }
+
+    #[test]
+    fn test_word_breakdown_splits_on_spaces() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+        for c in "fd jk".chars() {
+            session.add_input(c);
+        }
+
+        let words = session.word_breakdown();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "fd");
+        assert_eq!(words[0].error_count, 0);
+        assert_eq!(words[1].word, "jk");
+        assert_eq!(words[1].error_count, 0);
+    }
+
+    #[test]
+    fn test_word_breakdown_counts_errors_and_partial_final_word() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+        session.add_input('f');
+        session.add_input('x'); // typo for 'd'
+        session.add_input(' ');
+        session.add_input('j'); // session ends mid-word
+
+        let words = session.word_breakdown();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "fd");
+        assert_eq!(words[0].error_count, 1);
+        assert_eq!(words[1].word, "j");
+        assert_eq!(words[1].error_count, 0);
+    }
+
+    #[test]
+    fn test_export_result_csv_one_row_per_word() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+        for c in "fd jk".chars() {
+            session.add_input(c);
+        }
+        let result = SessionResult::new(
+            40.0,
+            40.0,
+            100.0,
+            Duration::from_secs(1),
+            5,
+            0,
+            vec![],
+            0,
+            100.0,
+        );
+
+        let mut out = Vec::new();
+        session
+            .export_result(&result, SessionExportFormat::Csv, &mut out)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.starts_with("word,started_at_ms,finished_at_ms,error_count\n"));
+        assert_eq!(text.lines().count(), 3); // header + 2 words
+    }
+
+    #[test]
+    fn test_export_result_json_includes_keystrokes_and_words() {
+        let mut session = TypingSession::new("fd".to_string(), Duration::from_secs(60));
+        session.add_input('f');
+        session.add_input('d');
+        let result = SessionResult::new(
+            40.0,
+            40.0,
+            100.0,
+            Duration::from_secs(1),
+            2,
+            0,
+            vec![],
+            0,
+            100.0,
+        );
+
+        let mut out = Vec::new();
+        session
+            .export_result(&result, SessionExportFormat::Json, &mut out)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(json["keystrokes"].as_array().unwrap().len(), 2);
+        assert_eq!(json["words"].as_array().unwrap().len(), 1);
+        assert_eq!(json["result"]["wpm"], 40.0);
+    }
+
+    #[test]
+    fn test_format_timeline_timestamp_pads_minutes_seconds_millis() {
+        assert_eq!(
+            format_timeline_timestamp(Duration::from_millis(5_000)),
+            "00:05.000"
+        );
+        assert_eq!(
+            format_timeline_timestamp(Duration::from_millis(65_250)),
+            "01:05.250"
+        );
+    }
+
+    #[test]
+    fn test_format_timeline_timestamp_does_not_roll_over_into_hours() {
+        assert_eq!(
+            format_timeline_timestamp(Duration::from_secs(60 * 130)),
+            "130:00.000"
+        );
+    }
+
+    #[test]
+    fn test_parse_timeline_timestamp_round_trips_formatted_output() {
+        let elapsed = Duration::from_millis(65_250);
+        let formatted = format_timeline_timestamp(elapsed);
+        assert_eq!(parse_timeline_timestamp(&formatted), Some(elapsed));
+    }
+
+    #[test]
+    fn test_parse_timeline_timestamp_tolerates_missing_or_short_fraction() {
+        assert_eq!(
+            parse_timeline_timestamp("01:05"),
+            Some(Duration::from_millis(65_000))
+        );
+        assert_eq!(
+            parse_timeline_timestamp("01:05.5"),
+            Some(Duration::from_millis(65_500))
+        );
+    }
+
+    #[test]
+    fn test_parse_timeline_timestamp_rejects_garbage() {
+        assert_eq!(parse_timeline_timestamp("not a timestamp"), None);
+        assert_eq!(parse_timeline_timestamp("01:99.000"), None);
+    }
+
+    #[test]
+    fn test_parse_timeline_timestamp_does_not_panic_on_multibyte_fraction() {
+        // A multi-byte char straddling the 3-char fractional cutoff must not panic.
+        assert_eq!(parse_timeline_timestamp("01:05.1€23"), None);
+    }
+
+    #[test]
+    fn test_export_result_timeline_one_line_per_keystroke() {
+        let mut session = TypingSession::new("fd".to_string(), Duration::from_secs(60));
+        session.add_input('f');
+        session.add_input('x'); // typo for 'd'
+        let result = SessionResult::new(
+            40.0,
+            40.0,
+            50.0,
+            Duration::from_secs(1),
+            2,
+            1,
+            vec![],
+            0,
+            100.0,
+        );
+
+        let mut out = Vec::new();
+        session
+            .export_result(&result, SessionExportFormat::Timeline, &mut out)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("f f ok"));
+        assert!(lines[1].ends_with("d x err"));
+    }
+
+    #[test]
+    fn test_load_keystroke_timeline_round_trips_exported_session() {
+        let mut session = TypingSession::new("fd".to_string(), Duration::from_secs(60));
+        session.add_input('f');
+        session.add_input('x');
+        let result = SessionResult::new(
+            40.0,
+            40.0,
+            50.0,
+            Duration::from_secs(1),
+            2,
+            1,
+            vec![],
+            0,
+            100.0,
+        );
+
+        let mut out = Vec::new();
+        session
+            .export_result(&result, SessionExportFormat::Timeline, &mut out)
+            .unwrap();
+
+        let inputs = load_keystroke_timeline(out.as_slice()).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].expected, 'f');
+        assert_eq!(inputs[0].typed, 'f');
+        assert!(inputs[0].is_correct);
+        assert_eq!(inputs[1].expected, 'd');
+        assert_eq!(inputs[1].typed, 'x');
+        assert!(!inputs[1].is_correct);
+    }
+
+    #[test]
+    fn test_load_keystroke_timeline_skips_blank_and_malformed_lines() {
+        let text = "00:00.000 f f ok\n\n   \nnot a timeline line\n00:00.100 d d ok\n";
+        let inputs = load_keystroke_timeline(text.as_bytes()).unwrap();
+
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].expected, 'f');
+        assert_eq!(inputs[1].expected, 'd');
+    }
+
+    #[test]
+    fn test_load_keystroke_timeline_ignores_untrusted_ok_err_flag() {
+        // Flag says "ok" but expected/typed disagree: is_correct must be recomputed.
+        let text = "00:00.000 a b ok\n";
+        let inputs = load_keystroke_timeline(text.as_bytes()).unwrap();
+
+        assert_eq!(inputs.len(), 1);
+        assert!(!inputs[0].is_correct);
+    }
+
+    #[test]
+    fn test_timeline_char_token_round_trips_non_breaking_space() {
+        let token = timeline_char_token('\u{a0}');
+        assert_eq!(parse_timeline_char_token(&token), Some('\u{a0}'));
+    }
+
+    #[test]
+    fn test_export_result_timeline_round_trips_space_keystrokes() {
+        let mut session = TypingSession::new("fd jk".to_string(), Duration::from_secs(60));
+        for c in "fd jk".chars() {
+            session.add_input(c);
+        }
+        let result = SessionResult::new(
+            40.0,
+            40.0,
+            100.0,
+            Duration::from_secs(1),
+            5,
+            0,
+            vec![],
+            0,
+            100.0,
+        );
+
+        let mut out = Vec::new();
+        session
+            .export_result(&result, SessionExportFormat::Timeline, &mut out)
+            .unwrap();
+
+        let inputs = load_keystroke_timeline(out.as_slice()).unwrap();
+        assert_eq!(inputs.len(), 5);
+        assert_eq!(inputs[2].expected, ' ');
+        assert_eq!(inputs[2].typed, ' ');
+        assert!(inputs[2].is_correct);
+    }
 }
@@ -1,96 +1,15 @@
-use ratatui::style::Color;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
-/// Finger assignment for touch typing
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Finger {
-    LeftPinky,
-    LeftRing,
-    LeftMiddle,
-    LeftIndex,  // Covers 2 columns (e.g., f and g on AZERTY home row)
-    RightIndex, // Covers 2 columns (e.g., h and j on AZERTY home row)
-    RightMiddle,
-    RightRing,
-    RightPinky,
-    Thumb, // Spacebar
-}
-
-/// Hand classification for shift key selection
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Hand {
-    Left,
-    Right,
-    Either, // For spacebar - either shift works
-}
+pub use super::layout::{Finger, Hand, Key, KeyboardRow, RowType};
+use super::layout::{KeyboardLayout, LayoutConfig};
 
-impl Finger {
-    /// Get the terminal color for this finger
-    pub fn color(&self) -> Color {
-        match self {
-            Finger::LeftPinky => Color::Magenta,
-            Finger::LeftRing => Color::LightBlue,
-            Finger::LeftMiddle => Color::Blue,
-            Finger::LeftIndex => Color::Cyan,
-            Finger::RightIndex => Color::Green,
-            Finger::RightMiddle => Color::Yellow,
-            Finger::RightRing => Color::LightRed,
-            Finger::RightPinky => Color::Red,
-            Finger::Thumb => Color::Gray,
-        }
-    }
-
-    /// Determine which hand uses this finger (for smart shift highlighting)
-    pub fn hand(&self) -> Hand {
-        match self {
-            Finger::LeftPinky | Finger::LeftRing | Finger::LeftMiddle | Finger::LeftIndex => {
-                Hand::Left
-            }
-            Finger::RightPinky | Finger::RightRing | Finger::RightMiddle | Finger::RightIndex => {
-                Hand::Right
-            }
-            Finger::Thumb => Hand::Either,
-        }
-    }
-}
-
-/// Row type classification for keyboard layout
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum RowType {
-    Number,   // 1234567890°=+
-    Top,      // azertyuiop^$
-    Home,     // qsdfghjklmù*
-    Bottom,   // <wxcvbn,;:!
-    Space,    // Space bar
-    Modifier, // Ctrl, Cmd, Option, Space, Alt, Fn1, Fn2
-}
-
-/// Single key representation
-#[derive(Debug, Clone)]
-pub struct Key {
-    pub base: char,
-    pub shift_variant: Option<char>,
-    #[allow(dead_code)]
-    pub display_width: u8,
-    pub finger: Finger,
-}
-
-impl Key {
-    pub fn new(base: char, shift_variant: Option<char>, finger: Finger) -> Self {
-        Self {
-            base,
-            shift_variant,
-            display_width: 1,
-            finger,
-        }
-    }
-}
-
-/// Keyboard row with keys and type
-#[derive(Debug, Clone)]
-pub struct KeyboardRow {
-    pub keys: Vec<Key>,
-    pub row_type: RowType,
-}
+/// The AZERTY layout shipped with the crate, in the same TOML format
+/// `from_config_file` accepts from users. Embedded at compile time via
+/// `include_str!` so `from_config_file` always has a working fallback, even
+/// with no user override on disk.
+const DEFAULT_LAYOUT_TOML: &str = include_str!("azerty_default.toml");
 
 /// Layout clavier AZERTY
 /// Phase 3+: Keyboard layout abstraction for future QWERTY/other layout support
@@ -100,6 +19,7 @@ pub struct AzertyLayout {
     pub home_row: Vec<char>,
     pub rows: Vec<KeyboardRow>,
     pub shift_mappings: HashMap<char, char>,
+    pub altgr_mappings: HashMap<char, char>,
 }
 
 /// Phase 3+: Keyboard layout abstraction for future QWERTY/other layout support
@@ -107,17 +27,20 @@ pub struct AzertyLayout {
 impl AzertyLayout {
     pub fn new() -> Self {
         let shift_mappings = Self::build_shift_mappings();
+        let rows = vec![
+            Self::number_row(),
+            Self::top_row(),
+            Self::home_row_keys(),
+            Self::bottom_row(),
+            Self::modifier_row(), // Replace space_row with modifier_row
+        ];
+        let altgr_mappings = Self::build_altgr_mappings(&rows);
 
         Self {
             home_row: vec!['q', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm'], // Keep original for backward compatibility
-            rows: vec![
-                Self::number_row(),
-                Self::top_row(),
-                Self::home_row_keys(),
-                Self::bottom_row(),
-                Self::modifier_row(), // Replace space_row with modifier_row
-            ],
+            rows,
             shift_mappings,
+            altgr_mappings,
         }
     }
 
@@ -129,24 +52,26 @@ impl AzertyLayout {
         self.home_row.contains(&c)
     }
 
-    /// Build number row - French AZERTY (symbols are base, numbers are shift)
+    /// Build number row - French AZERTY (symbols are base, numbers are shift).
+    /// Several keys also carry an AltGr variant (the third modifier layer),
+    /// matching a real French AZERTY keyboard's AltGr row.
     fn number_row() -> KeyboardRow {
         KeyboardRow {
             row_type: RowType::Number,
             keys: vec![
                 Key::new('²', Some('³'), Finger::LeftPinky), // Superscript 2/3 (first key)
-                Key::new('&', Some('1'), Finger::LeftPinky),
-                Key::new('é', Some('2'), Finger::LeftPinky),
-                Key::new('"', Some('3'), Finger::LeftRing),
-                Key::new('\'', Some('4'), Finger::LeftMiddle),
-                Key::new('(', Some('5'), Finger::LeftIndex),
-                Key::new('-', Some('6'), Finger::LeftIndex),
-                Key::new('è', Some('7'), Finger::RightIndex),
-                Key::new('_', Some('8'), Finger::RightMiddle),
-                Key::new('ç', Some('9'), Finger::RightRing),
-                Key::new('à', Some('0'), Finger::RightPinky),
-                Key::new(')', Some('°'), Finger::RightPinky),
-                Key::new('=', Some('+'), Finger::RightPinky),
+                Key::with_altgr('&', Some('1'), Some('¹'), Finger::LeftPinky),
+                Key::with_altgr('é', Some('2'), Some('~'), Finger::LeftPinky),
+                Key::with_altgr('"', Some('3'), Some('#'), Finger::LeftRing),
+                Key::with_altgr('\'', Some('4'), Some('{'), Finger::LeftMiddle),
+                Key::with_altgr('(', Some('5'), Some('['), Finger::LeftIndex),
+                Key::with_altgr('-', Some('6'), Some('|'), Finger::LeftIndex),
+                Key::with_altgr('è', Some('7'), Some('`'), Finger::RightIndex),
+                Key::with_altgr('_', Some('8'), Some('\\'), Finger::RightMiddle),
+                Key::with_altgr('ç', Some('9'), Some('^'), Finger::RightRing),
+                Key::with_altgr('à', Some('0'), Some('@'), Finger::RightPinky),
+                Key::with_altgr(')', Some('°'), Some(']'), Finger::RightPinky),
+                Key::with_altgr('=', Some('+'), Some('}'), Finger::RightPinky),
             ],
         }
     }
@@ -158,7 +83,7 @@ impl AzertyLayout {
             keys: vec![
                 Key::new('a', Some('A'), Finger::LeftPinky),
                 Key::new('z', Some('Z'), Finger::LeftRing),
-                Key::new('e', Some('E'), Finger::LeftMiddle),
+                Key::with_altgr('e', Some('E'), Some('€'), Finger::LeftMiddle),
                 Key::new('r', Some('R'), Finger::LeftIndex),
                 Key::new('t', Some('T'), Finger::LeftIndex),
                 Key::new('y', Some('Y'), Finger::RightIndex),
@@ -167,7 +92,7 @@ impl AzertyLayout {
                 Key::new('o', Some('O'), Finger::RightRing),
                 Key::new('p', Some('P'), Finger::RightPinky),
                 Key::new('^', Some('¨'), Finger::RightPinky),
-                Key::new('$', Some('£'), Finger::RightPinky),
+                Key::with_altgr('$', Some('£'), Some('¤'), Finger::RightPinky),
                 Key::new('\n', None, Finger::RightPinky), // Enter key (newline character)
             ],
         }
@@ -282,7 +207,24 @@ impl AzertyLayout {
         map
     }
 
-    /// Find the base key for a given character (handles shift variants)
+    /// Build AltGr mappings (base key -> AltGr output) from the already-built
+    /// rows, mirroring `build_shift_mappings` but derived from each key's
+    /// `altgr_variant` instead of hand-duplicated here.
+    fn build_altgr_mappings(rows: &[KeyboardRow]) -> HashMap<char, char> {
+        let mut map = HashMap::new();
+
+        for row in rows {
+            for key in &row.keys {
+                if let Some(altgr) = key.altgr_variant {
+                    map.insert(key.base, altgr);
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Find the base key for a given character (handles shift and AltGr variants)
     pub fn get_base_key(&self, c: char) -> Option<char> {
         // Direct match
         for row in &self.rows {
@@ -293,6 +235,9 @@ impl AzertyLayout {
                 if key.shift_variant == Some(c) {
                     return Some(key.base);
                 }
+                if key.altgr_variant == Some(c) {
+                    return Some(key.base);
+                }
             }
         }
         None
@@ -303,6 +248,11 @@ impl AzertyLayout {
         self.shift_mappings.values().any(|&v| v == c)
     }
 
+    /// Check if character is produced via the AltGr (third) layer
+    pub fn requires_altgr(&self, c: char) -> bool {
+        self.altgr_mappings.values().any(|&v| v == c)
+    }
+
     /// Find the Key object for a given base character (for smart shift highlighting)
     pub fn find_key(&self, base_char: char) -> Option<&Key> {
         for row in &self.rows {
@@ -314,6 +264,59 @@ impl AzertyLayout {
         }
         None
     }
+
+    /// Load an AZERTY layout from a TOML config file (see `LayoutConfig`),
+    /// so users with a bépo, Swiss-French, or custom/ortholinear keyboard
+    /// can override rows, fingers, and AltGr variants without recompiling.
+    ///
+    /// No override file is the common case (most users keep the built-in
+    /// AZERTY layout), so a missing `path` falls back to the bundled default
+    /// silently. If `path` exists but doesn't parse or fails validation (e.g.
+    /// a duplicate base character), that's a user mistake worth surfacing, so
+    /// a diagnostic goes to stderr before falling back. If even the bundled
+    /// default somehow fails to parse, falls back further to `Self::new()`.
+    pub fn from_config_file(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => match Self::from_layout_config(&content) {
+                Ok(layout) => layout,
+                Err(message) => {
+                    eprintln!(
+                        "warning: failed to load keyboard layout from {}: {message}; using built-in AZERTY layout",
+                        path.display()
+                    );
+                    Self::built_in()
+                }
+            },
+            Err(_) => Self::built_in(),
+        }
+    }
+
+    /// The layout bundled via `DEFAULT_LAYOUT_TOML`, falling back further to
+    /// the hand-written `Self::new()` in the unlikely case that embedded
+    /// TOML itself fails to parse.
+    fn built_in() -> Self {
+        Self::from_layout_config(DEFAULT_LAYOUT_TOML).unwrap_or_else(|_| Self::new())
+    }
+
+    /// Parse `toml_source` as a `LayoutConfig` and build the concrete rows,
+    /// home row, and derived shift/AltGr mappings from it.
+    fn from_layout_config(toml_source: &str) -> Result<Self, String> {
+        let config: LayoutConfig = toml::from_str(toml_source).map_err(|e| e.to_string())?;
+        let rows = config.build_rows()?;
+        let altgr_mappings = Self::build_altgr_mappings(&rows);
+        let shift_mappings = rows
+            .iter()
+            .flat_map(|row| &row.keys)
+            .filter_map(|key| key.shift_variant.map(|shift| (key.base, shift)))
+            .collect();
+
+        Ok(Self {
+            home_row: config.home_row_chars(),
+            rows,
+            shift_mappings,
+            altgr_mappings,
+        })
+    }
 }
 
 impl Default for AzertyLayout {
@@ -322,9 +325,44 @@ impl Default for AzertyLayout {
     }
 }
 
+impl KeyboardLayout for AzertyLayout {
+    fn name(&self) -> &'static str {
+        "AZERTY"
+    }
+
+    fn home_row(&self) -> &[char] {
+        self.get_home_row()
+    }
+
+    fn rows(&self) -> &[KeyboardRow] {
+        &self.rows
+    }
+
+    fn is_home_row_key(&self, c: char) -> bool {
+        AzertyLayout::is_home_row_key(self, c)
+    }
+
+    fn get_base_key(&self, c: char) -> Option<char> {
+        AzertyLayout::get_base_key(self, c)
+    }
+
+    fn requires_shift(&self, c: char) -> bool {
+        AzertyLayout::requires_shift(self, c)
+    }
+
+    fn requires_altgr(&self, c: char) -> bool {
+        AzertyLayout::requires_altgr(self, c)
+    }
+
+    fn find_key(&self, base_char: char) -> Option<&Key> {
+        AzertyLayout::find_key(self, base_char)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::layout::CapsLockState;
 
     #[test]
     fn test_azerty_home_row() {
@@ -511,4 +549,149 @@ mod tests {
         let key_none = layout.find_key('€');
         assert!(key_none.is_none());
     }
+
+    #[test]
+    fn test_altgr_mapping_euro_sign() {
+        let layout = AzertyLayout::new();
+        assert_eq!(layout.altgr_mappings.get(&'e'), Some(&'€'));
+        assert_eq!(layout.altgr_mappings.get(&'$'), Some(&'¤'));
+    }
+
+    #[test]
+    fn test_altgr_mapping_number_row_symbols() {
+        let layout = AzertyLayout::new();
+        assert_eq!(layout.altgr_mappings.get(&'\''), Some(&'{'));
+        assert_eq!(layout.altgr_mappings.get(&'('), Some(&'['));
+        assert_eq!(layout.altgr_mappings.get(&'-'), Some(&'|'));
+        assert_eq!(layout.altgr_mappings.get(&'='), Some(&'}'));
+        assert_eq!(layout.altgr_mappings.get(&'à'), Some(&'@'));
+    }
+
+    #[test]
+    fn test_get_base_key_resolves_altgr_variant() {
+        let layout = AzertyLayout::new();
+        assert_eq!(layout.get_base_key('€'), Some('e'));
+        assert_eq!(layout.get_base_key('@'), Some('à'));
+        assert_eq!(layout.get_base_key('{'), Some('\''));
+    }
+
+    #[test]
+    fn test_requires_altgr() {
+        let layout = AzertyLayout::new();
+        assert!(layout.requires_altgr('€'));
+        assert!(layout.requires_altgr('@'));
+        assert!(layout.requires_altgr('#'));
+        assert!(!layout.requires_altgr('e')); // base char, no modifier needed
+        assert!(!layout.requires_altgr('A')); // shift variant, not AltGr
+    }
+
+    #[test]
+    fn test_bundled_default_toml_parses_and_matches_azerty() {
+        let layout = AzertyLayout::from_layout_config(DEFAULT_LAYOUT_TOML).unwrap();
+        assert_eq!(layout.home_row, vec!['q', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm']);
+        assert_eq!(layout.altgr_mappings.get(&'e'), Some(&'€'));
+        assert_eq!(layout.shift_mappings.get(&'a'), Some(&'A'));
+        assert_eq!(layout.find_key('q').unwrap().finger, Finger::LeftPinky);
+    }
+
+    #[test]
+    fn test_from_config_file_falls_back_on_missing_path() {
+        let layout = AzertyLayout::from_config_file(Path::new("/nonexistent/layout.toml"));
+        assert_eq!(layout.rows.len(), 5);
+        assert_eq!(layout.altgr_mappings.get(&'e'), Some(&'€'));
+    }
+
+    #[test]
+    fn test_from_config_file_loads_custom_layout() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("layout.toml");
+        fs::write(
+            &path,
+            r#"
+            home_row = "ab"
+
+            [[rows]]
+            row_type = "home"
+            keys = [
+                { base = "a", shift = "A", finger = "left_pinky" },
+                { base = "b", shift = "B", altgr = "€", finger = "right_pinky" },
+            ]
+            "#,
+        )
+        .unwrap();
+
+        let layout = AzertyLayout::from_config_file(&path);
+        assert_eq!(layout.home_row, vec!['a', 'b']);
+        assert_eq!(layout.rows.len(), 1);
+        assert_eq!(layout.altgr_mappings.get(&'b'), Some(&'€'));
+    }
+
+    #[test]
+    fn test_from_config_file_falls_back_on_duplicate_base() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("layout.toml");
+        fs::write(
+            &path,
+            r#"
+            home_row = "a"
+
+            [[rows]]
+            row_type = "home"
+            keys = [
+                { base = "a", finger = "left_pinky" },
+                { base = "a", finger = "right_pinky" },
+            ]
+            "#,
+        )
+        .unwrap();
+
+        // Invalid (duplicate base 'a'), so this should fall back to the
+        // bundled default rather than panic or silently lose keys.
+        let layout = AzertyLayout::from_config_file(&path);
+        assert_eq!(layout.rows.len(), 5);
+    }
+
+    #[test]
+    fn test_letter_key_locked_variants_invert() {
+        let layout = AzertyLayout::new();
+        let key_a = layout.find_key('a').unwrap();
+        assert_eq!(key_a.locked_variant, Some('A'));
+        assert_eq!(key_a.locked_shifted_variant, Some('a'));
+    }
+
+    #[test]
+    fn test_digit_key_locked_variants_unaffected() {
+        let layout = AzertyLayout::new();
+        // '&' is base, '1' is its shift variant on French AZERTY
+        let key_ampersand = layout.find_key('&').unwrap();
+        assert_eq!(key_ampersand.locked_variant, Some('&'));
+        assert_eq!(key_ampersand.locked_shifted_variant, Some('1'));
+    }
+
+    #[test]
+    fn test_requires_shift_for_caps_lock_off_matches_key_shift_variants() {
+        let layout = AzertyLayout::new();
+        // 'A' is the shift variant of the 'a' key...
+        assert!(layout.requires_shift_for('A', CapsLockState::Off));
+        // ...while '1' is the shift variant of the '&' key.
+        assert!(layout.requires_shift_for('1', CapsLockState::Off));
+        assert!(!layout.requires_shift_for('&', CapsLockState::Off));
+    }
+
+    #[test]
+    fn test_requires_shift_for_caps_lock_on_inverts_letters() {
+        let layout = AzertyLayout::new();
+        // With Caps Lock on, uppercase no longer needs Shift...
+        assert!(!layout.requires_shift_for('A', CapsLockState::On));
+        // ...but lowercase now does.
+        assert!(layout.requires_shift_for('a', CapsLockState::On));
+    }
+
+    #[test]
+    fn test_requires_shift_for_caps_lock_on_unaffected_for_digits() {
+        let layout = AzertyLayout::new();
+        // Digit row symbols need Shift the same way with or without Caps Lock
+        assert!(layout.requires_shift_for('1', CapsLockState::On));
+        assert!(!layout.requires_shift_for('&', CapsLockState::On));
+    }
 }
@@ -0,0 +1,151 @@
+use super::layout::{Finger, Key, KeyboardLayout, KeyboardRow, RowType};
+
+/// Dvorak Simplified Keyboard layout
+#[derive(Debug, Clone)]
+pub struct DvorakLayout {
+    home_row: Vec<char>,
+    rows: Vec<KeyboardRow>,
+}
+
+impl DvorakLayout {
+    pub fn new() -> Self {
+        Self {
+            home_row: vec!['a', 'o', 'e', 'u', 'i', 'd', 'h', 't', 'n', 's'],
+            rows: vec![
+                Self::number_row(),
+                Self::top_row(),
+                Self::home_row_keys(),
+                Self::bottom_row(),
+            ],
+        }
+    }
+
+    fn number_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Number,
+            keys: vec![
+                Key::new('`', Some('~'), Finger::LeftPinky),
+                Key::new('1', Some('!'), Finger::LeftPinky),
+                Key::new('2', Some('@'), Finger::LeftRing),
+                Key::new('3', Some('#'), Finger::LeftMiddle),
+                Key::new('4', Some('$'), Finger::LeftIndex),
+                Key::new('5', Some('%'), Finger::LeftIndex),
+                Key::new('6', Some('^'), Finger::RightIndex),
+                Key::new('7', Some('&'), Finger::RightIndex),
+                Key::new('8', Some('*'), Finger::RightMiddle),
+                Key::new('9', Some('('), Finger::RightRing),
+                Key::new('0', Some(')'), Finger::RightPinky),
+                Key::new('[', Some('{'), Finger::RightPinky),
+                Key::new(']', Some('}'), Finger::RightPinky),
+            ],
+        }
+    }
+
+    fn top_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Top,
+            keys: vec![
+                Key::new('\'', Some('"'), Finger::LeftPinky),
+                Key::new(',', Some('<'), Finger::LeftRing),
+                Key::new('.', Some('>'), Finger::LeftMiddle),
+                Key::new('p', Some('P'), Finger::LeftIndex),
+                Key::new('y', Some('Y'), Finger::LeftIndex),
+                Key::new('f', Some('F'), Finger::RightIndex),
+                Key::new('g', Some('G'), Finger::RightIndex),
+                Key::new('c', Some('C'), Finger::RightMiddle),
+                Key::new('r', Some('R'), Finger::RightRing),
+                Key::new('l', Some('L'), Finger::RightPinky),
+                Key::new('/', Some('?'), Finger::RightPinky),
+                Key::new('=', Some('+'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key
+            ],
+        }
+    }
+
+    fn home_row_keys() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Home,
+            keys: vec![
+                Key::new('a', Some('A'), Finger::LeftPinky),
+                Key::new('o', Some('O'), Finger::LeftRing),
+                Key::new('e', Some('E'), Finger::LeftMiddle),
+                Key::new('u', Some('U'), Finger::LeftIndex),
+                Key::new('i', Some('I'), Finger::LeftIndex),
+                Key::new('d', Some('D'), Finger::RightIndex),
+                Key::new('h', Some('H'), Finger::RightIndex),
+                Key::new('t', Some('T'), Finger::RightMiddle),
+                Key::new('n', Some('N'), Finger::RightRing),
+                Key::new('s', Some('S'), Finger::RightPinky),
+                Key::new('-', Some('_'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key continuation
+            ],
+        }
+    }
+
+    fn bottom_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Bottom,
+            keys: vec![
+                Key::new(';', Some(':'), Finger::LeftPinky),
+                Key::new('q', Some('Q'), Finger::LeftRing),
+                Key::new('j', Some('J'), Finger::LeftMiddle),
+                Key::new('k', Some('K'), Finger::LeftIndex),
+                Key::new('x', Some('X'), Finger::LeftIndex),
+                Key::new('b', Some('B'), Finger::RightIndex),
+                Key::new('m', Some('M'), Finger::RightIndex),
+                Key::new('w', Some('W'), Finger::RightMiddle),
+                Key::new('v', Some('V'), Finger::RightRing),
+                Key::new('z', Some('Z'), Finger::RightPinky),
+                Key::new('\0', None, Finger::RightPinky), // Right Shift placeholder
+            ],
+        }
+    }
+}
+
+impl Default for DvorakLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyboardLayout for DvorakLayout {
+    fn name(&self) -> &'static str {
+        "Dvorak"
+    }
+
+    fn home_row(&self) -> &[char] {
+        &self.home_row
+    }
+
+    fn rows(&self) -> &[KeyboardRow] {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dvorak_home_row() {
+        let layout = DvorakLayout::new();
+        assert_eq!(
+            layout.home_row(),
+            &['a', 'o', 'e', 'u', 'i', 'd', 'h', 't', 'n', 's']
+        );
+    }
+
+    #[test]
+    fn test_get_base_key_for_shift_variant() {
+        let layout = DvorakLayout::new();
+        assert_eq!(layout.get_base_key('A'), Some('a'));
+        assert_eq!(layout.get_base_key('"'), Some('\''));
+    }
+
+    #[test]
+    fn test_find_key_finger_assignment() {
+        let layout = DvorakLayout::new();
+        let key = layout.find_key('s').unwrap();
+        assert_eq!(key.finger, Finger::RightPinky);
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use super::layout::{Finger, KeyboardLayout, RowType};
+
+/// Touch-typing effort metrics for a piece of text under a given
+/// `KeyboardLayout`, produced by `analyze_text`. Lets a user compare the
+/// same material across layouts to see which is least strenuous to type.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct ErgonomicsReport {
+    /// Characters of the input that had a matching `Key` in the layout
+    pub keystrokes: usize,
+    pub finger_counts: HashMap<Finger, usize>,
+    /// Consecutive keystroke pairs whose fingers are on different hands
+    pub hand_alternations: usize,
+    /// Consecutive keystroke pairs assigned to the same finger but a
+    /// different key (e.g. 'e' then 'd' on AZERTY, both `LeftMiddle`)
+    pub same_finger_bigrams: usize,
+    /// Sum of each keystroke's row-distance cost from `RowType::Home`
+    pub row_jump_cost: u32,
+}
+
+#[allow(dead_code)]
+impl ErgonomicsReport {
+    /// Fraction of consecutive keystroke pairs that alternate hands
+    pub fn hand_alternation_ratio(&self) -> f64 {
+        if self.keystrokes < 2 {
+            return 0.0;
+        }
+        self.hand_alternations as f64 / (self.keystrokes - 1) as f64
+    }
+
+    /// Fraction of consecutive keystroke pairs that are same-finger bigrams
+    pub fn same_finger_bigram_ratio(&self) -> f64 {
+        if self.keystrokes < 2 {
+            return 0.0;
+        }
+        self.same_finger_bigrams as f64 / (self.keystrokes - 1) as f64
+    }
+
+    /// Average row-jump cost per keystroke
+    pub fn row_jump_cost_per_keystroke(&self) -> f64 {
+        if self.keystrokes == 0 {
+            return 0.0;
+        }
+        self.row_jump_cost as f64 / self.keystrokes as f64
+    }
+}
+
+/// Row-distance cost for travel away from `RowType::Home`: zero for Home
+/// itself, one for an adjacent row, two for the Number row (the furthest
+/// from the resting position). `Space`/`Modifier` don't show up in ordinary
+/// typed text but are given the one-hop cost for completeness.
+fn row_jump_weight(row_type: RowType) -> u32 {
+    match row_type {
+        RowType::Home => 0,
+        RowType::Top | RowType::Bottom | RowType::Space | RowType::Modifier => 1,
+        RowType::Number => 2,
+    }
+}
+
+/// Like `KeyboardLayout::find_key`, but also returns the `RowType` of the
+/// row the key lives on, so callers that need both don't have to scan
+/// `rows()` twice.
+fn find_key_and_row(layout: &dyn KeyboardLayout, base_char: char) -> Option<(Finger, RowType)> {
+    layout.rows().iter().find_map(|row| {
+        row.keys
+            .iter()
+            .find(|key| key.base == base_char)
+            .map(|key| (key.finger, row.row_type))
+    })
+}
+
+/// Walk `text` left to right under `layout`, tallying per-finger keystroke
+/// counts, hand-alternation and same-finger-bigram counts, and row-jump
+/// cost. Each character is looked up by its base key (shift variants
+/// included, since only `Key::base` is matched), so characters with no
+/// matching base key are ignored entirely, including as context for the
+/// next character's bigram/alternation check.
+#[allow(dead_code)]
+pub fn analyze_text(layout: &dyn KeyboardLayout, text: &str) -> ErgonomicsReport {
+    let mut report = ErgonomicsReport::default();
+    let mut previous: Option<(Finger, char)> = None;
+
+    for c in text.chars() {
+        let Some((finger, row_type)) = find_key_and_row(layout, c) else {
+            continue;
+        };
+
+        report.keystrokes += 1;
+        *report.finger_counts.entry(finger).or_insert(0) += 1;
+        report.row_jump_cost += row_jump_weight(row_type);
+
+        if let Some((prev_finger, prev_base)) = previous {
+            if prev_finger == finger && prev_base != c {
+                report.same_finger_bigrams += 1;
+            }
+            if prev_finger.hand() != finger.hand() {
+                report.hand_alternations += 1;
+            }
+        }
+
+        previous = Some((finger, c));
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::AzertyLayout;
+
+    #[test]
+    fn test_empty_text_has_no_keystrokes() {
+        let layout = AzertyLayout::new();
+        let report = analyze_text(&layout, "");
+        assert_eq!(report.keystrokes, 0);
+        assert_eq!(report.hand_alternation_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_characters_without_a_key_are_ignored() {
+        let layout = AzertyLayout::new();
+        // 'A' is a shift variant, not a base char, so it has no matching key
+        let report = analyze_text(&layout, "A");
+        assert_eq!(report.keystrokes, 0);
+    }
+
+    #[test]
+    fn test_home_row_text_has_no_row_jump_cost() {
+        let layout = AzertyLayout::new();
+        let report = analyze_text(&layout, "qsdf");
+        assert_eq!(report.keystrokes, 4);
+        assert_eq!(report.row_jump_cost, 0);
+    }
+
+    #[test]
+    fn test_number_row_text_costs_two_per_keystroke() {
+        let layout = AzertyLayout::new();
+        let report = analyze_text(&layout, "&é");
+        assert_eq!(report.keystrokes, 2);
+        assert_eq!(report.row_jump_cost, 4);
+    }
+
+    #[test]
+    fn test_hand_alternation_between_opposite_hands() {
+        let layout = AzertyLayout::new();
+        // 'q' is LeftPinky, 'j' is RightIndex: one alternating pair
+        let report = analyze_text(&layout, "qj");
+        assert_eq!(report.hand_alternations, 1);
+        assert_eq!(report.hand_alternation_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_same_finger_bigram_on_distinct_keys() {
+        let layout = AzertyLayout::new();
+        // 'e' and 'd' are both LeftMiddle on AZERTY, but different keys
+        let report = analyze_text(&layout, "ed");
+        assert_eq!(report.same_finger_bigrams, 1);
+    }
+
+    #[test]
+    fn test_repeated_key_is_not_a_same_finger_bigram() {
+        let layout = AzertyLayout::new();
+        let report = analyze_text(&layout, "qq");
+        assert_eq!(report.same_finger_bigrams, 0);
+    }
+
+    #[test]
+    fn test_finger_counts_tally_every_keystroke() {
+        let layout = AzertyLayout::new();
+        let report = analyze_text(&layout, "qq");
+        assert_eq!(report.finger_counts.get(&Finger::LeftPinky), Some(&2));
+    }
+}
@@ -0,0 +1,324 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+
+/// Finger assignment for touch typing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Finger {
+    LeftPinky,
+    LeftRing,
+    LeftMiddle,
+    LeftIndex,  // Covers 2 columns (e.g., f and g on AZERTY home row)
+    RightIndex, // Covers 2 columns (e.g., h and j on AZERTY home row)
+    RightMiddle,
+    RightRing,
+    RightPinky,
+    Thumb, // Spacebar
+}
+
+/// Hand classification for shift key selection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hand {
+    Left,
+    Right,
+    Either, // For spacebar - either shift works
+}
+
+/// Whether Caps Lock is currently engaged. Consulted by
+/// `KeyboardLayout::requires_shift_for` so a Caps Lock practice mode can
+/// correctly decide whether Shift is actually needed for an expected
+/// character, instead of always demanding a literal Shift press for
+/// uppercase letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapsLockState {
+    Off,
+    On,
+}
+
+impl Default for CapsLockState {
+    fn default() -> Self {
+        CapsLockState::Off
+    }
+}
+
+impl Finger {
+    /// Get the terminal color for this finger
+    pub fn color(&self) -> Color {
+        match self {
+            Finger::LeftPinky => Color::Magenta,
+            Finger::LeftRing => Color::LightBlue,
+            Finger::LeftMiddle => Color::Blue,
+            Finger::LeftIndex => Color::Cyan,
+            Finger::RightIndex => Color::Green,
+            Finger::RightMiddle => Color::Yellow,
+            Finger::RightRing => Color::LightRed,
+            Finger::RightPinky => Color::Red,
+            Finger::Thumb => Color::Gray,
+        }
+    }
+
+    /// Determine which hand uses this finger (for smart shift highlighting)
+    pub fn hand(&self) -> Hand {
+        match self {
+            Finger::LeftPinky | Finger::LeftRing | Finger::LeftMiddle | Finger::LeftIndex => {
+                Hand::Left
+            }
+            Finger::RightPinky | Finger::RightRing | Finger::RightMiddle | Finger::RightIndex => {
+                Hand::Right
+            }
+            Finger::Thumb => Hand::Either,
+        }
+    }
+}
+
+/// Row type classification for keyboard layout
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowType {
+    Number,   // 1234567890°=+
+    Top,      // azertyuiop^$
+    Home,     // qsdfghjklmù*
+    Bottom,   // <wxcvbn,;:!
+    Space,    // Space bar
+    Modifier, // Ctrl, Cmd, Option, Space, Alt, Fn1, Fn2
+}
+
+/// Single key representation
+#[derive(Debug, Clone)]
+pub struct Key {
+    pub base: char,
+    pub shift_variant: Option<char>,
+    /// Character produced by the AltGr (third) modifier layer, e.g. `€` on
+    /// AZERTY's `e` key. `None` for keys with no AltGr output.
+    pub altgr_variant: Option<char>,
+    /// Character produced with Caps Lock engaged and no Shift held. Derived
+    /// automatically from `base`/`shift_variant`: letter keys invert (this
+    /// equals `shift_variant`, since Caps Lock alone capitalizes them) while
+    /// digit/symbol keys are unaffected by Caps Lock (this equals `base`).
+    /// `None` for keys with no shift variant at all.
+    #[allow(dead_code)]
+    pub locked_variant: Option<char>,
+    /// Character produced with Caps Lock engaged AND Shift held. The mirror
+    /// of `locked_variant`: letter keys invert back to `base`; digit/symbol
+    /// keys are unaffected and this equals `shift_variant`.
+    pub locked_shifted_variant: Option<char>,
+    #[allow(dead_code)]
+    pub display_width: u8,
+    pub finger: Finger,
+}
+
+impl Key {
+    pub fn new(base: char, shift_variant: Option<char>, finger: Finger) -> Self {
+        let (locked_variant, locked_shifted_variant) =
+            derive_locked_variants(base, shift_variant);
+        Self {
+            base,
+            shift_variant,
+            altgr_variant: None,
+            locked_variant,
+            locked_shifted_variant,
+            display_width: 1,
+            finger,
+        }
+    }
+
+    /// Build a key that also produces a character on the AltGr layer
+    pub fn with_altgr(
+        base: char,
+        shift_variant: Option<char>,
+        altgr_variant: Option<char>,
+        finger: Finger,
+    ) -> Self {
+        let (locked_variant, locked_shifted_variant) =
+            derive_locked_variants(base, shift_variant);
+        Self {
+            base,
+            shift_variant,
+            altgr_variant,
+            locked_variant,
+            locked_shifted_variant,
+            display_width: 1,
+            finger,
+        }
+    }
+}
+
+/// Derive a key's Caps-Lock-engaged outputs from its unshifted/shifted
+/// outputs: letters invert under Caps Lock (it capitalizes, so the
+/// unshifted slot now yields the shifted character and vice versa), while
+/// digits and symbols are unaffected by Caps Lock and keep their normal
+/// shift behavior.
+fn derive_locked_variants(base: char, shift_variant: Option<char>) -> (Option<char>, Option<char>) {
+    match shift_variant {
+        None => (None, None),
+        // ASCII only: AZERTY's number-row base characters (é, è, ç, à) and
+        // ù are `char::is_alphabetic`, but they're digit/symbol keys that
+        // Caps Lock never affects, not letters that invert under it.
+        Some(shifted) if base.is_ascii_alphabetic() => (Some(shifted), Some(base)),
+        Some(shifted) => (Some(base), Some(shifted)),
+    }
+}
+
+/// Keyboard row with keys and type
+#[derive(Debug, Clone)]
+pub struct KeyboardRow {
+    pub keys: Vec<Key>,
+    pub row_type: RowType,
+}
+
+/// Physical/virtual keyboard layout: maps characters to key positions and
+/// finger assignments, independent of which concrete arrangement (AZERTY,
+/// QWERTY, Dvorak, ...) provides them. `ui::keyboard` and `ui::render` are
+/// written against this trait so they render whichever layout the user
+/// picked in `AppState::LayoutMenu`, not a single hardcoded arrangement.
+pub trait KeyboardLayout {
+    /// Human-readable name shown in the layout-select menu
+    fn name(&self) -> &'static str;
+
+    /// Home row keys, left to right
+    fn home_row(&self) -> &[char];
+
+    /// All rows, top to bottom, in display order
+    fn rows(&self) -> &[KeyboardRow];
+
+    fn is_home_row_key(&self, c: char) -> bool {
+        self.home_row().contains(&c)
+    }
+
+    /// Find the base key for a given character (handles shift and AltGr variants)
+    fn get_base_key(&self, c: char) -> Option<char> {
+        for row in self.rows() {
+            for key in &row.keys {
+                if key.base == c || key.shift_variant == Some(c) || key.altgr_variant == Some(c) {
+                    return Some(key.base);
+                }
+            }
+        }
+        None
+    }
+
+    /// Check if character requires shift
+    fn requires_shift(&self, c: char) -> bool {
+        self.rows()
+            .iter()
+            .flat_map(|row| &row.keys)
+            .any(|key| key.shift_variant == Some(c))
+    }
+
+    /// Check if character requires physically pressing Shift, given the
+    /// current Caps Lock state. Both branches read straight off each key's
+    /// `shift_variant`/`locked_shifted_variant` (rather than one delegating
+    /// to `requires_shift`), so a layout can't disagree with itself about a
+    /// digit/symbol key merely because Caps Lock was toggled. With Caps Lock
+    /// on, letter keys are inverted (an uppercase letter no longer needs
+    /// Shift, a lowercase one does) while digit/symbol keys keep needing
+    /// Shift exactly as when Caps Lock is off.
+    fn requires_shift_for(&self, c: char, caps_lock: CapsLockState) -> bool {
+        self.rows().iter().flat_map(|row| &row.keys).any(|key| match caps_lock {
+            CapsLockState::Off => key.shift_variant == Some(c),
+            CapsLockState::On => key.locked_shifted_variant == Some(c),
+        })
+    }
+
+    /// Check if character is produced via the AltGr (third) layer, e.g. `€`
+    /// on AZERTY's `e` key, so the typing UI can highlight AltGr alongside
+    /// the base key instead of only ever looking for Shift.
+    fn requires_altgr(&self, c: char) -> bool {
+        self.rows()
+            .iter()
+            .flat_map(|row| &row.keys)
+            .any(|key| key.altgr_variant == Some(c))
+    }
+
+    /// Find the Key object for a given base character (for smart shift highlighting)
+    fn find_key(&self, base_char: char) -> Option<&Key> {
+        self.rows()
+            .iter()
+            .flat_map(|row| &row.keys)
+            .find(|key| key.base == base_char)
+    }
+}
+
+/// On-disk representation of a single key, as parsed from a layout config
+/// file. Characters are plain strings rather than `char` (TOML has no
+/// dedicated character type); `to_key` validates each field is exactly one
+/// character and builds the runtime `Key`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KeyConfig {
+    pub base: String,
+    #[serde(default)]
+    pub shift: Option<String>,
+    #[serde(default)]
+    pub altgr: Option<String>,
+    pub finger: Finger,
+}
+
+impl KeyConfig {
+    fn to_key(&self) -> Result<Key, String> {
+        let base = single_char(&self.base)?;
+        let shift = self.shift.as_deref().map(single_char).transpose()?;
+        let altgr = self.altgr.as_deref().map(single_char).transpose()?;
+        Ok(Key::with_altgr(base, shift, altgr, self.finger))
+    }
+}
+
+/// On-disk representation of a `KeyboardRow`
+#[derive(Debug, Clone, Deserialize)]
+pub struct KeyboardRowConfig {
+    pub row_type: RowType,
+    pub keys: Vec<KeyConfig>,
+}
+
+/// Serde-backed keyboard layout format: rows, each key's `base`/`shift`/`altgr`
+/// characters, its `RowType`, and its `Finger`. Parsed by
+/// `AzertyLayout::from_config_file` so users can describe a bépo,
+/// Swiss-French, or ortholinear layout without recompiling the crate.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LayoutConfig {
+    /// Home row characters, left to right, e.g. `"qsdfghjklm"`
+    pub home_row: String,
+    pub rows: Vec<KeyboardRowConfig>,
+}
+
+impl LayoutConfig {
+    /// Parse `rows`/`home_row` into `KeyboardRow`s, validating that every
+    /// base character across the whole layout is unique (duplicate base keys
+    /// would make `get_base_key`/`find_key` ambiguous). The space character
+    /// is exempt: it's the crate-wide placeholder for "no character here"
+    /// (modifier-row filler slots, the Right Shift position) as well as the
+    /// real spacebar, so it legitimately repeats.
+    pub fn build_rows(&self) -> Result<Vec<KeyboardRow>, String> {
+        let mut rows = Vec::with_capacity(self.rows.len());
+        let mut seen_bases = std::collections::HashSet::new();
+
+        for row_config in &self.rows {
+            let mut keys = Vec::with_capacity(row_config.keys.len());
+            for key_config in &row_config.keys {
+                let key = key_config.to_key()?;
+                if key.base != ' ' && !seen_bases.insert(key.base) {
+                    return Err(format!("duplicate base character '{}'", key.base));
+                }
+                keys.push(key);
+            }
+            rows.push(KeyboardRow {
+                keys,
+                row_type: row_config.row_type,
+            });
+        }
+
+        Ok(rows)
+    }
+
+    /// Home row characters as parsed from `home_row`
+    pub fn home_row_chars(&self) -> Vec<char> {
+        self.home_row.chars().collect()
+    }
+}
+
+fn single_char(s: &str) -> Result<char, String> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(format!("expected exactly one character, got {:?}", s)),
+    }
+}
@@ -0,0 +1,169 @@
+pub mod azerty;
+pub mod colemak;
+pub mod dvorak;
+pub mod ergonomics;
+pub mod layout;
+pub mod qwerty;
+pub mod qwertz;
+pub mod workman;
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+pub use azerty::AzertyLayout;
+pub use colemak::ColemakLayout;
+pub use dvorak::DvorakLayout;
+pub use ergonomics::{analyze_text, ErgonomicsReport};
+pub use layout::{CapsLockState, Finger, Hand, Key, KeyboardLayout, KeyboardRow, RowType};
+pub use qwerty::QwertyLayout;
+pub use qwertz::QwertzLayout;
+pub use workman::WorkmanLayout;
+
+/// Which concrete `KeyboardLayout` the user picked, persisted in `Stats` so
+/// it survives between runs. A plain enum (rather than storing the boxed
+/// trait object) is what gets serialized; `build` turns it back into the
+/// layout `ui::render` and the heatmap actually draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyboardLayoutKind {
+    Azerty,
+    Qwerty,
+    Qwertz,
+    Dvorak,
+    Colemak,
+    Workman,
+}
+
+impl KeyboardLayoutKind {
+    /// All selectable layouts, in the order shown in `AppState::LayoutMenu`
+    pub fn all() -> [KeyboardLayoutKind; 6] {
+        [
+            KeyboardLayoutKind::Azerty,
+            KeyboardLayoutKind::Qwerty,
+            KeyboardLayoutKind::Qwertz,
+            KeyboardLayoutKind::Dvorak,
+            KeyboardLayoutKind::Colemak,
+            KeyboardLayoutKind::Workman,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyboardLayoutKind::Azerty => "AZERTY",
+            KeyboardLayoutKind::Qwerty => "QWERTY",
+            KeyboardLayoutKind::Qwertz => "QWERTZ",
+            KeyboardLayoutKind::Dvorak => "Dvorak",
+            KeyboardLayoutKind::Colemak => "Colemak",
+            KeyboardLayoutKind::Workman => "Workman",
+        }
+    }
+
+    /// Build the concrete layout this kind refers to. `azerty_config_path` is
+    /// only consulted for `Azerty`, letting a user override that layout from
+    /// a config file (see `AzertyLayout::from_config_file`) without
+    /// recompiling; other layouts don't support this yet.
+    pub fn build(&self, azerty_config_path: &Path) -> Box<dyn KeyboardLayout> {
+        match self {
+            KeyboardLayoutKind::Azerty => {
+                Box::new(AzertyLayout::from_config_file(azerty_config_path))
+            }
+            KeyboardLayoutKind::Qwerty => Box::new(QwertyLayout::new()),
+            KeyboardLayoutKind::Qwertz => Box::new(QwertzLayout::new()),
+            KeyboardLayoutKind::Dvorak => Box::new(DvorakLayout::new()),
+            KeyboardLayoutKind::Colemak => Box::new(ColemakLayout::new()),
+            KeyboardLayoutKind::Workman => Box::new(WorkmanLayout::new()),
+        }
+    }
+}
+
+impl Default for KeyboardLayoutKind {
+    fn default() -> Self {
+        KeyboardLayoutKind::Azerty
+    }
+}
+
+/// Shape of the typing cursor drawn in `ui::render`'s "Your input" pane,
+/// persisted in `Stats` the same way `KeyboardLayoutKind` is so it survives
+/// between runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorStyle {
+    Block,
+    Beam,
+    Underline,
+    HollowBlock,
+}
+
+impl CursorStyle {
+    /// All selectable cursor styles
+    pub fn all() -> [CursorStyle; 4] {
+        [
+            CursorStyle::Block,
+            CursorStyle::Beam,
+            CursorStyle::Underline,
+            CursorStyle::HollowBlock,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CursorStyle::Block => "Block",
+            CursorStyle::Beam => "Beam",
+            CursorStyle::Underline => "Underline",
+            CursorStyle::HollowBlock => "Hollow Block",
+        }
+    }
+
+    /// The glyph drawn for this cursor shape.
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            CursorStyle::Block => "█",
+            CursorStyle::Beam => "⎸",
+            CursorStyle::Underline => "_",
+            CursorStyle::HollowBlock => "☐",
+        }
+    }
+}
+
+impl Default for CursorStyle {
+    fn default() -> Self {
+        CursorStyle::Block
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_layout_is_azerty() {
+        assert_eq!(KeyboardLayoutKind::default(), KeyboardLayoutKind::Azerty);
+    }
+
+    #[test]
+    fn test_all_layouts_build_distinct_names() {
+        let no_override = Path::new("/nonexistent/azerty_layout.toml");
+        let names: Vec<&str> = KeyboardLayoutKind::all()
+            .iter()
+            .map(|kind| kind.build(no_override).name())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["AZERTY", "QWERTY", "QWERTZ", "Dvorak", "Colemak", "Workman"]
+        );
+    }
+
+    #[test]
+    fn test_default_cursor_style_is_block() {
+        assert_eq!(CursorStyle::default(), CursorStyle::Block);
+    }
+
+    #[test]
+    fn test_all_cursor_styles_have_distinct_glyphs() {
+        let glyphs: Vec<&str> = CursorStyle::all().iter().map(|s| s.glyph()).collect();
+        let mut unique = glyphs.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(glyphs.len(), unique.len());
+    }
+}
@@ -0,0 +1,167 @@
+use super::layout::{Finger, Key, KeyboardLayout, KeyboardRow, RowType};
+
+/// German QWERTZ keyboard layout: a QWERTY derivative with Y and Z swapped.
+/// Simplified to the ASCII subset shared with `QwertyLayout` (no `ä`/`ö`/
+/// `ü`/`ß`), matching this crate's other non-AZERTY layouts.
+#[derive(Debug, Clone)]
+pub struct QwertzLayout {
+    home_row: Vec<char>,
+    rows: Vec<KeyboardRow>,
+}
+
+impl QwertzLayout {
+    pub fn new() -> Self {
+        Self {
+            home_row: vec!['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', ';'],
+            rows: vec![
+                Self::number_row(),
+                Self::top_row(),
+                Self::home_row_keys(),
+                Self::bottom_row(),
+            ],
+        }
+    }
+
+    fn number_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Number,
+            keys: vec![
+                Key::new('`', Some('~'), Finger::LeftPinky),
+                Key::new('1', Some('!'), Finger::LeftPinky),
+                Key::new('2', Some('"'), Finger::LeftRing),
+                Key::new('3', Some('#'), Finger::LeftMiddle),
+                Key::new('4', Some('$'), Finger::LeftIndex),
+                Key::new('5', Some('%'), Finger::LeftIndex),
+                Key::new('6', Some('^'), Finger::RightIndex),
+                Key::new('7', Some('&'), Finger::RightIndex),
+                Key::new('8', Some('*'), Finger::RightMiddle),
+                Key::new('9', Some('('), Finger::RightRing),
+                Key::new('0', Some(')'), Finger::RightPinky),
+                Key::new('-', Some('_'), Finger::RightPinky),
+                Key::new('=', Some('+'), Finger::RightPinky),
+            ],
+        }
+    }
+
+    fn top_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Top,
+            keys: vec![
+                Key::new('q', Some('Q'), Finger::LeftPinky),
+                Key::new('w', Some('W'), Finger::LeftRing),
+                Key::new('e', Some('E'), Finger::LeftMiddle),
+                Key::new('r', Some('R'), Finger::LeftIndex),
+                Key::new('t', Some('T'), Finger::LeftIndex),
+                Key::new('z', Some('Z'), Finger::RightIndex),
+                Key::new('u', Some('U'), Finger::RightIndex),
+                Key::new('i', Some('I'), Finger::RightMiddle),
+                Key::new('o', Some('O'), Finger::RightRing),
+                Key::new('p', Some('P'), Finger::RightPinky),
+                Key::new('[', Some('{'), Finger::RightPinky),
+                Key::new(']', Some('}'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key
+            ],
+        }
+    }
+
+    fn home_row_keys() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Home,
+            keys: vec![
+                Key::new('a', Some('A'), Finger::LeftPinky),
+                Key::new('s', Some('S'), Finger::LeftRing),
+                Key::new('d', Some('D'), Finger::LeftMiddle),
+                Key::new('f', Some('F'), Finger::LeftIndex),
+                Key::new('g', Some('G'), Finger::LeftIndex),
+                Key::new('h', Some('H'), Finger::RightIndex),
+                Key::new('j', Some('J'), Finger::RightIndex),
+                Key::new('k', Some('K'), Finger::RightMiddle),
+                Key::new('l', Some('L'), Finger::RightRing),
+                Key::new(';', Some(':'), Finger::RightPinky),
+                Key::new('\'', Some('"'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key continuation
+            ],
+        }
+    }
+
+    fn bottom_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Bottom,
+            keys: vec![
+                Key::new('y', Some('Y'), Finger::LeftPinky),
+                Key::new('x', Some('X'), Finger::LeftRing),
+                Key::new('c', Some('C'), Finger::LeftMiddle),
+                Key::new('v', Some('V'), Finger::LeftIndex),
+                Key::new('b', Some('B'), Finger::LeftIndex),
+                Key::new('n', Some('N'), Finger::RightIndex),
+                Key::new('m', Some('M'), Finger::RightIndex),
+                Key::new(',', Some('<'), Finger::RightMiddle),
+                Key::new('.', Some('>'), Finger::RightRing),
+                Key::new('/', Some('?'), Finger::RightPinky),
+                Key::new('\0', None, Finger::RightPinky), // Right Shift placeholder
+            ],
+        }
+    }
+}
+
+impl Default for QwertzLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyboardLayout for QwertzLayout {
+    fn name(&self) -> &'static str {
+        "QWERTZ"
+    }
+
+    fn home_row(&self) -> &[char] {
+        &self.home_row
+    }
+
+    fn rows(&self) -> &[KeyboardRow] {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qwertz_home_row() {
+        let layout = QwertzLayout::new();
+        assert_eq!(
+            layout.home_row(),
+            &['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l', ';']
+        );
+    }
+
+    #[test]
+    fn test_y_and_z_are_swapped_from_qwerty() {
+        let layout = QwertzLayout::new();
+        assert_eq!(layout.find_key('z').unwrap().finger, Finger::RightIndex);
+        assert_eq!(layout.find_key('y').unwrap().finger, Finger::LeftPinky);
+    }
+
+    #[test]
+    fn test_get_base_key_for_shift_variant() {
+        let layout = QwertzLayout::new();
+        assert_eq!(layout.get_base_key('A'), Some('a'));
+        assert_eq!(layout.get_base_key('!'), Some('1'));
+    }
+
+    #[test]
+    fn test_requires_shift() {
+        let layout = QwertzLayout::new();
+        assert!(layout.requires_shift('A'));
+        assert!(!layout.requires_shift('a'));
+    }
+
+    #[test]
+    fn test_find_key_finger_assignment() {
+        let layout = QwertzLayout::new();
+        let key = layout.find_key('j').unwrap();
+        assert_eq!(key.finger, Finger::RightIndex);
+    }
+}
@@ -0,0 +1,150 @@
+use super::layout::{Finger, Key, KeyboardLayout, KeyboardRow, RowType};
+
+/// Workman keyboard layout
+#[derive(Debug, Clone)]
+pub struct WorkmanLayout {
+    home_row: Vec<char>,
+    rows: Vec<KeyboardRow>,
+}
+
+impl WorkmanLayout {
+    pub fn new() -> Self {
+        Self {
+            home_row: vec!['a', 's', 'h', 't', 'g', 'y', 'n', 'e', 'o', 'i'],
+            rows: vec![
+                Self::number_row(),
+                Self::top_row(),
+                Self::home_row_keys(),
+                Self::bottom_row(),
+            ],
+        }
+    }
+
+    fn number_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Number,
+            keys: vec![
+                Key::new('`', Some('~'), Finger::LeftPinky),
+                Key::new('1', Some('!'), Finger::LeftPinky),
+                Key::new('2', Some('@'), Finger::LeftRing),
+                Key::new('3', Some('#'), Finger::LeftMiddle),
+                Key::new('4', Some('$'), Finger::LeftIndex),
+                Key::new('5', Some('%'), Finger::LeftIndex),
+                Key::new('6', Some('^'), Finger::RightIndex),
+                Key::new('7', Some('&'), Finger::RightIndex),
+                Key::new('8', Some('*'), Finger::RightMiddle),
+                Key::new('9', Some('('), Finger::RightRing),
+                Key::new('0', Some(')'), Finger::RightPinky),
+                Key::new('-', Some('_'), Finger::RightPinky),
+                Key::new('=', Some('+'), Finger::RightPinky),
+            ],
+        }
+    }
+
+    fn top_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Top,
+            keys: vec![
+                Key::new('q', Some('Q'), Finger::LeftPinky),
+                Key::new('d', Some('D'), Finger::LeftRing),
+                Key::new('r', Some('R'), Finger::LeftMiddle),
+                Key::new('w', Some('W'), Finger::LeftIndex),
+                Key::new('b', Some('B'), Finger::LeftIndex),
+                Key::new('j', Some('J'), Finger::RightIndex),
+                Key::new('f', Some('F'), Finger::RightIndex),
+                Key::new('u', Some('U'), Finger::RightMiddle),
+                Key::new('p', Some('P'), Finger::RightRing),
+                Key::new(';', Some(':'), Finger::RightPinky),
+                Key::new('[', Some('{'), Finger::RightPinky),
+                Key::new(']', Some('}'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key
+            ],
+        }
+    }
+
+    fn home_row_keys() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Home,
+            keys: vec![
+                Key::new('a', Some('A'), Finger::LeftPinky),
+                Key::new('s', Some('S'), Finger::LeftRing),
+                Key::new('h', Some('H'), Finger::LeftMiddle),
+                Key::new('t', Some('T'), Finger::LeftIndex),
+                Key::new('g', Some('G'), Finger::LeftIndex),
+                Key::new('y', Some('Y'), Finger::RightIndex),
+                Key::new('n', Some('N'), Finger::RightIndex),
+                Key::new('e', Some('E'), Finger::RightMiddle),
+                Key::new('o', Some('O'), Finger::RightRing),
+                Key::new('i', Some('I'), Finger::RightPinky),
+                Key::new('\'', Some('"'), Finger::RightPinky),
+                Key::new('\n', None, Finger::RightPinky), // Enter key continuation
+            ],
+        }
+    }
+
+    fn bottom_row() -> KeyboardRow {
+        KeyboardRow {
+            row_type: RowType::Bottom,
+            keys: vec![
+                Key::new('z', Some('Z'), Finger::LeftPinky),
+                Key::new('x', Some('X'), Finger::LeftRing),
+                Key::new('m', Some('M'), Finger::LeftMiddle),
+                Key::new('c', Some('C'), Finger::LeftIndex),
+                Key::new('v', Some('V'), Finger::LeftIndex),
+                Key::new('k', Some('K'), Finger::RightIndex),
+                Key::new('l', Some('L'), Finger::RightIndex),
+                Key::new(',', Some('<'), Finger::RightMiddle),
+                Key::new('.', Some('>'), Finger::RightRing),
+                Key::new('/', Some('?'), Finger::RightPinky),
+                Key::new('\0', None, Finger::RightPinky), // Right Shift placeholder
+            ],
+        }
+    }
+}
+
+impl Default for WorkmanLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyboardLayout for WorkmanLayout {
+    fn name(&self) -> &'static str {
+        "Workman"
+    }
+
+    fn home_row(&self) -> &[char] {
+        &self.home_row
+    }
+
+    fn rows(&self) -> &[KeyboardRow] {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workman_home_row() {
+        let layout = WorkmanLayout::new();
+        assert_eq!(
+            layout.home_row(),
+            &['a', 's', 'h', 't', 'g', 'y', 'n', 'e', 'o', 'i']
+        );
+    }
+
+    #[test]
+    fn test_get_base_key_for_shift_variant() {
+        let layout = WorkmanLayout::new();
+        assert_eq!(layout.get_base_key('D'), Some('d'));
+    }
+
+    #[test]
+    fn test_find_key_finger_assignment() {
+        let layout = WorkmanLayout::new();
+        let key = layout.find_key('y').unwrap();
+        assert_eq!(key.finger, Finger::RightIndex);
+    }
+}
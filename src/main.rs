@@ -1,4 +1,5 @@
 mod app;
+mod cli_docs;
 mod content;
 mod data;
 mod engine;
@@ -6,21 +7,128 @@ mod keyboard;
 mod ui;
 
 use app::App;
+use content::bigram::french_bigrams;
+use content::bigram_mastery::BigramMasteryStore;
+use data::Storage;
+use keyboard::KeyboardLayoutKind;
+use ui::terminal::TerminalGuard;
 
 fn main() -> std::io::Result<()> {
-    // Initialiser le terminal
-    let mut terminal = ratatui::init();
+    let mut args = std::env::args().skip(1);
 
-    // Créer et lancer l'app
-    let result = run_app(&mut terminal);
+    if let Some(arg) = args.next() {
+        if arg == "next" {
+            return run_next_command();
+        }
+        if arg == "util" {
+            return run_util_command(args);
+        }
+    }
+
+    let layout_override = parse_layout_arg(std::env::args().skip(1));
 
-    // Restaurer le terminal
-    ratatui::restore();
+    // Put the terminal into raw/alternate-screen mode; restored on drop
+    // (clean exit) and by the panic hook it installs (crash).
+    let _terminal_guard = TerminalGuard::new()?;
+    let backend = ratatui::backend::CrosstermBackend::new(std::io::stdout());
+    let mut terminal = ratatui::Terminal::new(backend)?;
 
-    result
+    // Créer et lancer l'app
+    run_app(&mut terminal, layout_override)
 }
 
-fn run_app(terminal: &mut ratatui::DefaultTerminal) -> std::io::Result<()> {
-    let mut app = App::new()?;
+fn run_app(
+    terminal: &mut ratatui::DefaultTerminal,
+    layout_override: Option<KeyboardLayoutKind>,
+) -> std::io::Result<()> {
+    let mut app = App::new_with_layout_override(layout_override)?;
     app.run(terminal)
 }
+
+/// `typer next`: print the bigram the user most needs to drill, picked by
+/// `BigramMasteryStore::next_weakest` over the persisted mastery store,
+/// without launching the TUI.
+fn run_next_command() -> std::io::Result<()> {
+    let storage = Storage::new()?;
+    let mastery = BigramMasteryStore::load(&storage.bigram_mastery_path());
+    let bigrams = french_bigrams();
+
+    match mastery.next_weakest(&bigrams) {
+        Some(bigram) => println!("{}", bigram.pattern),
+        None => println!("No weak bigrams found yet — keep practicing!"),
+    }
+
+    Ok(())
+}
+
+/// `typer util <subcommand>`: hidden developer utilities, currently just
+/// `markdown-help` (see `cli_docs`). Unknown or missing subcommands are a
+/// silent no-op, matching `run_next_command`'s "keep `main` simple" style.
+fn run_util_command(mut args: impl Iterator<Item = String>) -> std::io::Result<()> {
+    if args.next().as_deref() == Some("markdown-help") {
+        println!("{}", cli_docs::render_markdown(&cli_docs::ROOT));
+    }
+
+    Ok(())
+}
+
+/// Parse a `--layout <name>` flag (e.g. `--layout dvorak`) out of the CLI
+/// arguments, matching names case-insensitively against
+/// `KeyboardLayoutKind::label()`. Unknown or malformed values are ignored
+/// so the app falls back to the persisted layout choice.
+fn parse_layout_arg(args: impl Iterator<Item = String>) -> Option<KeyboardLayoutKind> {
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        let value = if let Some(value) = arg.strip_prefix("--layout=") {
+            Some(value.to_string())
+        } else if arg == "--layout" {
+            args.next()
+        } else {
+            None
+        };
+
+        if let Some(value) = value {
+            return KeyboardLayoutKind::all()
+                .into_iter()
+                .find(|kind| kind.label().eq_ignore_ascii_case(&value));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_layout_arg_matches_case_insensitively() {
+        let args = vec!["--layout".to_string(), "Dvorak".to_string()];
+        assert_eq!(
+            parse_layout_arg(args.into_iter()),
+            Some(KeyboardLayoutKind::Dvorak)
+        );
+    }
+
+    #[test]
+    fn test_parse_layout_arg_supports_equals_form() {
+        let args = vec!["--layout=qwertz".to_string()];
+        assert_eq!(
+            parse_layout_arg(args.into_iter()),
+            Some(KeyboardLayoutKind::Qwertz)
+        );
+    }
+
+    #[test]
+    fn test_parse_layout_arg_ignores_unknown_value() {
+        let args = vec!["--layout".to_string(), "nonsense".to_string()];
+        assert_eq!(parse_layout_arg(args.into_iter()), None);
+    }
+
+    #[test]
+    fn test_parse_layout_arg_none_when_absent() {
+        let args: Vec<String> = vec![];
+        assert_eq!(parse_layout_arg(args.into_iter()), None);
+    }
+}
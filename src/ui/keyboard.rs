@@ -5,16 +5,30 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthChar;
 
 use crate::engine::analytics::AdaptiveAnalytics;
-use crate::keyboard::{AzertyLayout, Hand, Key, RowType};
+use crate::keyboard::{Hand, Key, KeyboardLayout, RowType};
+
+pub use crate::keyboard::CursorStyle;
+
+/// Target on-screen column width for a key cell (`[` + glyph + `]` + trailing
+/// padding), so rows stay aligned even when a key's base character renders
+/// wider than one column (e.g. a CJK glyph on a future layout) or narrower
+/// than one (a zero-width combining mark attached to its base).
+const KEY_CELL_WIDTH: usize = 4;
 
 /// Keyboard display configuration
+#[derive(Debug, Clone)]
 pub struct KeyboardConfig {
     pub _show_shift_indicators: bool,
     pub show_heatmap: bool,
     pub show_finger_colors: bool,
     pub _compact_mode: bool,
+    /// Whether to append the "Tab: hide keyboard | H: heatmap | ..." hint
+    /// line under the keyboard. Off in contexts (like the stats page) that
+    /// already show their own instructions.
+    pub show_footer_shortcuts: bool,
 }
 
 impl Default for KeyboardConfig {
@@ -24,8 +38,83 @@ impl Default for KeyboardConfig {
             show_heatmap: true,
             show_finger_colors: true,
             _compact_mode: false,
+            show_footer_shortcuts: true,
+        }
+    }
+}
+
+/// One shortcut shown in a keybinding bar: the key token, the action it
+/// performs, and (for toggles) whether that mode is currently active. Plain
+/// actions (e.g. "hide keyboard") pass `active: None`; toggles pass
+/// `Some(is_on)` so the bar can mark which state they're currently in.
+pub struct KeybindEntry {
+    pub key: &'static str,
+    pub action: &'static str,
+    pub active: Option<bool>,
+}
+
+impl KeybindEntry {
+    const fn new(key: &'static str, action: &'static str) -> Self {
+        Self {
+            key,
+            action,
+            active: None,
+        }
+    }
+
+    const fn toggle(key: &'static str, action: &'static str, active: bool) -> Self {
+        Self {
+            key,
+            action,
+            active: Some(active),
+        }
+    }
+}
+
+/// Shortcuts shown under the full keyboard view, reflecting the config's
+/// actual toggle state (in the spirit of zellij's status-bar shortcuts).
+fn keyboard_footer_entries(config: &KeyboardConfig) -> Vec<KeybindEntry> {
+    vec![
+        KeybindEntry::new("Tab", "hide keyboard"),
+        KeybindEntry::toggle("H", "heatmap", config.show_heatmap),
+        KeybindEntry::toggle("F", "finger colors", config.show_finger_colors),
+    ]
+}
+
+/// Render a list of keybinding entries into one styled line: each key token
+/// is styled distinctly from its label, and active toggles are marked
+/// inline (`ON`/`OFF`) instead of baked into a static format string.
+fn render_keybinding_bar(entries: &[KeybindEntry]) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+        }
+
+        spans.push(Span::styled(
+            entry.key.to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(": ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(
+            entry.action.to_string(),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        if let Some(active) = entry.active {
+            let (label, color) = if active {
+                (" ON", Color::Green)
+            } else {
+                (" OFF", Color::DarkGray)
+            };
+            spans.push(Span::styled(label, Style::default().fg(color)));
         }
     }
+
+    Line::from(spans)
 }
 
 /// Calculate color based on accuracy heatmap
@@ -55,7 +144,7 @@ fn get_key_accuracy(key: char, analytics: &Option<AdaptiveAnalytics>) -> Option<
 fn should_highlight_shift(
     next_char: Option<char>,
     requires_shift: bool,
-    layout: &AzertyLayout,
+    layout: &dyn KeyboardLayout,
     is_left_shift: bool,
 ) -> bool {
     if !requires_shift {
@@ -127,8 +216,12 @@ fn render_key(
         Style::default().fg(Color::White)
     };
 
-    // Format: [x] - 3 characters wide
-    let text = format!("[{}]", display_char);
+    // Pad the `[x]` cell out to `KEY_CELL_WIDTH` display columns so a wide
+    // (e.g. double-width CJK) or zero-width (combining mark) base character
+    // doesn't shift every key after it out of alignment.
+    let char_width = display_char.width().unwrap_or(1);
+    let padding = " ".repeat(KEY_CELL_WIDTH.saturating_sub(2 + char_width));
+    let text = format!("[{}]{}", display_char, padding);
 
     Span::styled(text, style)
 }
@@ -140,7 +233,7 @@ fn render_keyboard_row<'a>(
     requires_shift: bool,
     analytics: &Option<AdaptiveAnalytics>,
     config: &KeyboardConfig,
-    layout: &AzertyLayout,
+    layout: &dyn KeyboardLayout,
 ) -> Line<'a> {
     let mut spans = Vec::new();
 
@@ -259,11 +352,6 @@ fn render_keyboard_row<'a>(
                 config,
             );
             spans.push(key_span);
-
-            // Add space between keys (except after last key)
-            if i < row.keys.len() - 1 {
-                spans.push(Span::raw(" "));
-            }
         }
     }
 
@@ -290,7 +378,7 @@ fn render_keyboard_row<'a>(
 pub fn render_keyboard(
     f: &mut Frame,
     area: Rect,
-    layout: &AzertyLayout,
+    layout: &dyn KeyboardLayout,
     next_char: Option<char>,
     analytics: &Option<AdaptiveAnalytics>,
     config: &KeyboardConfig,
@@ -300,7 +388,7 @@ pub fn render_keyboard(
     let mut lines = Vec::new();
 
     // Build keyboard rows
-    for row in &layout.rows {
+    for row in layout.rows() {
         let line = render_keyboard_row(row, next_char, requires_shift, analytics, config, layout);
         lines.push(line);
     }
@@ -358,13 +446,12 @@ pub fn render_keyboard(
         lines.push(legend2);
     }
 
-    // Footer hint - always show all available toggles
-    lines.push(Line::from(""));
-    let footer_text = " Tab: hide keyboard | H: toggle heatmap | F: toggle finger colors";
-    lines.push(Line::from(Span::styled(
-        footer_text,
-        Style::default().fg(Color::DarkGray),
-    )));
+    // Footer hint - always show all available toggles, styled via the
+    // keybinding bar so it can't drift from `config`'s actual state
+    if config.show_footer_shortcuts {
+        lines.push(Line::from(""));
+        lines.push(render_keybinding_bar(&keyboard_footer_entries(config)));
+    }
 
     let keyboard_widget = Paragraph::new(lines)
         .block(
@@ -382,22 +469,27 @@ pub fn render_keyboard(
 pub fn render_keyboard_compact(
     f: &mut Frame,
     area: Rect,
-    layout: &AzertyLayout,
+    layout: &dyn KeyboardLayout,
     next_char: Option<char>,
 ) {
-    let text = if let Some(c) = next_char {
+    let next_key_text = if let Some(c) = next_char {
         let requires_shift = layout.requires_shift(c);
 
         if requires_shift {
-            format!(" Next key: [{}] (⇧ Shift)              (Tab to expand)", c)
+            format!(" Next key: [{}] (⇧ Shift)", c)
         } else {
-            format!(" Next key: [{}]                     (Tab to expand)", c)
+            format!(" Next key: [{}]", c)
         }
     } else {
-        " No active session                (Tab to expand)".to_string()
+        " No active session".to_string()
     };
 
-    let compact_widget = Paragraph::new(text)
+    let expand_hint = render_keybinding_bar(&[KeybindEntry::new("Tab", "expand")]);
+
+    let mut spans = vec![Span::raw(next_key_text), Span::raw("   ")];
+    spans.extend(expand_hint.spans);
+
+    let compact_widget = Paragraph::new(Line::from(spans))
         .style(Style::default().fg(Color::Cyan))
         .alignment(Alignment::Left)
         .block(
@@ -0,0 +1,8 @@
+pub mod keyboard;
+pub mod render;
+pub mod terminal;
+
+pub use render::{
+    matching_lesson_indices, render, render_duration_menu, render_layout_menu, render_menu,
+    render_results, MenuPreviewCache, StatsTabState,
+};
@@ -1,17 +1,21 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, Paragraph, Tabs},
     Frame,
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use crate::content::Lesson;
-use crate::data::Stats;
+use crate::content::lesson::LessonType;
+use crate::content::{ContentGenerator, Lesson};
+use crate::data::{Stats, StatsDisplayConfig, StatsLayoutConfig, StatsPanel};
 use crate::engine::analytics::{AdaptiveAnalytics, MasteryLevel};
-use crate::engine::TypingSession;
-use crate::keyboard::AzertyLayout;
-use crate::ui::keyboard::{render_keyboard, render_keyboard_compact, KeyboardConfig};
+use crate::engine::{GhostReplay, TypingSession};
+use crate::keyboard::{KeyboardLayout, KeyboardLayoutKind};
+use crate::ui::keyboard::{render_keyboard, render_keyboard_compact, CursorStyle, KeyboardConfig};
 use std::collections::HashMap;
 
 /// Structure for visible text window
@@ -30,21 +34,32 @@ struct VisibleWindow {
     line_start_indices: Vec<usize>,
 }
 
-/// Wrap text to fit terminal width using word boundaries
+/// Wrap text to fit terminal display width, at word boundaries.
+///
+/// Splits on Unicode word boundaries (`split_word_bounds`) rather than
+/// `split_whitespace`, so every token — including runs of spaces, tabs, and
+/// code indentation — is preserved verbatim and simply redistributed across
+/// lines; concatenating the returned lines reproduces `content` exactly,
+/// with no inserted or dropped whitespace. Line width is measured in
+/// terminal display columns via `unicode-width` (so CJK/emoji count as
+/// their true cell width) instead of byte or char length, which otherwise
+/// disagrees with `find_cursor_line`'s char-based accounting on any
+/// multi-byte input.
 fn wrap_text(content: &str, width: usize) -> Vec<String> {
     let mut lines = Vec::new();
     let mut current_line = String::new();
+    let mut current_width = 0;
 
-    for word in content.split_whitespace() {
-        if current_line.is_empty() {
-            current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= width {
-            current_line.push(' ');
-            current_line.push_str(word);
-        } else {
-            lines.push(current_line);
-            current_line = word.to_string();
+    for token in content.split_word_bounds() {
+        let token_width = token.width();
+
+        if !current_line.is_empty() && current_width + token_width > width {
+            lines.push(std::mem::take(&mut current_line));
+            current_width = 0;
         }
+
+        current_line.push_str(token);
+        current_width += token_width;
     }
 
     if !current_line.is_empty() {
@@ -54,7 +69,13 @@ fn wrap_text(content: &str, width: usize) -> Vec<String> {
     lines
 }
 
-/// Find which wrapped line contains a given character position
+/// Find which wrapped line contains a given character position.
+///
+/// `char_pos` is in the same units as `TypingSession::current_index`
+/// (a count of `char`s into the original content), so this walks `lines`
+/// accumulating `chars().count()` with no separator fudge — `wrap_text`
+/// guarantees the lines already contain every original character exactly
+/// once, back to back.
 fn find_cursor_line(lines: &[String], char_pos: usize) -> (usize, usize) {
     let mut char_count = 0;
 
@@ -63,7 +84,7 @@ fn find_cursor_line(lines: &[String], char_pos: usize) -> (usize, usize) {
         if char_pos < char_count + line_len {
             return (line_idx, char_pos.saturating_sub(char_count));
         }
-        char_count += line_len + 1; // +1 for space between words
+        char_count += line_len;
     }
 
     // If not found, return last line
@@ -92,15 +113,12 @@ fn extract_visible_window(session: &TypingSession, width: usize) -> VisibleWindo
         .cloned()
         .collect();
 
-    // Compute cumulative character indices for visible lines
+    // Compute cumulative character indices for visible lines. `wrap_text`
+    // guarantees every line is a verbatim, contiguous slice of `content`'s
+    // characters, so no separator needs to be accounted for between lines.
     let mut line_start_indices = Vec::new();
     for idx in cursor_line_idx..(cursor_line_idx + visible_lines.len()) {
-        // Calculate chars from start of content to this line
-        let chars_before_line: usize = lines
-            .iter()
-            .take(idx)
-            .map(|l| l.chars().count() + 1) // +1 for space between words
-            .sum();
+        let chars_before_line: usize = lines.iter().take(idx).map(|l| l.chars().count()).sum();
         line_start_indices.push(chars_before_line);
     }
 
@@ -121,6 +139,7 @@ fn extract_visible_window(session: &TypingSession, width: usize) -> VisibleWindo
 fn create_styled_expected_text(
     session: &TypingSession,
     window: &VisibleWindow,
+    ghost_index: Option<usize>,
 ) -> Vec<Line<'static>> {
     let mut result_lines = Vec::new();
 
@@ -151,6 +170,11 @@ fn create_styled_expected_text(
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            } else if ghost_index == Some(absolute_index) {
+                // Ghost's current position - dimmed marker racing ahead of (or behind) the cursor
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::UNDERLINED)
             } else {
                 // Remaining - normal
                 Style::default().fg(Color::White)
@@ -173,12 +197,16 @@ pub fn render(
     wpm: f64,
     accuracy: f64,
     keyboard_visible: bool,
-    keyboard_layout: &AzertyLayout,
+    keyboard_layout: &dyn KeyboardLayout,
     analytics: &Option<AdaptiveAnalytics>,
     keyboard_config: &KeyboardConfig,
     lesson_name: &str,
+    ghost: Option<&GhostReplay>,
+    cursor_style: CursorStyle,
 ) {
     let terminal_height = f.area().height;
+    let ghost_index = ghost.map(|g| g.index_at(session.duration()));
+    let ghost_wpm_delta = ghost.map(|g| wpm - g.wpm_at(session.duration()));
 
     // Dynamic constraints based on keyboard visibility and terminal size
     // New layout: Header -> Stats -> Content -> Keyboard -> Spacer -> Instructions
@@ -243,11 +271,12 @@ pub fn render(
         wpm,
         accuracy,
         session.remaining_time(),
+        ghost_wpm_delta,
     );
     chunk_idx += 1;
 
     // Content area (typing area)
-    render_typing_area(f, chunks[chunk_idx], session);
+    render_typing_area(f, chunks[chunk_idx], session, ghost_index, cursor_style);
     chunk_idx += 1;
 
     // Keyboard (follows content with margin)
@@ -295,7 +324,11 @@ fn render_header(f: &mut Frame, area: Rect, lesson_name: &str) {
 }
 
 /// Create multiline colored input display
-fn create_colored_input_multiline(session: &TypingSession, width: usize) -> Vec<Line<'static>> {
+fn create_colored_input_multiline(
+    session: &TypingSession,
+    width: usize,
+    cursor_style: CursorStyle,
+) -> Vec<Line<'static>> {
     let effective_width = width.saturating_sub(4);
     let mut lines = Vec::new();
     let mut current_line_spans = Vec::new();
@@ -329,12 +362,34 @@ fn create_colored_input_multiline(session: &TypingSession, width: usize) -> Vec<
 
     // Add cursor to current line
     if !session.is_complete() {
-        current_line_spans.push(Span::styled(
-            "█",
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::SLOW_BLINK),
-        ));
+        let next_char = session.content.chars().nth(session.current_index);
+        let blink = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::SLOW_BLINK);
+
+        match (cursor_style, next_char) {
+            // Beam: a thin marker followed by the character it sits before.
+            (CursorStyle::Beam, Some(next_char)) => {
+                current_line_spans.push(Span::styled(CursorStyle::Beam.glyph(), blink));
+                current_line_spans.push(Span::styled(
+                    next_char.to_string(),
+                    Style::default().fg(Color::White),
+                ));
+            }
+            // Underline: the character itself, underlined, rather than hidden.
+            (CursorStyle::Underline, Some(next_char)) => {
+                current_line_spans.push(Span::styled(
+                    next_char.to_string(),
+                    blink.add_modifier(Modifier::UNDERLINED),
+                ));
+            }
+            // Block/HollowBlock fully occupy the cell, so they replace the
+            // character as before; Beam/Underline fall back here too once
+            // there's no next character left to draw under them.
+            _ => {
+                current_line_spans.push(Span::styled(cursor_style.glyph(), blink));
+            }
+        }
     }
 
     // Push final line
@@ -347,7 +402,13 @@ fn create_colored_input_multiline(session: &TypingSession, width: usize) -> Vec<
 }
 
 /// Rendu de la zone de typing (multiline with sliding window)
-fn render_typing_area(f: &mut Frame, area: Rect, session: &TypingSession) {
+fn render_typing_area(
+    f: &mut Frame,
+    area: Rect,
+    session: &TypingSession,
+    ghost_index: Option<usize>,
+    cursor_style: CursorStyle,
+) {
     let terminal_width = area.width as usize;
 
     let chunks = Layout::default()
@@ -360,7 +421,7 @@ fn render_typing_area(f: &mut Frame, area: Rect, session: &TypingSession) {
 
     // Expected text - 3-line sliding window with character-level styling
     let window = extract_visible_window(session, terminal_width);
-    let expected_lines = create_styled_expected_text(session, &window);
+    let expected_lines = create_styled_expected_text(session, &window, ghost_index);
 
     let expected_text = Paragraph::new(expected_lines).block(
         Block::default()
@@ -372,7 +433,7 @@ fn render_typing_area(f: &mut Frame, area: Rect, session: &TypingSession) {
     f.render_widget(expected_text, chunks[0]);
 
     // User input - multiline colored display
-    let user_input_lines = create_colored_input_multiline(session, terminal_width);
+    let user_input_lines = create_colored_input_multiline(session, terminal_width, cursor_style);
     let input_widget = Paragraph::new(user_input_lines).block(
         Block::default()
             .title("Your input")
@@ -390,8 +451,9 @@ fn render_stats(
     wpm: f64,
     accuracy: f64,
     remaining: std::time::Duration,
+    ghost_wpm_delta: Option<f64>,
 ) {
-    let stats_text = format!(
+    let mut stats_text = format!(
         " WPM: {:.0}  │  Accuracy: {:.1}%  │  Time Remaining: {:02}:{:02}",
         wpm,
         accuracy,
@@ -399,6 +461,10 @@ fn render_stats(
         remaining.as_secs() % 60
     );
 
+    if let Some(delta) = ghost_wpm_delta {
+        stats_text.push_str(&format!("  │  Ghost: {:+.0} wpm", delta));
+    }
+
     let stats = Paragraph::new(stats_text)
         .style(Style::default().fg(Color::Yellow))
         .alignment(Alignment::Center)
@@ -411,6 +477,59 @@ fn render_stats(
     f.render_widget(stats, area);
 }
 
+/// Render a centered row of `(key, action)` hints — key bold/highlighted,
+/// action in gray, pairs joined by "  •  " — stopping before any pair that
+/// would overflow `area`'s width and appending a "+N more" indicator instead
+/// of letting the line wrap or clip.
+fn render_keyhints(f: &mut Frame, area: Rect, hints: &[(&str, &str)]) {
+    const SEPARATOR: &str = "  •  ";
+    let max_width = area.width as usize;
+
+    let mut spans = Vec::new();
+    let mut used_width = 0;
+    let mut shown = 0;
+
+    for (key, action) in hints {
+        let pair_width = key.width() + 1 + action.width();
+        let separator_width = if shown > 0 { SEPARATOR.width() } else { 0 };
+
+        if used_width + separator_width + pair_width > max_width {
+            break;
+        }
+
+        if shown > 0 {
+            spans.push(Span::raw(SEPARATOR));
+        }
+
+        spans.push(Span::styled(
+            key.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            action.to_string(),
+            Style::default().fg(Color::Gray),
+        ));
+
+        used_width += separator_width + pair_width;
+        shown += 1;
+    }
+
+    if shown < hints.len() {
+        spans.push(Span::styled(
+            format!("  +{} more", hints.len() - shown),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let instructions = vec![Line::from(""), Line::from(spans)];
+    let instructions_widget = Paragraph::new(instructions).alignment(Alignment::Center);
+
+    f.render_widget(instructions_widget, area);
+}
+
 /// Rendu des instructions
 fn render_instructions(f: &mut Frame, area: Rect) {
     let instructions = vec![
@@ -426,24 +545,288 @@ fn render_instructions(f: &mut Frame, area: Rect) {
     f.render_widget(instructions_widget, area);
 }
 
+/// Terminal width below which the lesson preview pane is collapsed in favor
+/// of the full-width list, since there isn't room to render both legibly.
+const PREVIEW_MIN_TERMINAL_WIDTH: u16 = 80;
+
+/// Length of the generated content sample used to build the preview's
+/// wrapped-text excerpt.
+const PREVIEW_SAMPLE_LENGTH: usize = 200;
+
+/// How many wrapped content lines the preview shows.
+const PREVIEW_CONTENT_LINES: usize = 5;
+
+/// Cache of rendered lesson-preview panes, keyed by lesson index and the
+/// width they were wrapped at, so scrolling fast with held arrow keys
+/// doesn't re-wrap lesson content on every single frame.
+#[derive(Default)]
+pub struct MenuPreviewCache {
+    entries: HashMap<(usize, usize), Vec<Line<'static>>>,
+}
+
+impl MenuPreviewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetch the cached preview for `lesson` at `index`, building (and
+    /// caching) it first if this is the first time it's been requested at
+    /// this wrap `width`.
+    fn get_or_build(&mut self, index: usize, lesson: &Lesson, width: usize) -> Vec<Line<'static>> {
+        self.entries
+            .entry((index, width))
+            .or_insert_with(|| build_lesson_preview(lesson, width))
+            .clone()
+    }
+}
+
+/// The "level" driving a lesson's difficulty, where the lesson type tracks
+/// one; `KeyPair`/`KeyPairGroup`/`Adaptive` have no single level to report.
+fn lesson_level(lesson: &Lesson) -> Option<usize> {
+    match &lesson.lesson_type {
+        LessonType::Bigram { level, .. }
+        | LessonType::Trigram { level, .. }
+        | LessonType::CommonWords { level, .. }
+        | LessonType::CodeSymbols { level, .. }
+        | LessonType::CodeSnippet { level, .. }
+        | LessonType::Identifier { level, .. }
+        | LessonType::Inflection { level, .. } => Some(*level),
+        LessonType::Chord { level } => Some(*level as usize),
+        LessonType::FingerPair { level, .. } => Some(*level as usize),
+        LessonType::KeyPair { .. }
+        | LessonType::KeyPairGroup { .. }
+        | LessonType::Adaptive
+        | LessonType::Custom { .. } => None,
+    }
+}
+
+/// Rough target WPM for a given level: later levels introduce harder key
+/// combinations, so the realistic speed target eases off as level
+/// increases, floored so advanced drills still carry a meaningful target.
+fn target_wpm_for_level(level: usize) -> u32 {
+    60u32
+        .saturating_sub((level as u32).saturating_sub(1) * 5)
+        .max(20)
+}
+
+/// Build the preview pane content for a single lesson: title, derived
+/// difficulty/target WPM, its distinct key inventory, and a wrapped excerpt
+/// of generated content.
+fn build_lesson_preview(lesson: &Lesson, width: usize) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            lesson.title.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if let Some(level) = lesson_level(lesson) {
+        lines.push(Line::from(format!(
+            "Difficulty: level {}  •  target ~{} wpm",
+            level,
+            target_wpm_for_level(level)
+        )));
+    }
+
+    let mut charset = lesson.keys.clone();
+    charset.sort_unstable();
+    charset.dedup();
+    if !charset.is_empty() {
+        lines.push(Line::from(format!(
+            "Keys: {}",
+            charset.iter().collect::<String>()
+        )));
+    }
+
+    lines.push(Line::from(""));
+
+    let sample = lesson.generate(PREVIEW_SAMPLE_LENGTH);
+    if sample.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "(no preview available)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else {
+        for line in wrap_text(&sample, width)
+            .into_iter()
+            .take(PREVIEW_CONTENT_LINES)
+        {
+            lines.push(Line::from(Span::styled(
+                line,
+                Style::default().fg(Color::White),
+            )));
+        }
+    }
+
+    lines
+}
+
+/// Fuzzy subsequence match of `query` against `target`, case-insensitive.
+/// Returns the match score and the `char` positions in `target` that
+/// satisfied it, or `None` if `query`'s characters don't all appear in
+/// `target` in order. Consecutive runs and hits that start a word score
+/// higher, so "lg" ranks "Language Basics" above "Lesson Group".
+fn fuzzy_match(query: &str, target: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let target_chars: Vec<char> = target.chars().collect();
+    let target_lower: Vec<char> = target.to_lowercase().chars().collect();
+
+    let mut matched = Vec::with_capacity(query.len());
+    let mut score: i64 = 0;
+    let mut qi = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for (ti, &c) in target_lower.iter().enumerate() {
+        if qi >= query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+
+        let mut bonus = 1;
+        if ti > 0 && prev_match == Some(ti - 1) {
+            bonus += 8;
+        }
+        if ti == 0 || !target_chars[ti - 1].is_alphanumeric() {
+            bonus += 5;
+        }
+
+        score += bonus;
+        matched.push(ti);
+        prev_match = Some(ti);
+        qi += 1;
+    }
+
+    (qi == query.len()).then_some((score, matched))
+}
+
+/// Indices into `lessons` whose title fuzzy-matches `query`, in their
+/// original order. An empty `query` matches every lesson.
+pub fn matching_lesson_indices(lessons: &[Lesson], query: &str) -> Vec<usize> {
+    lessons
+        .iter()
+        .enumerate()
+        .filter(|(_, lesson)| fuzzy_match(query, &lesson.title).is_some())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Split `title` into spans, applying `highlight` to the char positions in
+/// `matched` (as produced by `fuzzy_match`) and `base` to everything else.
+fn highlighted_title_spans(
+    title: &str,
+    matched: &[usize],
+    base: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    if matched.is_empty() {
+        return vec![Span::styled(title.to_string(), base)];
+    }
+
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut current_highlighted = false;
+
+    for (i, ch) in title.chars().enumerate() {
+        let is_highlighted = matched.contains(&i);
+        if is_highlighted != current_highlighted && !current.is_empty() {
+            spans.push(Span::styled(
+                std::mem::take(&mut current),
+                if current_highlighted { highlight } else { base },
+            ));
+        }
+        current_highlighted = is_highlighted;
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        spans.push(Span::styled(
+            current,
+            if current_highlighted { highlight } else { base },
+        ));
+    }
+
+    spans
+}
+
+/// Build the numbered `ListItem` for one lesson, highlighting the title's
+/// `matched` char positions (from `fuzzy_match`) with a distinct style.
+fn lesson_list_item<'a>(
+    index: usize,
+    selected: usize,
+    lesson: &Lesson,
+    matched: &[usize],
+) -> ListItem<'a> {
+    let is_selected = index == selected;
+    let style = if is_selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let highlight_style = if is_selected {
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    } else {
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::UNDERLINED)
+    };
+
+    let prefix = if is_selected { "▶ " } else { "  " };
+    let mut spans = vec![Span::styled(format!("{}{}. ", prefix, index + 1), style)];
+    spans.extend(highlighted_title_spans(
+        &lesson.title,
+        matched,
+        style,
+        highlight_style,
+    ));
+
+    ListItem::new(Line::from(spans))
+}
+
 /// Rendu du menu de sélection de leçon
 pub fn render_menu(
     f: &mut Frame,
     lessons: &[Lesson],
     selected: usize,
     scroll_offset: usize,
+    query: &str,
+    preview_cache: &mut MenuPreviewCache,
     category_name: Option<&str>,
 ) {
+    let show_preview = f.area().width >= PREVIEW_MIN_TERMINAL_WIDTH;
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
             Constraint::Length(3), // Header
+            Constraint::Length(1), // Filter prompt
             Constraint::Min(10),   // Menu
             Constraint::Length(3), // Instructions
         ])
         .split(f.area());
 
+    let (list_area, preview_area) = if show_preview {
+        let split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[2]);
+        (split[0], Some(split[1]))
+    } else {
+        (chunks[2], None)
+    };
+
     // Header
     let header_text = if let Some(name) = category_name {
         format!("TYPER CLI - {} Lessons", name)
@@ -464,8 +847,27 @@ pub fn render_menu(
         );
     f.render_widget(header, chunks[0]);
 
+    // Filter prompt
+    let filter_line = if query.is_empty() {
+        Line::from(Span::styled(
+            "Type to filter lessons...",
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        Line::from(vec![
+            Span::styled("Filter: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                query.to_string(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])
+    };
+    f.render_widget(Paragraph::new(filter_line), chunks[1]);
+
     // Calculate visible area height (minus borders and padding)
-    let menu_area_height = chunks[1].height.saturating_sub(2) as usize;
+    let menu_area_height = list_area.height.saturating_sub(2) as usize;
 
     // Build lesson items with category-specific grouping separators
     let mut all_items: Vec<ListItem> = Vec::new();
@@ -477,6 +879,7 @@ pub fn render_menu(
             use crate::content::lesson::LessonType;
 
             let mut current_language: Option<Language> = None;
+            let mut pending_separator: Option<&'static str> = None;
 
             for (i, lesson) in lessons.iter().enumerate() {
                 // Detect language from lesson type
@@ -490,22 +893,28 @@ pub fn render_menu(
                     _ => None,
                 };
 
-                // Add separator when language changes
+                // Track language changes even through non-matching lessons, so
+                // the separator for a new group still appears before its
+                // first surviving match.
                 if lesson_language != current_language && lesson_language.is_some() {
                     current_language = lesson_language;
+                    pending_separator = Some(match current_language {
+                        Some(Language::French) => "FRENCH",
+                        Some(Language::English) => "ENGLISH",
+                        None => "",
+                    });
+                }
+
+                let Some((_, matched)) = fuzzy_match(query, &lesson.title) else {
+                    continue;
+                };
 
+                if let Some(language_name) = pending_separator.take() {
                     // Add blank line before separator (except for first group)
-                    if i > 0 {
+                    if !all_items.is_empty() {
                         all_items.push(ListItem::new(Line::from("")));
                     }
 
-                    // Add language separator
-                    let language_name = match current_language {
-                        Some(Language::French) => "FRENCH",
-                        Some(Language::English) => "ENGLISH",
-                        None => "",
-                    };
-
                     all_items.push(ListItem::new(Line::from(Span::styled(
                         format!("─── {} ───", language_name),
                         Style::default()
@@ -514,19 +923,7 @@ pub fn render_menu(
                     ))));
                 }
 
-                // Add lesson item
-                let style = if i == selected {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
-                };
-
-                let prefix = if i == selected { "▶ " } else { "  " };
-                let content = format!("{}{}. {}", prefix, i + 1, lesson.title);
-
-                all_items.push(ListItem::new(Line::from(Span::styled(content, style))));
+                all_items.push(lesson_list_item(i, selected, lesson, &matched));
             }
         }
         Some("Finger Training") => {
@@ -534,6 +931,7 @@ pub fn render_menu(
             use crate::content::lesson::{FingerPairType, LessonType};
 
             let mut current_finger_pair: Option<FingerPairType> = None;
+            let mut pending_separator: Option<&'static str> = None;
 
             for (i, lesson) in lessons.iter().enumerate() {
                 // Detect finger pair from lesson type
@@ -542,23 +940,25 @@ pub fn render_menu(
                     _ => None,
                 };
 
-                // Add separator when finger pair changes
                 if lesson_finger_pair != current_finger_pair && lesson_finger_pair.is_some() {
                     current_finger_pair = lesson_finger_pair;
-
-                    // Add blank line before separator (except for first group)
-                    if i > 0 {
-                        all_items.push(ListItem::new(Line::from("")));
-                    }
-
-                    // Add finger pair separator
-                    let finger_name = match current_finger_pair {
+                    pending_separator = Some(match current_finger_pair {
                         Some(FingerPairType::Pinky) => "PINKY FINGERS",
                         Some(FingerPairType::Ring) => "RING FINGERS",
                         Some(FingerPairType::Middle) => "MIDDLE FINGERS",
                         Some(FingerPairType::Index) => "INDEX FINGERS",
                         None => "",
-                    };
+                    });
+                }
+
+                let Some((_, matched)) = fuzzy_match(query, &lesson.title) else {
+                    continue;
+                };
+
+                if let Some(finger_name) = pending_separator.take() {
+                    if !all_items.is_empty() {
+                        all_items.push(ListItem::new(Line::from("")));
+                    }
 
                     all_items.push(ListItem::new(Line::from(Span::styled(
                         format!("─── {} ───", finger_name),
@@ -568,19 +968,7 @@ pub fn render_menu(
                     ))));
                 }
 
-                // Add lesson item
-                let style = if i == selected {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
-                };
-
-                let prefix = if i == selected { "▶ " } else { "  " };
-                let content = format!("{}{}. {}", prefix, i + 1, lesson.title);
-
-                all_items.push(ListItem::new(Line::from(Span::styled(content, style))));
+                all_items.push(lesson_list_item(i, selected, lesson, &matched));
             }
         }
         Some("Code") => {
@@ -598,6 +986,7 @@ pub fn render_menu(
             }
 
             let mut current_group: Option<CodeGroupType> = None;
+            let mut pending_separator: Option<&'static str> = None;
 
             for (i, lesson) in lessons.iter().enumerate() {
                 // Detect code group type from lesson type
@@ -621,23 +1010,25 @@ pub fn render_menu(
                     _ => None,
                 };
 
-                // Add separator when group changes
                 if lesson_group != current_group && lesson_group.is_some() {
                     current_group = lesson_group;
-
-                    // Add blank line before separator (except for first group)
-                    if i > 0 {
-                        all_items.push(ListItem::new(Line::from("")));
-                    }
-
-                    // Add group separator
-                    let group_name = match current_group {
+                    pending_separator = Some(match current_group {
                         Some(CodeGroupType::CodeBigrams) => "CODE PATTERNS",
                         Some(CodeGroupType::TypeScript) => "TYPESCRIPT",
                         Some(CodeGroupType::Rust) => "RUST",
                         Some(CodeGroupType::Python) => "PYTHON",
                         None => "",
-                    };
+                    });
+                }
+
+                let Some((_, matched)) = fuzzy_match(query, &lesson.title) else {
+                    continue;
+                };
+
+                if let Some(group_name) = pending_separator.take() {
+                    if !all_items.is_empty() {
+                        all_items.push(ListItem::new(Line::from("")));
+                    }
 
                     all_items.push(ListItem::new(Line::from(Span::styled(
                         format!("─── {} ───", group_name),
@@ -647,36 +1038,17 @@ pub fn render_menu(
                     ))));
                 }
 
-                // Add lesson item
-                let style = if i == selected {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
-                };
-
-                let prefix = if i == selected { "▶ " } else { "  " };
-                let content = format!("{}{}. {}", prefix, i + 1, lesson.title);
-
-                all_items.push(ListItem::new(Line::from(Span::styled(content, style))));
+                all_items.push(lesson_list_item(i, selected, lesson, &matched));
             }
         }
         _ => {
             // Standard rendering for other categories (Key Training, Adaptive)
             for (i, lesson) in lessons.iter().enumerate() {
-                let style = if i == selected {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::White)
+                let Some((_, matched)) = fuzzy_match(query, &lesson.title) else {
+                    continue;
                 };
 
-                let prefix = if i == selected { "▶ " } else { "  " };
-                let content = format!("{}{}. {}", prefix, i + 1, lesson.title);
-
-                all_items.push(ListItem::new(Line::from(Span::styled(content, style))));
+                all_items.push(lesson_list_item(i, selected, lesson, &matched));
             }
         }
     }
@@ -712,20 +1084,31 @@ pub fn render_menu(
             .padding(ratatui::widgets::Padding::new(1, 1, 1, 0)),
     );
 
-    f.render_widget(list, chunks[1]);
+    f.render_widget(list, list_area);
 
-    // Instructions
-    let instructions = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "Use ↑/↓ or j/k to navigate  •  Press Enter/Space or 1-9 to select  •  ESC to go back",
-            Style::default().fg(Color::Gray),
-        )),
-    ];
-
-    let instructions_widget = Paragraph::new(instructions).alignment(Alignment::Center);
+    if let Some(area) = preview_area {
+        let preview_width = area.width.saturating_sub(4) as usize;
+        let preview_lines = preview_cache.get_or_build(selected, &lessons[selected], preview_width);
+        let preview = Paragraph::new(preview_lines).block(
+            Block::default()
+                .title("Preview")
+                .borders(Borders::ALL)
+                .padding(ratatui::widgets::Padding::new(1, 1, 1, 0)),
+        );
+        f.render_widget(preview, area);
+    }
 
-    f.render_widget(instructions_widget, chunks[2]);
+    // Instructions
+    render_keyhints(
+        f,
+        chunks[3],
+        &[
+            ("↑/↓ or j/k", "navigate"),
+            ("Enter/Space or 1-9", "select"),
+            ("type", "filter"),
+            ("ESC", "go back"),
+        ],
+    );
 }
 
 /// Rendu du menu de sélection de durée
@@ -788,11 +1171,79 @@ pub fn render_duration_menu(f: &mut Frame, selected: usize) {
 
     f.render_widget(list, chunks[1]);
 
+    // Instructions
+    render_keyhints(
+        f,
+        chunks[2],
+        &[
+            ("↑/↓ or j/k", "navigate"),
+            ("Enter/Space", "start"),
+            ("ESC", "go back"),
+        ],
+    );
+}
+
+/// Rendu du menu de sélection de disposition clavier
+pub fn render_layout_menu(f: &mut Frame, layouts: &[KeyboardLayoutKind], selected: usize) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(10),   // Layout list
+            Constraint::Length(3), // Instructions
+        ])
+        .split(f.area());
+
+    // Header
+    let header = Paragraph::new("TYPER CLI - Select Keyboard Layout")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(ratatui::widgets::Padding::horizontal(1)),
+        );
+    f.render_widget(header, chunks[0]);
+
+    // Layout list
+    let items: Vec<ListItem> = layouts
+        .iter()
+        .enumerate()
+        .map(|(i, kind)| {
+            let style = if i == selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            let prefix = if i == selected { "▶ " } else { "  " };
+            let content = format!("{}{}", prefix, kind.label());
+
+            ListItem::new(Line::from(Span::styled(content, style)))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .title("Keyboard Layout")
+            .borders(Borders::ALL)
+            .padding(ratatui::widgets::Padding::new(1, 1, 1, 0)),
+    );
+
+    f.render_widget(list, chunks[1]);
+
     // Instructions
     let instructions = vec![
         Line::from(""),
         Line::from(Span::styled(
-            "Use ↑/↓ or j/k to navigate  •  Press Enter/Space to start  •  ESC to go back",
+            "Use ↑/↓ or j/k to navigate  •  Press Enter/Space to select  •  ESC to go back",
             Style::default().fg(Color::Gray),
         )),
     ];
@@ -878,34 +1329,41 @@ pub fn render_lesson_type_menu(
     f.render_widget(list, chunks[1]);
 
     // Instructions
-    let instructions = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "Use ↑/↓ or j/k to navigate  •  Press Enter/Space or 1-5 to select  •  Press 's' for Statistics  •  ESC to quit",
-            Style::default().fg(Color::Gray),
-        )),
-    ];
-
-    let instructions_widget = Paragraph::new(instructions).alignment(Alignment::Center);
-
-    f.render_widget(instructions_widget, chunks[2]);
+    render_keyhints(
+        f,
+        chunks[2],
+        &[
+            ("↑/↓ or j/k", "navigate"),
+            ("Enter/Space or 1-5", "select"),
+            ("s", "statistics"),
+            ("ESC", "quit"),
+        ],
+    );
 }
 
 /// Rendu de l'écran de fin
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments)]
 pub fn render_results(
     f: &mut Frame,
     wpm: f64,
+    raw_wpm: f64,
     accuracy: f64,
     duration: std::time::Duration,
     error_count: usize,
+    slow_keys: &[char],
+    rollover_count: usize,
+    consistency: f64,
+    wpm_per_second: &[f64],
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(4)
         .constraints([
-            Constraint::Length(3), // Title
-            Constraint::Length(8), // Results
-            Constraint::Length(2), // Instructions
+            Constraint::Length(3),  // Title
+            Constraint::Length(11), // Results
+            Constraint::Length(3),  // WPM-over-time sparkline
+            Constraint::Length(2),  // Instructions
         ])
         .split(f.area());
 
@@ -929,7 +1387,7 @@ pub fn render_results(
     let results_text = vec![
         Line::from(""),
         Line::from(Span::styled(
-            format!("WPM: {:.1}", wpm),
+            format!("WPM: {:.1}  (raw: {:.1})", wpm, raw_wpm),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
@@ -938,6 +1396,10 @@ pub fn render_results(
             format!("Accuracy: {:.1}%", accuracy),
             Style::default().fg(Color::Green),
         )),
+        Line::from(Span::styled(
+            format!("Consistency: {:.0}%", consistency),
+            Style::default().fg(Color::Cyan),
+        )),
         Line::from(Span::styled(
             format!("Errors: {}", error_count),
             Style::default().fg(Color::Red),
@@ -950,6 +1412,18 @@ pub fn render_results(
             ),
             Style::default().fg(Color::Yellow),
         )),
+        Line::from(Span::styled(
+            if slow_keys.is_empty() {
+                "Slow keys: none".to_string()
+            } else {
+                format!("Slow keys: {}", slow_keys.iter().collect::<String>())
+            },
+            Style::default().fg(Color::Magenta),
+        )),
+        Line::from(Span::styled(
+            format!("Rollover events: {}", rollover_count),
+            Style::default().fg(Color::Magenta),
+        )),
     ];
 
     let results = Paragraph::new(results_text)
@@ -962,29 +1436,175 @@ pub fn render_results(
 
     f.render_widget(results, chunks[1]);
 
+    // WPM-over-time sparkline
+    render_wpm_sparkline(f, wpm_per_second, chunks[2]);
+
     // Instructions
-    let instructions = Paragraph::new("Press ESC to return to menu  •  Press 'r' to restart")
-        .style(Style::default().fg(Color::Gray))
-        .alignment(Alignment::Center);
+    render_keyhints(f, chunks[3], &[("ESC", "return to menu"), ("r", "restart")]);
+}
 
-    f.render_widget(instructions, chunks[2]);
+/// Render `wpm_per_second` (one instantaneous-WPM sample per second, see
+/// `engine::scoring::wpm_per_second_buckets`) as an inline Unicode block
+/// sparkline, each bar height scaled between the series' own min and max.
+fn render_wpm_sparkline(f: &mut Frame, wpm_per_second: &[f64], area: Rect) {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let line = if wpm_per_second.len() < 2 {
+        Line::from(Span::styled(
+            "Not enough data for a trend",
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        let min = wpm_per_second.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = wpm_per_second
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let range = (max - min).max(1.0);
+
+        let bars: String = wpm_per_second
+            .iter()
+            .map(|&wpm| {
+                let normalized = ((wpm - min) / range).clamp(0.0, 1.0);
+                let index = (normalized * (BLOCKS.len() - 1) as f64).round() as usize;
+                BLOCKS[index]
+            })
+            .collect();
+
+        Line::from(Span::styled(bars, Style::default().fg(Color::Cyan)))
+    };
+
+    let title = if wpm_per_second.len() < 2 {
+        "WPM over time".to_string()
+    } else {
+        let steadiness = crate::engine::scoring::wpm_series_consistency(wpm_per_second);
+        format!("WPM over time (steadiness: {:.0}%)", steadiness)
+    };
+
+    let sparkline = Paragraph::new(line).alignment(Alignment::Center).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(sparkline, area);
 }
 
 /// Render statistics and performance analytics page
 pub fn render_statistics(
     f: &mut Frame,
     stats: &Stats,
-    keyboard_layout: &AzertyLayout,
+    keyboard_layout: &dyn KeyboardLayout,
     keyboard_config: &KeyboardConfig,
+    tab_state: &StatsTabState,
+    display_config: &StatsDisplayConfig,
 ) {
     // Check if we have analytics data
     if let Some(analytics) = &stats.adaptive_analytics {
-        render_statistics_with_data(f, stats, analytics, keyboard_layout, keyboard_config);
+        render_statistics_with_data(
+            f,
+            stats,
+            analytics,
+            keyboard_layout,
+            keyboard_config,
+            tab_state,
+            display_config,
+        );
     } else {
         render_statistics_placeholder(f);
     }
 }
 
+/// Which panel of the statistics screen is currently shown, cycled through by
+/// `StatsTabState`. Mirrors `StatsPanel`, the serializable form stored in the
+/// `[statistics]` section of `config.toml` — `ui::render` doesn't use that
+/// type directly so the `data` module never has to depend on `ui`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsTab {
+    Overview,
+    Mastery,
+    Weaknesses,
+    Mistypes,
+    Heatmap,
+    Trend,
+}
+
+impl StatsTab {
+    fn from_panel(panel: StatsPanel) -> Self {
+        match panel {
+            StatsPanel::Overall => StatsTab::Overview,
+            StatsPanel::Mastery => StatsTab::Mastery,
+            StatsPanel::Weaknesses => StatsTab::Weaknesses,
+            StatsPanel::Mistypes => StatsTab::Mistypes,
+            StatsPanel::Heatmap => StatsTab::Heatmap,
+            StatsPanel::Trend => StatsTab::Trend,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            StatsTab::Overview => "Overview",
+            StatsTab::Mastery => "Mastery",
+            StatsTab::Weaknesses => "Weaknesses",
+            StatsTab::Mistypes => "Mistypes",
+            StatsTab::Heatmap => "Heatmap",
+            StatsTab::Trend => "Trend",
+        }
+    }
+}
+
+/// Tracks which `StatsTab` is active on the statistics screen, cycled with
+/// Tab/Shift-Tab or ←/→. Each tab then renders into the screen's full content
+/// area instead of the panels fighting for space in one fixed grid. Built
+/// from the `[statistics].panels` list in `config.toml` (`StatsLayoutConfig`),
+/// so users can choose which panels to show and in what order.
+#[derive(Debug, Clone)]
+pub struct StatsTabState {
+    tabs: Vec<StatsTab>,
+    pub titles: Vec<&'static str>,
+    pub index: usize,
+}
+
+impl StatsTabState {
+    /// Build tab state from the configured panel list, falling back to
+    /// `StatsLayoutConfig::default()`'s order if `panels` is empty.
+    pub fn new(panels: &[StatsPanel]) -> Self {
+        let panels = if panels.is_empty() {
+            StatsLayoutConfig::default().panels
+        } else {
+            panels.to_vec()
+        };
+
+        let tabs: Vec<StatsTab> = panels.into_iter().map(StatsTab::from_panel).collect();
+        let titles = tabs.iter().map(|tab| tab.title()).collect();
+
+        Self {
+            tabs,
+            titles,
+            index: 0,
+        }
+    }
+
+    pub fn current(&self) -> StatsTab {
+        self.tabs[self.index]
+    }
+
+    pub fn next(&mut self) {
+        self.index = (self.index + 1) % self.tabs.len();
+    }
+
+    pub fn previous(&mut self) {
+        self.index = (self.index + self.tabs.len() - 1) % self.tabs.len();
+    }
+}
+
+impl Default for StatsTabState {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
 /// Render statistics placeholder when no data exists
 fn render_statistics_placeholder(f: &mut Frame) {
     let chunks = Layout::default()
@@ -1050,15 +1670,18 @@ fn render_statistics_with_data(
     f: &mut Frame,
     stats: &Stats,
     analytics: &AdaptiveAnalytics,
-    keyboard_layout: &AzertyLayout,
+    keyboard_layout: &dyn KeyboardLayout,
     keyboard_config: &KeyboardConfig,
+    tab_state: &StatsTabState,
+    display_config: &StatsDisplayConfig,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
             Constraint::Length(3), // Header
-            Constraint::Min(20),   // Content
+            Constraint::Length(3), // Tabs
+            Constraint::Min(15),   // Active tab's content
             Constraint::Length(3), // Instructions
         ])
         .split(f.area());
@@ -1079,42 +1702,41 @@ fn render_statistics_with_data(
 
     f.render_widget(header, chunks[0]);
 
-    // Content area - split horizontally (40% left / 60% right)
-    let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(chunks[1]);
-
-    // Left column - split vertically for different stats sections
-    let left_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(8),  // Overall stats
-            Constraint::Length(20), // Mastery breakdown (4 levels x 2 lines + spacing + borders)
-            Constraint::Length(8),  // Weaknesses (reduced by 2 lines)
-            Constraint::Min(10),    // Common mistypes (increased)
-        ])
-        .split(content_chunks[0]);
-
-    // Render left column sections
-    render_overall_stats_block(f, stats, analytics, left_chunks[0]);
-    render_mastery_breakdown(f, analytics, left_chunks[1]);
-    render_weaknesses_list(f, analytics, left_chunks[2]);
-    render_common_mistypes(f, analytics, left_chunks[3]);
+    // Tab bar
+    let tab_titles: Vec<Line> = tab_state
+        .titles
+        .iter()
+        .map(|title| Line::from(*title))
+        .collect();
+    let tabs = Tabs::new(tab_titles)
+        .select(tab_state.index)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::Gray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
 
-    // Render keyboard heatmap on the right
-    render_keyboard_with_heatmap(
-        f,
-        keyboard_layout,
-        keyboard_config,
-        analytics,
-        content_chunks[1],
-    );
+    f.render_widget(tabs, chunks[1]);
+
+    // Active tab gets the full content area, rather than every section
+    // fighting for space in one fixed grid.
+    match tab_state.current() {
+        StatsTab::Overview => render_overall_stats_block(f, stats, analytics, chunks[2]),
+        StatsTab::Mastery => render_mastery_breakdown(f, analytics, chunks[2]),
+        StatsTab::Weaknesses => render_weaknesses_list(f, analytics, chunks[2], display_config),
+        StatsTab::Mistypes => render_common_mistypes(f, analytics, chunks[2], display_config),
+        StatsTab::Heatmap => {
+            render_keyboard_with_heatmap(f, keyboard_layout, keyboard_config, analytics, chunks[2])
+        }
+        StatsTab::Trend => render_performance_trend(f, analytics, chunks[2]),
+    }
 
     // Instructions
     let session_count = analytics.total_sessions;
     let instructions_text = format!(
-        "ESC to return  •  Analyzing {} session{}",
+        "ESC to return  •  Tab/Shift-Tab or ←/→ to switch view  •  Analyzing {} session{}",
         session_count,
         if session_count == 1 { "" } else { "s" }
     );
@@ -1123,7 +1745,86 @@ fn render_statistics_with_data(
         .style(Style::default().fg(Color::Gray))
         .alignment(Alignment::Center);
 
-    f.render_widget(instructions, chunks[2]);
+    f.render_widget(instructions, chunks[3]);
+}
+
+/// Render a line chart of WPM and accuracy across `analytics.session_history`,
+/// one point per completed session, so the user can see whether they're
+/// improving over time rather than just a single aggregate snapshot.
+fn render_performance_trend(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Rect) {
+    let block = Block::default()
+        .title("Performance Trend")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if analytics.session_history.len() < 2 {
+        let placeholder = Paragraph::new("Complete a few more sessions to see your trend here.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(placeholder, area);
+        return;
+    }
+
+    let wpm_points: Vec<(f64, f64)> = analytics
+        .session_history
+        .iter()
+        .enumerate()
+        .map(|(i, session)| (i as f64, session.wpm))
+        .collect();
+    let accuracy_points: Vec<(f64, f64)> = analytics
+        .session_history
+        .iter()
+        .enumerate()
+        .map(|(i, session)| (i as f64, session.accuracy))
+        .collect();
+
+    let max_session = (analytics.session_history.len() - 1) as f64;
+    let max_wpm = wpm_points
+        .iter()
+        .map(|(_, wpm)| *wpm)
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let datasets = vec![
+        Dataset::default()
+            .name("WPM")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Yellow))
+            .data(&wpm_points),
+        Dataset::default()
+            .name("Accuracy %")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Green))
+            .data(&accuracy_points),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .title("Session")
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_session])
+                .labels(vec![
+                    Span::raw("0"),
+                    Span::raw(format!("{}", max_session as usize)),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .title("WPM / Accuracy %")
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_wpm.max(100.0)])
+                .labels(vec![
+                    Span::raw("0"),
+                    Span::raw(format!("{:.0}", max_wpm.max(100.0))),
+                ]),
+        );
+
+    f.render_widget(chart, area);
 }
 
 /// Render overall performance statistics
@@ -1281,11 +1982,19 @@ fn render_mastery_breakdown(f: &mut Frame, analytics: &AdaptiveAnalytics, area:
 }
 
 /// Render top weaknesses list
-fn render_weaknesses_list(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Rect) {
+fn render_weaknesses_list(
+    f: &mut Frame,
+    analytics: &AdaptiveAnalytics,
+    area: Rect,
+    display_config: &StatsDisplayConfig,
+) {
     let mut weak_keys: Vec<_> = analytics
         .key_stats
         .iter()
-        .filter(|(_, stats)| stats.accuracy() < 80.0 && stats.total_attempts >= 5)
+        .filter(|(_, stats)| {
+            stats.accuracy() < display_config.weakness_accuracy_threshold
+                && stats.total_attempts >= display_config.weakness_min_attempts as usize
+        })
         .collect();
 
     weak_keys.sort_by(|a, b| {
@@ -1304,7 +2013,11 @@ fn render_weaknesses_list(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Re
                 .add_modifier(Modifier::BOLD),
         )));
     } else {
-        for (i, (key, stats)) in weak_keys.iter().take(10).enumerate() {
+        for (i, (key, stats)) in weak_keys
+            .iter()
+            .take(display_config.max_weaknesses_shown)
+            .enumerate()
+        {
             let accuracy = stats.accuracy();
             let color = if accuracy < 50.0 {
                 Color::Red
@@ -1347,7 +2060,12 @@ fn render_weaknesses_list(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Re
 }
 
 /// Render common mistype patterns
-fn render_common_mistypes(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Rect) {
+fn render_common_mistypes(
+    f: &mut Frame,
+    analytics: &AdaptiveAnalytics,
+    area: Rect,
+    display_config: &StatsDisplayConfig,
+) {
     let mut all_mistypes = Vec::new();
     for (expected, key_stats) in &analytics.key_stats {
         for (typed, count) in &key_stats.mistype_map {
@@ -1359,19 +2077,22 @@ fn render_common_mistypes(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Re
 
     let mut mistype_lines = vec![];
 
-    if all_mistypes.is_empty() || all_mistypes.len() < 5 {
+    if all_mistypes.is_empty() || all_mistypes.len() < display_config.min_mistypes_to_display {
         mistype_lines.push(Line::from(Span::styled(
             "Insufficient data",
             Style::default().fg(Color::Gray),
         )));
     } else {
-        // Split into two columns: items 1-5 on left, 6-10 on right
-        let top_10: Vec<_> = all_mistypes.iter().take(10).collect();
-        let max_rows = top_10.len().div_ceil(2);
+        // Split into two columns: items 1..N/2 on left, N/2+1..N on right
+        let top_n: Vec<_> = all_mistypes
+            .iter()
+            .take(display_config.max_mistypes_shown)
+            .collect();
+        let max_rows = top_n.len().div_ceil(2);
 
         for i in 0..max_rows {
-            let left_item = top_10.get(i);
-            let right_item = top_10.get(i + max_rows);
+            let left_item = top_n.get(i);
+            let right_item = top_n.get(i + max_rows);
 
             let mut spans = Vec::new();
 
@@ -1416,7 +2137,7 @@ fn render_common_mistypes(f: &mut Frame, analytics: &AdaptiveAnalytics, area: Re
 /// Render keyboard for statistics page
 fn render_keyboard_with_heatmap(
     f: &mut Frame,
-    keyboard_layout: &AzertyLayout,
+    keyboard_layout: &dyn KeyboardLayout,
     keyboard_config: &KeyboardConfig,
     analytics: &AdaptiveAnalytics,
     area: Rect,
@@ -0,0 +1,52 @@
+/// Terminal setup/teardown, kept separate from `render` since it's about the
+/// terminal's raw-mode/alternate-screen state rather than what's drawn in it.
+///
+/// Without this, a panic while raw mode and the alternate screen are active
+/// drops the user into a scrambled terminal that needs `reset` to recover.
+/// `TerminalGuard` and the panic hook it installs both funnel through
+/// `restore_terminal`, so the clean-exit and panic paths leave the terminal
+/// in the same state.
+use std::io;
+use std::panic;
+
+use crossterm::cursor::{Hide, Show};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+
+/// Leave the alternate screen, disable raw mode, and show the cursor again.
+/// Errors are ignored: this runs during teardown (including from a panic
+/// hook), where there's no sensible way to react to a failure anyway.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+}
+
+/// RAII guard that puts the terminal into raw mode and the alternate screen
+/// on construction, and restores it on `Drop` (the clean-exit path). Also
+/// installs a panic hook that performs the same restoration before
+/// forwarding to the previous hook (the crash path), so a panic never
+/// leaves the terminal scrambled.
+pub struct TerminalGuard {
+    _private: (),
+}
+
+impl TerminalGuard {
+    pub fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |panic_info| {
+            restore_terminal();
+            previous_hook(panic_info);
+        }));
+
+        Ok(Self { _private: () })
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}